@@ -0,0 +1,101 @@
+use crate::*;
+
+macro_rules! impl_aabb2 {
+    ($t:ident) => {
+        impl Aabb2<$t> {
+            pub fn new(min: Vec2<$t>, max: Vec2<$t>) -> Self {
+                Self(min, max)
+            }
+
+            // inverted bounds that any point or box merges into
+            pub fn empty() -> Self {
+                Self(
+                    Vec2::<$t>::one() * $t::INFINITY,
+                    Vec2::<$t>::one() * $t::NEG_INFINITY,
+                )
+            }
+
+            pub fn from_points(points: &[Vec2<$t>]) -> Self {
+                points.iter().fold(Self::empty(), |aabb, &p| aabb.expanded(p))
+            }
+
+            pub fn min(&self) -> Vec2<$t> {
+                self.0
+            }
+
+            pub fn max(&self) -> Vec2<$t> {
+                self.1
+            }
+
+            pub fn center(&self) -> Vec2<$t> {
+                (self.0 + self.1) * $t::splat(0.5)
+            }
+
+            pub fn size(&self) -> Vec2<$t> {
+                self.1 - self.0
+            }
+
+            pub fn half_extents(&self) -> Vec2<$t> {
+                self.size() * $t::splat(0.5)
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0[0] > self.1[0] || self.0[1] > self.1[1]
+            }
+
+            pub fn area(&self) -> $t {
+                let size = self.size();
+                size[0] * size[1]
+            }
+
+            pub fn perimeter(&self) -> $t {
+                let size = self.size();
+                $t::splat(2.0) * (size[0] + size[1])
+            }
+
+            pub fn contains(&self, point: Vec2<$t>) -> bool {
+                (self.0[0]..=self.1[0]).contains(&point[0]) && (self.0[1]..=self.1[1]).contains(&point[1])
+            }
+
+            pub fn intersects(&self, rhs: Self) -> bool {
+                self.0[0] <= rhs.1[0] && rhs.0[0] <= self.1[0] && self.0[1] <= rhs.1[1] && rhs.0[1] <= self.1[1]
+            }
+
+            pub fn closest_point(&self, point: Vec2<$t>) -> Vec2<$t> {
+                Vec2::<$t>::new(
+                    point[0].max(self.0[0]).min(self.1[0]),
+                    point[1].max(self.0[1]).min(self.1[1]),
+                )
+            }
+
+            pub fn distance_to_point(&self, point: Vec2<$t>) -> $t {
+                (self.closest_point(point) - point).length()
+            }
+
+            pub fn expanded(&self, point: Vec2<$t>) -> Self {
+                Self(
+                    Vec2::<$t>::new(self.0[0].min(point[0]), self.0[1].min(point[1])),
+                    Vec2::<$t>::new(self.1[0].max(point[0]), self.1[1].max(point[1])),
+                )
+            }
+
+            pub fn merged(&self, rhs: Self) -> Self {
+                Self(
+                    Vec2::<$t>::new(self.0[0].min(rhs.0[0]), self.0[1].min(rhs.0[1])),
+                    Vec2::<$t>::new(self.1[0].max(rhs.1[0]), self.1[1].max(rhs.1[1])),
+                )
+            }
+        }
+
+        // display
+        // ---------------------------------------------------------------------------------------------------
+        impl core::fmt::Display for Aabb2<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "(min, max): [{}, {}]", self.0, self.1)
+            }
+        }
+    };
+}
+
+impl_aabb2!(f32);
+impl_aabb2!(f64);
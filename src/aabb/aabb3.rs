@@ -0,0 +1,125 @@
+use crate::*;
+
+macro_rules! impl_aabb3 {
+    ($t:ident) => {
+        impl Aabb3<$t> {
+            pub fn new(min: Vec3<$t>, max: Vec3<$t>) -> Self {
+                Self(min, max)
+            }
+
+            // inverted bounds that any point or box merges into
+            pub fn empty() -> Self {
+                Self(
+                    Vec3::<$t>::one() * $t::INFINITY,
+                    Vec3::<$t>::one() * $t::NEG_INFINITY,
+                )
+            }
+
+            pub fn from_points(points: &[Vec3<$t>]) -> Self {
+                points.iter().fold(Self::empty(), |aabb, &p| aabb.expanded(p))
+            }
+
+            pub fn min(&self) -> Vec3<$t> {
+                self.0
+            }
+
+            pub fn max(&self) -> Vec3<$t> {
+                self.1
+            }
+
+            pub fn center(&self) -> Vec3<$t> {
+                (self.0 + self.1) * $t::splat(0.5)
+            }
+
+            pub fn size(&self) -> Vec3<$t> {
+                self.1 - self.0
+            }
+
+            pub fn half_extents(&self) -> Vec3<$t> {
+                self.size() * $t::splat(0.5)
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0[0] > self.1[0] || self.0[1] > self.1[1] || self.0[2] > self.1[2]
+            }
+
+            pub fn volume(&self) -> $t {
+                let size = self.size();
+                size[0] * size[1] * size[2]
+            }
+
+            pub fn surface_area(&self) -> $t {
+                let size = self.size();
+                $t::splat(2.0) * (size[0] * size[1] + size[1] * size[2] + size[2] * size[0])
+            }
+
+            pub fn contains(&self, point: Vec3<$t>) -> bool {
+                (self.0[0]..=self.1[0]).contains(&point[0])
+                    && (self.0[1]..=self.1[1]).contains(&point[1])
+                    && (self.0[2]..=self.1[2]).contains(&point[2])
+            }
+
+            pub fn intersects(&self, rhs: Self) -> bool {
+                self.0[0] <= rhs.1[0]
+                    && rhs.0[0] <= self.1[0]
+                    && self.0[1] <= rhs.1[1]
+                    && rhs.0[1] <= self.1[1]
+                    && self.0[2] <= rhs.1[2]
+                    && rhs.0[2] <= self.1[2]
+            }
+
+            pub fn closest_point(&self, point: Vec3<$t>) -> Vec3<$t> {
+                Vec3::<$t>::new(
+                    point[0].max(self.0[0]).min(self.1[0]),
+                    point[1].max(self.0[1]).min(self.1[1]),
+                    point[2].max(self.0[2]).min(self.1[2]),
+                )
+            }
+
+            pub fn distance_to_point(&self, point: Vec3<$t>) -> $t {
+                (self.closest_point(point) - point).length()
+            }
+
+            pub fn expanded(&self, point: Vec3<$t>) -> Self {
+                Self(
+                    Vec3::<$t>::new(
+                        self.0[0].min(point[0]),
+                        self.0[1].min(point[1]),
+                        self.0[2].min(point[2]),
+                    ),
+                    Vec3::<$t>::new(
+                        self.1[0].max(point[0]),
+                        self.1[1].max(point[1]),
+                        self.1[2].max(point[2]),
+                    ),
+                )
+            }
+
+            pub fn merged(&self, rhs: Self) -> Self {
+                Self(
+                    Vec3::<$t>::new(
+                        self.0[0].min(rhs.0[0]),
+                        self.0[1].min(rhs.0[1]),
+                        self.0[2].min(rhs.0[2]),
+                    ),
+                    Vec3::<$t>::new(
+                        self.1[0].max(rhs.1[0]),
+                        self.1[1].max(rhs.1[1]),
+                        self.1[2].max(rhs.1[2]),
+                    ),
+                )
+            }
+        }
+
+        // display
+        // ---------------------------------------------------------------------------------------------------
+        impl core::fmt::Display for Aabb3<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "(min, max): [{}, {}]", self.0, self.1)
+            }
+        }
+    };
+}
+
+impl_aabb3!(f32);
+impl_aabb3!(f64);
@@ -0,0 +1,62 @@
+mod aabb2;
+mod aabb3;
+
+use crate::{Vec2, Vec3};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb2<S>(pub Vec2<S>, pub Vec2<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Aabb2<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Aabb2<S> where S: bytemuck::Pod {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb3<S>(pub Vec3<S>, pub Vec3<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Aabb3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Aabb3<S> where S: bytemuck::Pod {}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_aabb2() {
+        let a = Aabb2::<f32>::new(Vec2::<f32>::new(0.0, 0.0), Vec2::<f32>::new(2.0, 1.0));
+        let b = Aabb2::<f32>::from_points(&[Vec2::<f32>::new(1.0, -1.0), Vec2::<f32>::new(3.0, 0.5)]);
+
+        assert_eq!(a.center(), Vec2::<f32>::new(1.0, 0.5));
+        assert_eq!(a.size(), Vec2::<f32>::new(2.0, 1.0));
+        assert_eq!(a.area(), 2.0);
+        assert!(a.contains(Vec2::<f32>::new(2.0, 1.0)));
+        assert!(!a.contains(Vec2::<f32>::new(2.1, 1.0)));
+        assert!(a.intersects(b));
+        assert_eq!(
+            a.merged(b),
+            Aabb2::<f32>::new(Vec2::<f32>::new(0.0, -1.0), Vec2::<f32>::new(3.0, 1.0))
+        );
+        assert!(Aabb2::<f32>::empty().is_empty());
+        assert!(Aabb2::<f32>::from_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_aabb3() {
+        let a = Aabb3::<f32>::new(Vec3::<f32>::zero(), Vec3::<f32>::new(2.0, 1.0, 3.0));
+        let b = Aabb3::<f32>::empty().expanded(Vec3::<f32>::new(5.0, 5.0, 5.0));
+
+        assert_eq!(a.center(), Vec3::<f32>::new(1.0, 0.5, 1.5));
+        assert_eq!(a.half_extents(), Vec3::<f32>::new(1.0, 0.5, 1.5));
+        assert_eq!(a.volume(), 6.0);
+        assert_eq!(a.surface_area(), 22.0);
+        assert!(!a.intersects(b));
+        assert!(b.contains(Vec3::<f32>::new(5.0, 5.0, 5.0)));
+        assert_eq!(a.merged(b).max(), Vec3::<f32>::new(5.0, 5.0, 5.0));
+        assert_eq!(a.merged(Aabb3::<f32>::empty()), a);
+    }
+}
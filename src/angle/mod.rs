@@ -0,0 +1,124 @@
+mod units;
+
+// angles tagged with their unit, so a value in degrees can't be passed where radians are expected. both convert into
+// `Rad`, which is what the rotation constructors take
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rad<S>(pub S);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Rad<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Rad<S> where S: bytemuck::Pod {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deg<S>(pub S);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Deg<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Deg<S> where S: bytemuck::Pod {}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_angle_units() {
+        assert_eq!(Deg::<f64>(180.0).to_rad(), Rad(PI));
+        assert_eq!(Rad::<f64>(FRAC_PI_2).to_deg(), Deg(90.0));
+        assert_eq!(Rad::<f32>::from(Deg(-90.0)), Rad(-core::f32::consts::FRAC_PI_2));
+        assert_eq!(Deg::<f64>::from(Rad::<f64>::full_turn()), Deg::<f64>::full_turn());
+        assert_eq!(Rad::<f64>::half_turn(), Rad(PI));
+        assert_eq!(Deg::<f32>::quarter_turn(), Deg(90.0));
+
+        let a = Deg::<f32>(30.0);
+        assert_eq!(a + a, Deg(60.0));
+        assert_eq!(a - Deg(45.0), Deg(-15.0));
+        assert_eq!(-a, Deg(-30.0));
+        assert_eq!(a * 3.0, Deg(90.0));
+        assert_eq!(2.0 * a, Deg(60.0));
+        assert_eq!(a / 2.0, Deg(15.0));
+        assert_eq!(Deg::<f32>(90.0) / a, 3.0);
+        let mut b = a;
+        b += Deg(10.0);
+        b *= 2.0;
+        assert_eq!(b, Deg(80.0));
+        assert!(Deg::<f32>(10.0) < Deg(20.0));
+
+        assert_relative_eq!(Deg::<f64>(30.0).sin(), 0.5, epsilon = 1e-15);
+        assert_relative_eq!(Rad::<f64>(PI / 3.0).cos(), 0.5, epsilon = 1e-15);
+        assert_relative_eq!(Deg::<f64>(45.0).tan(), 1.0, epsilon = 1e-15);
+        assert_eq!(Deg::<f64>(60.0).sin_cos(), Float::sin_cos(PI / 3.0));
+        assert_relative_eq!(Deg::<f64>::asin(0.5).0, 30.0, epsilon = 1e-12);
+        assert_relative_eq!(Deg::<f64>::acos(0.0).0, 90.0, epsilon = 1e-12);
+        assert_eq!(Rad::<f64>::atan2(1.0, -1.0), Rad(0.75 * PI));
+
+        assert_eq!(format!("{}", Deg::<f32>(45.5)), "45.5°");
+        assert_eq!(format!("{}", Rad::<f64>(1.25)), "1.25 rad");
+    }
+
+    #[test]
+    fn test_angle_wrapping() {
+        assert_eq!(Deg::<f32>(370.0).normalized(), Deg(10.0));
+        assert_eq!(Deg::<f32>(-90.0).normalized(), Deg(270.0));
+        assert_eq!(Deg::<f32>(720.0).normalized(), Deg(0.0));
+        assert_eq!(Deg::<f32>(190.0).wrapped(), Deg(-170.0));
+        assert_eq!(Deg::<f32>(-180.0).wrapped(), Deg(-180.0));
+        assert_eq!(Deg::<f32>(180.0).wrapped(), Deg(-180.0));
+        assert_eq!(Deg::<f64>(-1e-20).normalized(), Deg(0.0));
+
+        for i in -50..50 {
+            let a = Rad::<f64>(i as f64 * 0.77);
+            let n = a.normalized();
+            let w = a.wrapped();
+            assert!(n >= Rad(0.0) && n < Rad::<f64>::full_turn(), "{}", n);
+            assert!(w >= -Rad::<f64>::half_turn() && w < Rad::<f64>::half_turn(), "{}", w);
+            assert_relative_eq!(n.sin_cos().0, a.sin_cos().0, epsilon = 1e-12);
+            assert_relative_eq!(w.sin_cos().1, a.sin_cos().1, epsilon = 1e-12);
+        }
+
+        let f = Deg::<I16F16>(I16F16::from_int(400)).normalized();
+        assert_eq!(f, Deg(I16F16::from_int(40)));
+    }
+
+    #[test]
+    fn test_angle_constructors() {
+        // degrees and radians build the same rotation
+        let v = Vec3::<f64>::new(1.0, 2.0, 3.0);
+        assert_relative_eq!(
+            Mat3::<f64>::from_rotation_z(Deg(90.0)) * v,
+            Mat3::<f64>::from_rotation_z(Rad(FRAC_PI_2)) * v
+        );
+        assert_relative_eq!(
+            Quat::<f64>::from_rotation_axis(Deg(90.0), Vec3::<f64>::unit_z()) * v,
+            Vec3::<f64>::new(-2.0, 1.0, 3.0),
+            epsilon = 1e-12
+        );
+        assert_eq!(
+            Mat4::<f32>::perspective(Deg(90.0), 1.0, 0.1, 10.0),
+            Mat4::<f32>::perspective(Rad::<f32>::quarter_turn(), 1.0, 0.1, 10.0)
+        );
+        assert_eq!(
+            Rot2::<f32>::from_rotation(Deg(180.0)),
+            Rot2::<f32>::from_rotation(Rad(core::f32::consts::PI))
+        );
+        assert_relative_eq!(
+            Rot3::<f64>::from_euler(Deg(10.0), Deg(20.0), Deg(30.0)),
+            Rot3::<f64>::from_euler(
+                Rad(10f64.to_radians()),
+                Rad(20f64.to_radians()),
+                Rad(30f64.to_radians())
+            ),
+            epsilon = 1e-15
+        );
+
+        let mut m = Mat2::<f32>::identity();
+        m.rotate(Deg(45.0));
+        m.rotate(Rad(core::f32::consts::FRAC_PI_4));
+        assert_abs_diff_eq!(m, Mat2::<f32>::from_rotation(Deg(90.0)), epsilon = 1e-6);
+    }
+}
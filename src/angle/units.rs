@@ -0,0 +1,203 @@
+use crate::*;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// shared by both units, `$turn` is a full turn in the unit
+macro_rules! impl_angle {
+    ($a:ident, $t:ident, $turn:expr) => {
+        impl $a<$t> {
+            pub fn zero() -> Self {
+                Self($t::zero())
+            }
+
+            pub fn full_turn() -> Self {
+                Self($turn)
+            }
+
+            pub fn half_turn() -> Self {
+                Self($turn * $t::splat(0.5))
+            }
+
+            pub fn quarter_turn() -> Self {
+                Self($turn * $t::splat(0.25))
+            }
+
+            // wrapped into [0, full turn)
+            pub fn normalized(&self) -> Self {
+                let turn = $turn;
+                let r = self.0 - Float::floor(self.0 / turn) * turn;
+                // a tiny negative angle can round up to a whole turn
+                if r >= turn {
+                    Self::zero()
+                } else {
+                    Self(r)
+                }
+            }
+
+            // wrapped into [-half turn, half turn)
+            pub fn wrapped(&self) -> Self {
+                (*self + Self::half_turn()).normalized() - Self::half_turn()
+            }
+
+            pub fn sin(&self) -> $t {
+                Float::sin(Rad::<$t>::from(*self).0)
+            }
+
+            pub fn cos(&self) -> $t {
+                Float::cos(Rad::<$t>::from(*self).0)
+            }
+
+            pub fn tan(&self) -> $t {
+                Float::tan(Rad::<$t>::from(*self).0)
+            }
+
+            pub fn sin_cos(&self) -> ($t, $t) {
+                Float::sin_cos(Rad::<$t>::from(*self).0)
+            }
+
+            pub fn asin(x: $t) -> Self {
+                Rad(Float::asin(x)).into()
+            }
+
+            pub fn acos(x: $t) -> Self {
+                Rad(Float::acos(x)).into()
+            }
+
+            pub fn atan2(y: $t, x: $t) -> Self {
+                Rad(Float::atan2(y, x)).into()
+            }
+        }
+
+        // addition
+        // ---------------------------------------------------------------------------------------------------
+        impl Add for $a<$t> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $a<$t> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        // subtraction
+        // ---------------------------------------------------------------------------------------------------
+        impl Sub for $a<$t> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $a<$t> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        // scalar multiplication
+        // ---------------------------------------------------------------------------------------------------
+        impl Mul<$t> for $a<$t> {
+            type Output = Self;
+            fn mul(self, rhs: $t) -> Self::Output {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$a<$t>> for $t {
+            type Output = $a<$t>;
+            fn mul(self, rhs: $a<$t>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl MulAssign<$t> for $a<$t> {
+            fn mul_assign(&mut self, rhs: $t) {
+                *self = *self * rhs;
+            }
+        }
+
+        // division, by a scalar or by another angle for the ratio between them
+        // ---------------------------------------------------------------------------------------------------
+        impl Div<$t> for $a<$t> {
+            type Output = Self;
+            fn div(self, rhs: $t) -> Self::Output {
+                Self(self.0 / rhs)
+            }
+        }
+
+        impl DivAssign<$t> for $a<$t> {
+            fn div_assign(&mut self, rhs: $t) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Div for $a<$t> {
+            type Output = $t;
+            fn div(self, rhs: Self) -> Self::Output {
+                self.0 / rhs.0
+            }
+        }
+
+        // negation
+        // ---------------------------------------------------------------------------------------------------
+        impl Neg for $a<$t> {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+macro_rules! impl_units {
+    ($t:ident, $pi:expr, $tau:expr) => {
+        impl_angle!(Rad, $t, $tau);
+        impl_angle!(Deg, $t, $t::splat(360.0));
+
+        impl Rad<$t> {
+            pub fn to_deg(&self) -> Deg<$t> {
+                Deg(self.0 * ($t::splat(180.0) / $pi))
+            }
+        }
+
+        impl Deg<$t> {
+            pub fn to_rad(&self) -> Rad<$t> {
+                Rad(self.0 * ($pi / $t::splat(180.0)))
+            }
+        }
+
+        impl From<Deg<$t>> for Rad<$t> {
+            fn from(d: Deg<$t>) -> Self {
+                d.to_rad()
+            }
+        }
+
+        impl From<Rad<$t>> for Deg<$t> {
+            fn from(r: Rad<$t>) -> Self {
+                r.to_deg()
+            }
+        }
+
+        // display
+        // ---------------------------------------------------------------------------------------------------
+        impl core::fmt::Display for Rad<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "{} rad", self.0)
+            }
+        }
+
+        impl core::fmt::Display for Deg<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "{}°", self.0)
+            }
+        }
+    };
+}
+
+impl_units!(f32, core::f32::consts::PI, core::f32::consts::TAU);
+impl_units!(f64, core::f64::consts::PI, core::f64::consts::TAU);
+impl_units!(I16F16, I16F16::PI, I16F16::TAU);
+impl_units!(I32F32, I32F32::PI, I32F32::TAU);
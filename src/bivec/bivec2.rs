@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
@@ -31,7 +31,7 @@ macro_rules! impl_bivec2 {
             }
 
             pub fn length(&self) -> $t {
-                self.dot(*self).sqrt()
+                Float::sqrt(self.dot(*self))
             }
 
             pub fn normalized(&self) -> Self {
@@ -45,8 +45,8 @@ macro_rules! impl_bivec2 {
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Bivec2<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for Bivec2<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 write!(f, "(xy): {:>8.4}", self[0])
             }
         }
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
@@ -47,11 +47,11 @@ macro_rules! impl_bivec3 {
             }
 
             pub fn length(&self) -> $t {
-                self.dot(*self).sqrt()
+                Float::sqrt(self.dot(*self))
             }
 
             pub fn normalized(&self) -> Self {
-                if self.length() == 0.0 {
+                if self.length() == $t::zero() {
                     *self
                 } else {
                     *self / self.length()
@@ -61,8 +61,8 @@ macro_rules! impl_bivec3 {
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Bivec3<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for Bivec3<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 write!(
                     f,
                     "(xy, xz, yz): {:>8.4} {:>8.4} {:>8.4}",
@@ -196,3 +196,5 @@ macro_rules! impl_bivec3 {
 
 impl_bivec3!(f32);
 impl_bivec3!(f64);
+impl_bivec3!(I16F16);
+impl_bivec3!(I32F32);
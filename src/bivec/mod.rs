@@ -4,13 +4,19 @@ pub use bivec2::*;
 pub use bivec3::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bivec2<S>(pub S);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Bivec2<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Bivec2<S> where S: bytemuck::Pod {}
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bivec3<S>(pub [S; 3]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Bivec3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Bivec3<S> where S: bytemuck::Pod {}
 
 // test
@@ -0,0 +1,154 @@
+use crate::*;
+
+// bounding volumes fitted to point sets. an empty set gives a zero sized volume at the origin, `Aabb3::from_points`
+// already gives the tight axis aligned box
+macro_rules! impl_fit {
+    ($t:ident) => {
+        impl Sphere<$t> {
+            // ritter's approximation: starts from two far apart points and grows to take in the rest in a single
+            // pass. usually within a few percent of the minimal sphere
+            pub fn ritter(points: &[Vec3<$t>]) -> Self {
+                let Some(&first) = points.first() else {
+                    return Self::default();
+                };
+                let farthest = |from: Vec3<$t>| {
+                    points.iter().fold(from, |best, &p| {
+                        if (p - from).length() > (best - from).length() {
+                            p
+                        } else {
+                            best
+                        }
+                    })
+                };
+                let a = farthest(first);
+                let b = farthest(a);
+                let sphere = Self((a + b) * $t::splat(0.5), (b - a).length() * $t::splat(0.5));
+                points.iter().fold(sphere, |sphere, &p| sphere.expanded(p))
+            }
+
+            // welzl's minimal bounding sphere, unrolled into loops over the points with up to four of them pinned to
+            // the surface. expected linear time when the points come in random order, sorted or gridded input
+            // benefits from shuffling first
+            pub fn welzl(points: &[Vec3<$t>]) -> Self {
+                let Some(&first) = points.first() else {
+                    return Self::default();
+                };
+                let mut sphere = Self(first, $t::zero());
+                for i in 1..points.len() {
+                    if sphere.encloses(points[i]) {
+                        continue;
+                    }
+                    sphere = Self(points[i], $t::zero());
+                    for j in 0..i {
+                        if sphere.encloses(points[j]) {
+                            continue;
+                        }
+                        sphere = Self::through2(points[i], points[j]);
+                        for k in 0..j {
+                            if sphere.encloses(points[k]) {
+                                continue;
+                            }
+                            sphere = Self::through3(points[i], points[j], points[k]);
+                            for l in 0..k {
+                                if !sphere.encloses(points[l]) {
+                                    sphere = Self::through4(points[i], points[j], points[k], points[l]);
+                                }
+                            }
+                        }
+                    }
+                }
+                sphere
+            }
+
+            // `contains` with some slack, so points the sphere was built through count as inside it
+            fn encloses(&self, point: Vec3<$t>) -> bool {
+                let slack = $t::EPSILON * $t::splat(64.0);
+                (point - self.0).length() <= self.1 + (self.1 + $t::one()) * slack
+            }
+
+            fn through2(a: Vec3<$t>, b: Vec3<$t>) -> Self {
+                Self((a + b) * $t::splat(0.5), (b - a).length() * $t::splat(0.5))
+            }
+
+            // the smallest sphere with all three on its surface, centered in their plane
+            fn through3(a: Vec3<$t>, b: Vec3<$t>, c: Vec3<$t>) -> Self {
+                let (ab, ac) = (b - a, c - a);
+                let n = ab.cross(ac);
+                let denom = n.dot(n) * $t::splat(2.0);
+                if denom <= ab.dot(ab) * ac.dot(ac) * $t::EPSILON {
+                    // collinear, the two farthest apart span the sphere
+                    let (bc, ab, ac) = (Self::through2(b, c), Self::through2(a, b), Self::through2(a, c));
+                    return [ab, ac]
+                        .into_iter()
+                        .fold(bc, |best, s| if s.1 > best.1 { s } else { best });
+                }
+                let offset = (n.cross(ab) * ac.dot(ac) + ac.cross(n) * ab.dot(ab)) / denom;
+                Self(a + offset, offset.length())
+            }
+
+            fn through4(a: Vec3<$t>, b: Vec3<$t>, c: Vec3<$t>, d: Vec3<$t>) -> Self {
+                let (ab, ac, ad) = (b - a, c - a, d - a);
+                let denom = ab.dot(ac.cross(ad)) * $t::splat(2.0);
+                let scale = ab.length() * ac.length() * ad.length();
+                if denom.abs() <= scale * $t::EPSILON {
+                    // coplanar, the smallest circle through three of them that holds the fourth
+                    let spheres = [
+                        Self::through3(a, b, c),
+                        Self::through3(a, b, d),
+                        Self::through3(a, c, d),
+                        Self::through3(b, c, d),
+                    ];
+                    let holds = |s: &Self| [a, b, c, d].iter().all(|&p| s.encloses(p));
+                    return spheres.into_iter().filter(holds).fold(spheres[0], |best, s| {
+                        if s.1 < best.1 || !holds(&best) {
+                            s
+                        } else {
+                            best
+                        }
+                    });
+                }
+                let offset =
+                    (ab.cross(ac) * ad.dot(ad) + ad.cross(ab) * ac.dot(ac) + ac.cross(ad) * ab.dot(ab)) / denom;
+                Self(a + offset, offset.length())
+            }
+        }
+
+        impl Obb3<$t> {
+            // principal component fit: the box is aligned with the eigenvectors of the points' covariance and then
+            // sized to take them all in. not minimal, but close for elongated or flat sets
+            pub fn from_points(points: &[Vec3<$t>]) -> Self {
+                if points.is_empty() {
+                    return Self(Vec3::<$t>::zero(), Vec3::<$t>::zero(), Quat::<$t>::identity());
+                }
+                let n = points.len() as $t;
+                let mean = points.iter().fold(Vec3::<$t>::zero(), |sum, &p| sum + p) / n;
+                let mut covariance = Mat3::<$t>::zero();
+                for &p in points {
+                    let d = p - mean;
+                    for col in 0..3 {
+                        covariance.0[col] += d * (d[col] / n);
+                    }
+                }
+
+                let (_, axes) = covariance.symmetric_eigen();
+                let (mut min, mut max) = (
+                    Vec3::<$t>::one() * $t::INFINITY,
+                    Vec3::<$t>::one() * $t::NEG_INFINITY,
+                );
+                for &p in points {
+                    let d = p - mean;
+                    for i in 0..3 {
+                        let x = d.dot(axes.0[i]);
+                        min[i] = min[i].min(x);
+                        max[i] = max[i].max(x);
+                    }
+                }
+                let center = mean + axes * ((min + max) * $t::splat(0.5));
+                Self::from_mat3(center, (max - min) * $t::splat(0.5), axes)
+            }
+        }
+    };
+}
+
+impl_fit!(f32);
+impl_fit!(f64);
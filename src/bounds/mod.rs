@@ -0,0 +1,155 @@
+mod fit;
+mod obb3;
+mod sphere;
+
+use crate::{Quat, Vec3};
+
+// center, half extents along the box's own axes, and the rotation taking the world axes onto them
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Obb3<S>(pub Vec3<S>, pub Vec3<S>, pub Quat<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Obb3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Obb3<S> where S: bytemuck::Pod {}
+
+// center and radius
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere<S>(pub Vec3<S>, pub S);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Sphere<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Sphere<S> where S: bytemuck::Pod {}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn grid() -> impl Iterator<Item = Vec3<f64>> {
+        (0..125)
+            .map(|i| Vec3::<f64>::new((i % 5) as f64, (i / 5 % 5) as f64, (i / 25) as f64) * 0.5 - Vec3::<f64>::one())
+    }
+
+    #[test]
+    fn test_obb3() {
+        let q = Quat::<f64>::from_rotation_axis(Deg(30.0), Vec3::<f64>::new(1.0, 2.0, 2.0).normalized());
+        let obb = Obb3::<f64>::new(Vec3::<f64>::new(1.0, 0.0, -2.0), Vec3::<f64>::new(2.0, 1.0, 0.5), q);
+        assert_abs_diff_eq!(
+            Obb3::<f64>::from_mat3(obb.0, obb.1, obb.to_mat3()),
+            obb,
+            epsilon = 1e-12
+        );
+        assert_eq!(obb.volume(), 8.0);
+
+        let corners = obb.corners();
+        assert_abs_diff_eq!(obb.to_aabb(), Aabb3::<f64>::from_points(&corners), epsilon = 1e-12);
+        assert!(corners.iter().all(|&c| obb.contains(obb.0 + (c - obb.0) * 0.999)));
+        assert!(!obb.contains(obb.0 + (corners[5] - obb.0) * 1.001));
+        assert!(corners
+            .iter()
+            .all(|&c| (obb.closest_point(c + (c - obb.0)) - c).length() < 1e-12));
+        assert_eq!(obb.closest_point(obb.0), obb.0);
+
+        let aabb = Aabb3::<f64>::new(Vec3::<f64>::new(-1.0, -1.0, -1.0), Vec3::<f64>::new(0.0, 0.0, 0.0));
+        assert!(Obb3::<f64>::from_aabb(aabb).intersects_aabb(aabb));
+        assert!(obb.intersects_aabb(aabb.merged(Aabb3::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::one()))));
+        assert!(!obb.intersects_aabb(Aabb3::<f64>::new(
+            Vec3::<f64>::new(5.0, 5.0, 5.0),
+            Vec3::<f64>::new(6.0, 6.0, 6.0)
+        )));
+    }
+
+    #[test]
+    fn test_obb3_edge_edge() {
+        // two cubes meeting edge on, with their edges along z and y, only the cross product of the edges
+        // separates them
+        let half = Vec3::<f64>::one();
+        let a = Obb3::<f64>::new(
+            Vec3::<f64>::zero(),
+            half,
+            Quat::<f64>::from_rotation_axis(Deg(45.0), Vec3::<f64>::unit_z()),
+        );
+        let b_rotation = Quat::<f64>::from_rotation_axis(Deg(45.0), Vec3::<f64>::unit_y());
+        let reach = 2f64.sqrt() * 2.0;
+        let apart = Obb3::<f64>::new(Vec3::<f64>::new(reach + 0.01, 0.0, 0.0), half, b_rotation);
+        let touching = Obb3::<f64>::new(Vec3::<f64>::new(reach - 0.01, 0.0, 0.0), half, b_rotation);
+        assert!(!a.intersects(apart) && !apart.intersects(a));
+        assert!(a.intersects(touching) && touching.intersects(a));
+        // none of the face normals has a gap
+        for axis in a.axes().into_iter().chain(apart.axes()) {
+            let range = |o: Obb3<f64>| {
+                let d = o.corners().map(|c| c.dot(axis));
+                (
+                    d.iter().copied().fold(f64::MAX, f64::min),
+                    d.iter().copied().fold(f64::MIN, f64::max),
+                )
+            };
+            let ((a_min, a_max), (b_min, b_max)) = (range(a), range(apart));
+            assert!(a_min <= b_max && b_min <= a_max);
+        }
+    }
+
+    #[test]
+    fn test_bounding_spheres() {
+        assert_eq!(Sphere::<f64>::welzl(&[]), Sphere::<f64>::default());
+        let points: Vec<Vec3<f64>> = grid().collect();
+        let ritter = Sphere::<f64>::ritter(&points);
+        let welzl = Sphere::<f64>::welzl(&points);
+        assert!(points.iter().all(|&p| ritter.contains(p)));
+        assert!(points.iter().all(|&p| (p - welzl.0).length() <= welzl.1 + 1e-12));
+        assert!(welzl.1 <= ritter.1);
+        assert_abs_diff_eq!(
+            welzl,
+            Sphere::<f64>::new(Vec3::<f64>::zero(), 3f64.sqrt()),
+            epsilon = 1e-12
+        );
+
+        // a regular tetrahedron pins four points, a triangle three
+        let tetra = [
+            Vec3::<f64>::new(1.0, 1.0, 1.0),
+            Vec3::<f64>::new(1.0, -1.0, -1.0),
+            Vec3::<f64>::new(-1.0, 1.0, -1.0),
+            Vec3::<f64>::new(-1.0, -1.0, 1.0),
+        ];
+        let mut with_inside = tetra.to_vec();
+        with_inside.insert(2, Vec3::<f64>::new(0.1, 0.2, -0.3));
+        assert_abs_diff_eq!(
+            Sphere::<f64>::welzl(&with_inside),
+            Sphere::<f64>::new(Vec3::<f64>::zero(), 3f64.sqrt()),
+            epsilon = 1e-12
+        );
+        let triangle = [
+            Vec3::<f64>::new(1.0, 0.0, 0.0),
+            Vec3::<f64>::new(-0.5, 0.8, 0.0),
+            Vec3::<f64>::new(-0.5, -0.8, 0.0),
+        ];
+        let s = Sphere::<f64>::welzl(&triangle);
+        assert!(triangle.iter().all(|&p| ((p - s.0).length() - s.1).abs() < 1e-12));
+        assert!(s.0[2] == 0.0 && s.1 < 1.0);
+
+        assert_eq!(
+            Sphere::<f64>::new(Vec3::<f64>::zero(), 1.0).expanded(Vec3::<f64>::new(3.0, 0.0, 0.0)),
+            Sphere::<f64>::new(Vec3::<f64>::new(1.0, 0.0, 0.0), 2.0)
+        );
+    }
+
+    #[test]
+    fn test_obb3_fit() {
+        let q = Quat::<f64>::from_rotation_axis(Deg(40.0), Vec3::<f64>::new(-1.0, 3.0, 0.5).normalized());
+        let center = Vec3::<f64>::new(5.0, -1.0, 2.0);
+        let points: Vec<Vec3<f64>> = grid()
+            .map(|p| center + q * (p * Vec3::<f64>::new(3.0, 0.5, 1.5)))
+            .collect();
+
+        let obb = Obb3::<f64>::from_points(&points);
+        assert_abs_diff_eq!(obb.0, center, epsilon = 1e-9);
+        assert_abs_diff_eq!(obb.1, Vec3::<f64>::new(3.0, 1.5, 0.5), epsilon = 1e-9);
+        assert!((obb.volume() - 18.0).abs() < 1e-9);
+        assert!(points.iter().all(|&p| obb.contains(obb.0 + (p - obb.0) * (1.0 - 1e-9))));
+        assert!(obb.volume() < Aabb3::<f64>::from_points(&points).volume());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::*;
+
+macro_rules! impl_obb3 {
+    ($t:ident) => {
+        impl Obb3<$t> {
+            pub fn new(center: Vec3<$t>, half_extents: Vec3<$t>, rotation: Quat<$t>) -> Self {
+                Self(center, half_extents, rotation)
+            }
+
+            // `axes` has to be a rotation, its columns are the box's axes
+            pub fn from_mat3(center: Vec3<$t>, half_extents: Vec3<$t>, axes: Mat3<$t>) -> Self {
+                Self(center, half_extents, Quat::<$t>::from_mat3(axes))
+            }
+
+            pub fn from_aabb(aabb: Aabb3<$t>) -> Self {
+                Self(aabb.center(), aabb.half_extents(), Quat::<$t>::identity())
+            }
+
+            pub fn center(&self) -> Vec3<$t> {
+                self.0
+            }
+
+            pub fn half_extents(&self) -> Vec3<$t> {
+                self.1
+            }
+
+            pub fn rotation(&self) -> Quat<$t> {
+                self.2
+            }
+
+            // the box's local x, y and z axes in world space
+            pub fn axes(&self) -> [Vec3<$t>; 3] {
+                [Vec3::<$t>::unit_x(), Vec3::<$t>::unit_y(), Vec3::<$t>::unit_z()].map(|axis| self.2 * axis)
+            }
+
+            pub fn to_mat3(&self) -> Mat3<$t> {
+                Mat3::<$t>(self.axes())
+            }
+
+            pub fn volume(&self) -> $t {
+                self.1[0] * self.1[1] * self.1[2] * $t::splat(8.0)
+            }
+
+            // in the order of the bits of the index, x lowest, with a set bit on the positive side
+            pub fn corners(&self) -> [Vec3<$t>; 8] {
+                let [x, y, z] = self.axes();
+                core::array::from_fn(|i| {
+                    let sign = |bit: usize| if i & bit == 0 { -$t::one() } else { $t::one() };
+                    self.0 + x * (self.1[0] * sign(1)) + y * (self.1[1] * sign(2)) + z * (self.1[2] * sign(4))
+                })
+            }
+
+            // the tightest axis aligned box around it
+            pub fn to_aabb(&self) -> Aabb3<$t> {
+                let [x, y, z] = self.axes();
+                let reach = |i: usize| x[i].abs() * self.1[0] + y[i].abs() * self.1[1] + z[i].abs() * self.1[2];
+                let reach = Vec3::<$t>::new(reach(0), reach(1), reach(2));
+                Aabb3::<$t>::new(self.0 - reach, self.0 + reach)
+            }
+
+            pub fn contains(&self, point: Vec3<$t>) -> bool {
+                let d = point - self.0;
+                let axes = self.axes();
+                (0..3).all(|i| d.dot(axes[i]).abs() <= self.1[i])
+            }
+
+            pub fn closest_point(&self, point: Vec3<$t>) -> Vec3<$t> {
+                let d = point - self.0;
+                let axes = self.axes();
+                (0..3).fold(self.0, |p, i| {
+                    p + axes[i] * d.dot(axes[i]).max(-self.1[i]).min(self.1[i])
+                })
+            }
+
+            pub fn distance_to_point(&self, point: Vec3<$t>) -> $t {
+                (self.closest_point(point) - point).length()
+            }
+
+            // separating axis test over the 3 + 3 face normals and the 9 edge pairs
+            pub fn intersects(&self, rhs: Self) -> bool {
+                let (a, b) = (self.axes(), rhs.axes());
+                let (ea, eb) = (self.1, rhs.1);
+                let d = rhs.0 - self.0;
+                let t = [d.dot(a[0]), d.dot(a[1]), d.dot(a[2])];
+                let r = [0, 1, 2].map(|i| [0, 1, 2].map(|j| a[i].dot(b[j])));
+                // the epsilon keeps near parallel edges, whose cross product is next to zero, from finding a gap
+                let abs_r = r.map(|row| row.map(|x| x.abs() + $t::EPSILON * $t::splat(8.0)));
+
+                for i in 0..3 {
+                    let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+                    if t[i].abs() > ea[i] + rb {
+                        return false;
+                    }
+                }
+                for j in 0..3 {
+                    let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+                    let dist = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+                    if dist.abs() > ra + eb[j] {
+                        return false;
+                    }
+                }
+                for i in 0..3 {
+                    let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+                    for j in 0..3 {
+                        let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                        let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                        let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                        if (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb {
+                            return false;
+                        }
+                    }
+                }
+                true
+            }
+
+            pub fn intersects_aabb(&self, aabb: Aabb3<$t>) -> bool {
+                self.intersects(Self::from_aabb(aabb))
+            }
+        }
+
+        // display
+        // ---------------------------------------------------------------------------------------------------
+        impl core::fmt::Display for Obb3<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(
+                    f,
+                    "(center, half extents, rotation): [{}, {}, {}]",
+                    self.0, self.1, self.2
+                )
+            }
+        }
+    };
+}
+
+impl_obb3!(f32);
+impl_obb3!(f64);
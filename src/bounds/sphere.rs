@@ -0,0 +1,58 @@
+use crate::*;
+
+macro_rules! impl_sphere {
+    ($t:ident) => {
+        impl Sphere<$t> {
+            pub fn new(center: Vec3<$t>, radius: $t) -> Self {
+                Self(center, radius)
+            }
+
+            pub fn center(&self) -> Vec3<$t> {
+                self.0
+            }
+
+            pub fn radius(&self) -> $t {
+                self.1
+            }
+
+            pub fn volume(&self) -> $t {
+                core::$t::consts::PI * $t::splat(4.0) / $t::splat(3.0) * self.1 * self.1 * self.1
+            }
+
+            pub fn to_aabb(&self) -> Aabb3<$t> {
+                let reach = Vec3::<$t>::one() * self.1;
+                Aabb3::<$t>::new(self.0 - reach, self.0 + reach)
+            }
+
+            pub fn contains(&self, point: Vec3<$t>) -> bool {
+                (point - self.0).length() <= self.1
+            }
+
+            pub fn intersects(&self, rhs: Self) -> bool {
+                (rhs.0 - self.0).length() <= self.1 + rhs.1
+            }
+
+            // the smallest sphere around this one and `point`
+            pub fn expanded(&self, point: Vec3<$t>) -> Self {
+                let d = point - self.0;
+                let dist = d.length();
+                if dist <= self.1 {
+                    return *self;
+                }
+                let radius = (self.1 + dist) * $t::splat(0.5);
+                Self(self.0 + d * ((radius - self.1) / dist), radius)
+            }
+        }
+
+        // display
+        // ---------------------------------------------------------------------------------------------------
+        impl core::fmt::Display for Sphere<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "(center, radius): [{}, {}]", self.0, self.1)
+            }
+        }
+    };
+}
+
+impl_sphere!(f32);
+impl_sphere!(f64);
@@ -0,0 +1,388 @@
+use super::{Node, NodeKind};
+use crate::*;
+use alloc::vec::Vec;
+
+// buckets the centroids fall into along each axis when looking for the cheapest split
+const BINS: usize = 12;
+
+impl<T, S> Bvh<T, S> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            items: Vec::new(),
+            free_nodes: Vec::new(),
+            free_items: Vec::new(),
+            root: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len() - self.free_items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.items.get(id)?.as_ref().map(|(item, _)| item)
+    }
+
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut T> {
+        self.items.get_mut(id)?.as_mut().map(|(item, _)| item)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(id, item)| Some((id, &item.as_ref()?.0)))
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    // the leaf of a live item
+    fn leaf(&self, id: usize) -> Option<usize> {
+        let (_, leaf) = self.items.get(id)?.as_ref()?;
+        Some(*leaf)
+    }
+
+    fn alloc_node(&mut self, node: Node<S>) -> usize {
+        match self.free_nodes.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn free_node(&mut self, index: usize) {
+        self.nodes[index].kind = NodeKind::Free;
+        self.free_nodes.push(index);
+    }
+
+    fn alloc_item(&mut self, item: T, leaf: usize) -> usize {
+        match self.free_items.pop() {
+            Some(id) => {
+                self.items[id] = Some((item, leaf));
+                id
+            }
+            None => {
+                self.items.push(Some((item, leaf)));
+                self.items.len() - 1
+            }
+        }
+    }
+
+    // points whichever of `parent`'s links led to `old` at `new` instead, or the root when there is no parent
+    fn relink(&mut self, parent: Option<usize>, old: usize, new: usize) {
+        match parent {
+            Some(p) => {
+                if let NodeKind::Branch(left, right) = &mut self.nodes[p].kind {
+                    if *left == old {
+                        *left = new;
+                    } else if *right == old {
+                        *right = new;
+                    }
+                }
+            }
+            None => self.root = Some(new),
+        }
+    }
+
+    // takes a leaf out of the tree without freeing it, its sibling moves up in place of their parent
+    fn detach(&mut self, leaf: usize) -> Option<usize> {
+        let Some(parent) = self.nodes[leaf].parent else {
+            self.root = None;
+            return None;
+        };
+        let sibling = match self.nodes[parent].kind {
+            NodeKind::Branch(left, right) if left == leaf => right,
+            NodeKind::Branch(left, _) => left,
+            _ => unreachable!("a leaf's parent is a branch"),
+        };
+        let grandparent = self.nodes[parent].parent;
+        self.nodes[sibling].parent = grandparent;
+        self.relink(grandparent, parent, sibling);
+        self.nodes[leaf].parent = None;
+        self.free_node(parent);
+        grandparent
+    }
+}
+
+impl<T, S> Default for Bvh<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_bvh_build {
+    ($t:ident) => {
+        impl<T> Bvh<T, $t> {
+            // top down, splitting each set of leaves where the surface area heuristic says it's cheapest to test
+            // rays against. ids are handed out in order starting from 0
+            pub fn build(items: impl IntoIterator<Item = (T, Aabb3<$t>)>) -> Self {
+                let mut bvh = Self::new();
+                let mut leaves = Vec::new();
+                for (item, bounds) in items {
+                    let leaf = bvh.alloc_node(Node {
+                        bounds,
+                        parent: None,
+                        kind: NodeKind::Leaf(bvh.items.len()),
+                    });
+                    bvh.items.push(Some((item, leaf)));
+                    leaves.push(leaf);
+                }
+                if leaves.is_empty() {
+                    return bvh;
+                }
+
+                // ranges of `leaves` still to split, with the branch and side they hang from
+                let mut pending = Vec::from([(0, leaves.len(), None)]);
+                while let Some((start, end, parent)) = pending.pop() {
+                    let range = &mut leaves[start..end];
+                    let node = if range.len() == 1 {
+                        range[0]
+                    } else {
+                        let mid = start + bvh.partition(range);
+                        let bounds = range
+                            .iter()
+                            .fold(Aabb3::<$t>::empty(), |b, &leaf| b.merged(bvh.nodes[leaf].bounds));
+                        let node = bvh.alloc_node(Node {
+                            bounds,
+                            parent: None,
+                            kind: NodeKind::Branch(usize::MAX, usize::MAX),
+                        });
+                        pending.push((start, mid, Some((node, false))));
+                        pending.push((mid, end, Some((node, true))));
+                        node
+                    };
+                    match parent {
+                        Some((p, right)) => {
+                            bvh.nodes[node].parent = Some(p);
+                            if let NodeKind::Branch(l, r) = &mut bvh.nodes[p].kind {
+                                *(if right { r } else { l }) = node;
+                            }
+                        }
+                        None => bvh.root = Some(node),
+                    }
+                }
+                bvh
+            }
+
+            // reorders the leaves so the ones before the returned count go left of the cheapest split, which is
+            // always somewhere strictly inside
+            fn partition(&self, leaves: &mut [usize]) -> usize {
+                let center = |leaf: usize| self.nodes[leaf].bounds.center();
+                let centers = leaves
+                    .iter()
+                    .fold(Aabb3::<$t>::empty(), |b, &leaf| b.expanded(center(leaf)));
+                let extent = centers.size();
+                let bin = |leaf: usize, axis: usize| {
+                    let x = (center(leaf)[axis] - centers.0[axis]) / extent[axis] * (BINS as $t);
+                    (x as usize).min(BINS - 1)
+                };
+
+                let mut best: Option<($t, usize, usize)> = None;
+                for axis in 0..3 {
+                    if extent[axis] <= $t::zero() {
+                        continue;
+                    }
+                    let mut bins = [(Aabb3::<$t>::empty(), 0usize); BINS];
+                    for &leaf in leaves.iter() {
+                        let b = &mut bins[bin(leaf, axis)];
+                        *b = (b.0.merged(self.nodes[leaf].bounds), b.1 + 1);
+                    }
+                    // area times count of everything right of each split, then swept in from the left
+                    let mut right = [$t::zero(); BINS];
+                    let mut acc = (Aabb3::<$t>::empty(), 0);
+                    for split in (1..BINS).rev() {
+                        acc = (acc.0.merged(bins[split].0), acc.1 + bins[split].1);
+                        right[split] = if acc.1 > 0 {
+                            acc.0.surface_area() * (acc.1 as $t)
+                        } else {
+                            $t::zero()
+                        };
+                    }
+                    let mut acc = (Aabb3::<$t>::empty(), 0);
+                    for split in 1..BINS {
+                        acc = (acc.0.merged(bins[split - 1].0), acc.1 + bins[split - 1].1);
+                        if acc.1 == 0 || acc.1 == leaves.len() {
+                            continue;
+                        }
+                        let cost = acc.0.surface_area() * (acc.1 as $t) + right[split];
+                        if best.map_or(true, |(c, _, _)| cost < c) {
+                            best = Some((cost, axis, split));
+                        }
+                    }
+                }
+
+                // all the centers in one spot, any even split does
+                let Some((_, axis, split)) = best else {
+                    return leaves.len() / 2;
+                };
+                let mut mid = 0;
+                for i in 0..leaves.len() {
+                    if bin(leaves[i], axis) < split {
+                        leaves.swap(i, mid);
+                        mid += 1;
+                    }
+                }
+                mid
+            }
+
+            pub fn insert(&mut self, item: T, bounds: Aabb3<$t>) -> usize {
+                let leaf = self.alloc_node(Node {
+                    bounds,
+                    parent: None,
+                    kind: NodeKind::Free,
+                });
+                let id = self.alloc_item(item, leaf);
+                self.nodes[leaf].kind = NodeKind::Leaf(id);
+                self.attach(leaf);
+                id
+            }
+
+            pub fn remove(&mut self, id: usize) -> Option<T> {
+                let (item, leaf) = self.items.get_mut(id)?.take()?;
+                self.free_items.push(id);
+                let parent = self.detach(leaf);
+                self.refit_up(parent);
+                self.free_node(leaf);
+                Some(item)
+            }
+
+            pub fn bounds(&self, id: usize) -> Option<Aabb3<$t>> {
+                Some(self.nodes[self.leaf(id)?].bounds)
+            }
+
+            // moves an item to where its new bounds fit best, for items that moved far or grew. false if there's
+            // no item with the id, like one that was removed
+            pub fn update(&mut self, id: usize, bounds: Aabb3<$t>) -> bool {
+                let Some(leaf) = self.leaf(id) else {
+                    return false;
+                };
+                let parent = self.detach(leaf);
+                self.refit_up(parent);
+                self.nodes[leaf].bounds = bounds;
+                self.attach(leaf);
+                true
+            }
+
+            // changes an item's bounds and keeps the tree as is, `refit` then brings the branches up to date. cheaper
+            // than `update` when lots of items move a little every frame, but queries are slower once they've moved
+            // away from where the tree was built for them
+            pub fn set_bounds(&mut self, id: usize, bounds: Aabb3<$t>) -> bool {
+                let Some(leaf) = self.leaf(id) else {
+                    return false;
+                };
+                self.nodes[leaf].bounds = bounds;
+                true
+            }
+
+            // recomputes every branch's bounds from its children
+            pub fn refit(&mut self) {
+                let Some(root) = self.root else {
+                    return;
+                };
+                // parents come before their children in this order, so going backwards does the children first
+                let mut order = Vec::with_capacity(self.nodes.len());
+                order.push(root);
+                let mut i = 0;
+                while i < order.len() {
+                    if let NodeKind::Branch(left, right) = self.nodes[order[i]].kind {
+                        order.push(left);
+                        order.push(right);
+                    }
+                    i += 1;
+                }
+                for &node in order.iter().rev() {
+                    if let NodeKind::Branch(left, right) = self.nodes[node].kind {
+                        self.nodes[node].bounds = self.nodes[left].bounds.merged(self.nodes[right].bounds);
+                    }
+                }
+            }
+
+            // the cost of the tree by the surface area heuristic, the summed surface area of the branches relative to
+            // the root's. lower is better for queries, comparing it before and after changes shows how much the tree
+            // has worn down
+            pub fn cost(&self) -> $t {
+                let Some(root) = self.root else {
+                    return $t::zero();
+                };
+                let total = self
+                    .nodes
+                    .iter()
+                    .filter(|n| matches!(n.kind, NodeKind::Branch(..)))
+                    .fold($t::zero(), |sum, n| sum + n.bounds.surface_area());
+                let root_area = self.nodes[root].bounds.surface_area();
+                if root_area > $t::zero() {
+                    total / root_area
+                } else {
+                    $t::zero()
+                }
+            }
+
+            // walks down to the sibling that makes the new parent cheapest, then refits back up
+            fn attach(&mut self, leaf: usize) {
+                let Some(root) = self.root else {
+                    self.root = Some(leaf);
+                    self.nodes[leaf].parent = None;
+                    return;
+                };
+                let bounds = self.nodes[leaf].bounds;
+                let two = $t::splat(2.0);
+                let mut sibling = root;
+                while let NodeKind::Branch(left, right) = self.nodes[sibling].kind {
+                    let area = self.nodes[sibling].bounds.surface_area();
+                    let combined = self.nodes[sibling].bounds.merged(bounds).surface_area();
+                    // a new parent here, against pushing the leaf further down, which grows this branch either way
+                    let here = two * combined;
+                    let inherited = two * (combined - area);
+                    let descend = |child: usize| {
+                        let node = &self.nodes[child];
+                        let merged = node.bounds.merged(bounds).surface_area();
+                        match node.kind {
+                            NodeKind::Leaf(_) => merged + inherited,
+                            _ => merged - node.bounds.surface_area() + inherited,
+                        }
+                    };
+                    let (left_cost, right_cost) = (descend(left), descend(right));
+                    if here < left_cost && here < right_cost {
+                        break;
+                    }
+                    sibling = if left_cost < right_cost { left } else { right };
+                }
+
+                let old_parent = self.nodes[sibling].parent;
+                let parent = self.alloc_node(Node {
+                    bounds: self.nodes[sibling].bounds.merged(bounds),
+                    parent: old_parent,
+                    kind: NodeKind::Branch(sibling, leaf),
+                });
+                self.nodes[sibling].parent = Some(parent);
+                self.nodes[leaf].parent = Some(parent);
+                self.relink(old_parent, sibling, parent);
+                self.refit_up(old_parent);
+            }
+
+            fn refit_up(&mut self, mut node: Option<usize>) {
+                while let Some(n) = node {
+                    if let NodeKind::Branch(left, right) = self.nodes[n].kind {
+                        self.nodes[n].bounds = self.nodes[left].bounds.merged(self.nodes[right].bounds);
+                    }
+                    node = self.nodes[n].parent;
+                }
+            }
+        }
+    };
+}
+
+impl_bvh_build!(f32);
+impl_bvh_build!(f64);
@@ -0,0 +1,59 @@
+use super::NodeKind;
+use crate::*;
+use alloc::vec::Vec;
+
+macro_rules! impl_bvh_flat {
+    ($t:ident) => {
+        impl<T> Bvh<T, $t> {
+            // the tree in depth first order for stackless traversal: test a node, and on a hit go on to the next one
+            // (after testing the item for a leaf), otherwise go to `skip`. bounds are rounded outwards to f32
+            pub fn flatten(&self) -> Vec<FlatBvhNode> {
+                let Some(root) = self.root else {
+                    return Vec::new();
+                };
+                let outward = |x: $t, up: bool| {
+                    let r = x as f32;
+                    match (r as $t).partial_cmp(&x) {
+                        Some(core::cmp::Ordering::Less) if up => r.next_up(),
+                        Some(core::cmp::Ordering::Greater) if !up => r.next_down(),
+                        _ => r,
+                    }
+                };
+
+                let mut flat = Vec::with_capacity(self.nodes.len() - self.free_nodes.len());
+                let mut stack = Vec::from([root]);
+                while let Some(node) = stack.pop() {
+                    let Aabb3(min, max) = self.nodes[node].bounds;
+                    let item = match self.nodes[node].kind {
+                        NodeKind::Leaf(id) => id as u32,
+                        NodeKind::Branch(left, right) => {
+                            stack.extend([right, left]);
+                            u32::MAX
+                        }
+                        NodeKind::Free => unreachable!("free nodes aren't in the tree"),
+                    };
+                    flat.push(FlatBvhNode {
+                        min: [0, 1, 2].map(|i| outward(min[i], false)),
+                        item,
+                        max: [0, 1, 2].map(|i| outward(max[i], true)),
+                        skip: 0,
+                    });
+                }
+
+                // a subtree is its root, then its left subtree, then its right one
+                let mut size = alloc::vec![1usize; flat.len()];
+                for i in (0..flat.len()).rev() {
+                    if flat[i].item == u32::MAX {
+                        let left = size[i + 1];
+                        size[i] += left + size[i + 1 + left];
+                    }
+                    flat[i].skip = (i + size[i]) as u32;
+                }
+                flat
+            }
+        }
+    };
+}
+
+impl_bvh_flat!(f32);
+impl_bvh_flat!(f64);
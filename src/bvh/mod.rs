@@ -0,0 +1,196 @@
+mod build;
+mod flat;
+mod query;
+
+use crate::Aabb3;
+use alloc::vec::Vec;
+
+// a bounding volume hierarchy over items with axis aligned bounds, one item per leaf. items are addressed by the id
+// `insert` or `build` hands out, which stays the same until the item is removed and may be reused after that
+#[derive(Debug, Clone)]
+pub struct Bvh<T, S> {
+    nodes: Vec<Node<S>>,
+    // each item with its leaf
+    items: Vec<Option<(T, usize)>>,
+    free_nodes: Vec<usize>,
+    free_items: Vec<usize>,
+    root: Option<usize>,
+}
+
+// a node of the flattened tree, 32 bytes laid out for a gpu storage buffer. nodes are in depth first order, so a
+// branch's first child comes right after it, and `skip` is where to carry on once the node's subtree is done with or
+// missed, the node count when there is nothing left. `item` is a leaf's item id, or `u32::MAX` for a branch
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct FlatBvhNode {
+    pub min: [f32; 3],
+    pub item: u32,
+    pub max: [f32; 3],
+    pub skip: u32,
+}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for FlatBvhNode {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for FlatBvhNode {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Node<S> {
+    bounds: Aabb3<S>,
+    parent: Option<usize>,
+    kind: NodeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NodeKind {
+    Branch(usize, usize),
+    Leaf(usize),
+    Free,
+}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    // boxes of half size 0.25 on the integer points of an n cube
+    fn grid(n: usize) -> Vec<Aabb3<f64>> {
+        (0..n * n * n)
+            .map(|i| {
+                let c = Vec3::<f64>::new((i % n) as f64, (i / n % n) as f64, (i / (n * n)) as f64);
+                Aabb3::<f64>::new(c - Vec3::<f64>::one() * 0.25, c + Vec3::<f64>::one() * 0.25)
+            })
+            .collect()
+    }
+
+    fn found(
+        bvh: &Bvh<Aabb3<f64>, f64>,
+        query: impl Fn(&Bvh<Aabb3<f64>, f64>, &mut dyn FnMut(usize, &Aabb3<f64>)),
+    ) -> Vec<usize> {
+        let mut ids = Vec::new();
+        query(bvh, &mut |id, _| ids.push(id));
+        ids.sort();
+        ids
+    }
+
+    fn brute(bvh: &Bvh<Aabb3<f64>, f64>, keep: impl Fn(&Aabb3<f64>) -> bool) -> Vec<usize> {
+        bvh.iter().filter(|(_, b)| keep(b)).map(|(id, _)| id).collect()
+    }
+
+    #[test]
+    fn test_bvh_queries() {
+        let boxes = grid(5);
+        let bvh = Bvh::<Aabb3<f64>, f64>::build(boxes.iter().map(|&b| (b, b)));
+        assert_eq!(bvh.len(), 125);
+        assert_eq!(bvh.bounds(7), Some(boxes[7]));
+
+        let aabb = Aabb3::<f64>::new(Vec3::<f64>::new(0.5, 1.5, -1.0), Vec3::<f64>::new(2.6, 3.0, 1.1));
+        let ids = found(&bvh, |t, f| t.query_aabb(aabb, f));
+        assert_eq!(ids, brute(&bvh, |b| b.intersects(aabb)));
+        assert_eq!(ids.len(), 2 * 2 * 2);
+
+        let sphere = Sphere::<f64>::new(Vec3::<f64>::new(2.0, 2.0, 2.0), 1.2);
+        let ids = found(&bvh, |t, f| t.query_sphere(sphere, f));
+        assert_eq!(ids, brute(&bvh, |b| b.distance_to_point(sphere.0) <= sphere.1));
+        assert_eq!(ids.len(), 1 + 6 + 12);
+
+        // looking down -z from above the middle of the grid
+        let view = Mat4::<f64>::from_translation(Vec3::<f64>::new(-2.0, -2.0, -10.0));
+        let frustum = Frustum::<f64>::from_matrix(Mat4::<f64>::perspective(Deg(10.0), 1.0, 0.1, 100.0) * view);
+        let ids = found(&bvh, |t, f| t.query_frustum(frustum, f));
+        assert_eq!(ids, brute(&bvh, |b| frustum.intersects_aabb(*b)));
+        assert!(ids.contains(&(2 + 2 * 5 + 4 * 25)) && !ids.contains(&0));
+
+        let ray = Ray::<f64>::new(Vec3::<f64>::new(-5.0, 2.0, 3.0), Vec3::<f64>::new(1.0, 0.0, 0.0));
+        let hit = bvh.cast_ray(ray, 100.0, |_, b| ray.intersect_aabb(*b, 100.0));
+        assert_eq!(hit, Some((2 * 5 + 3 * 25, 4.75)));
+        assert_eq!(bvh.cast_ray(ray, 4.0, |_, b| ray.intersect_aabb(*b, 4.0)), None);
+        let miss = Ray::<f64>::new(Vec3::<f64>::new(-5.0, 2.5, 3.0), Vec3::<f64>::new(1.0, 0.0, 0.0));
+        assert_eq!(bvh.cast_ray(miss, 100.0, |_, b| miss.intersect_aabb(*b, 100.0)), None);
+
+        let point = Vec3::<f64>::new(2.1, 3.9, 0.2);
+        let (id, dist) = bvh.nearest(point, 10.0, |_, b| (b.center() - point).length()).unwrap();
+        assert_eq!(id, 2 + 4 * 5);
+        assert!((dist - 0.06f64.sqrt()).abs() < 1e-12);
+        assert_eq!(
+            bvh.nearest(Vec3::<f64>::one() * 10.0, 1.0, |_, b| (b.center() - point).length()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bvh_insert_remove() {
+        let boxes = grid(4);
+        let mut bvh = Bvh::<Aabb3<f64>, f64>::new();
+        for (i, &b) in boxes.iter().enumerate() {
+            assert_eq!(bvh.insert(b, b), i);
+        }
+        let built = Bvh::<Aabb3<f64>, f64>::build(boxes.iter().map(|&b| (b, b)));
+        // incremental insertion makes a worse tree than building it in one go, but not by much
+        assert!(bvh.cost() >= built.cost() && bvh.cost() < built.cost() * 2.0);
+
+        for id in (0..64).step_by(2) {
+            assert_eq!(bvh.remove(id), Some(boxes[id]));
+        }
+        assert_eq!(bvh.remove(0), None);
+        assert!(!bvh.update(0, boxes[0]) && !bvh.set_bounds(0, boxes[0]) && !bvh.update(1000, boxes[0]));
+        assert_eq!(bvh.len(), 32);
+        let aabb = Aabb3::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::new(3.0, 1.0, 3.0));
+        assert_eq!(
+            found(&bvh, |t, f| t.query_aabb(aabb, f)),
+            brute(&bvh, |b| b.intersects(aabb))
+        );
+
+        // ids of removed items come back, and moved items are found where they went
+        let far = Aabb3::<f64>::new(Vec3::<f64>::one() * 9.0, Vec3::<f64>::one() * 10.0);
+        let id = bvh.insert(far, far);
+        assert!(id < 64 && id.is_multiple_of(2));
+        *bvh.get_mut(1).unwrap() = far;
+        assert!(bvh.update(1, far));
+        *bvh.get_mut(3).unwrap() = far;
+        assert!(bvh.set_bounds(3, far));
+        bvh.refit();
+        let ids = found(&bvh, |t, f| t.query_aabb(far, f));
+        let mut expected = Vec::from([1, 3, id]);
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert_eq!(ids, brute(&bvh, |b| b.intersects(far)));
+
+        let ids: Vec<usize> = bvh.iter().map(|(id, _)| id).collect();
+        for id in ids {
+            bvh.remove(id);
+        }
+        assert!(bvh.is_empty() && bvh.cast_ray(Ray::<f64>::default(), 1.0, |_, _| Some(0.0)).is_none());
+    }
+
+    #[test]
+    fn test_bvh_flatten() {
+        let boxes = grid(3);
+        let bvh = Bvh::<Aabb3<f64>, f64>::build(boxes.iter().map(|&b| (b, b)));
+        let flat = bvh.flatten();
+        assert_eq!(core::mem::size_of::<FlatBvhNode>(), 32);
+        assert_eq!(flat.len(), 2 * 27 - 1);
+        assert_eq!(flat[0].skip as usize, flat.len());
+        assert_eq!(flat.iter().filter(|n| n.item != u32::MAX).count(), 27);
+
+        // stackless traversal the way a shader would, every box the ray passes through
+        let ray = Ray::<f32>::new(Vec3::<f32>::new(-1.0, -1.0, -1.0), Vec3::<f32>::new(1.0, 1.0, 1.0));
+        let (mut i, mut hits) = (0, Vec::new());
+        while i < flat.len() {
+            let node = flat[i];
+            let bounds = Aabb3::<f32>::new(node.min.into(), node.max.into());
+            if ray.intersect_aabb(bounds, 10.0).is_some() {
+                if node.item != u32::MAX {
+                    hits.push(node.item as usize);
+                }
+                i += 1;
+            } else {
+                i = node.skip as usize;
+            }
+        }
+        hits.sort();
+        assert_eq!(hits, [0, 13, 26]);
+    }
+}
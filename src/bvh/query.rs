@@ -0,0 +1,132 @@
+use super::NodeKind;
+use crate::*;
+use alloc::vec::Vec;
+
+macro_rules! impl_bvh_query {
+    ($t:ident) => {
+        impl<T> Bvh<T, $t> {
+            // calls `f` with every item whose bounds overlap the box
+            pub fn query_aabb(&self, aabb: Aabb3<$t>, f: impl FnMut(usize, &T)) {
+                self.visit(|bounds| bounds.intersects(aabb), f);
+            }
+
+            pub fn query_sphere(&self, sphere: Sphere<$t>, f: impl FnMut(usize, &T)) {
+                self.visit(|bounds| bounds.distance_to_point(sphere.0) <= sphere.1, f);
+            }
+
+            // conservative like `Frustum::intersects_aabb`, items just past the frustum's corners may come up too
+            pub fn query_frustum(&self, frustum: Frustum<$t>, f: impl FnMut(usize, &T)) {
+                self.visit(|bounds| frustum.intersects_aabb(*bounds), f);
+            }
+
+            // the closest hit along the ray up to `max_t`, where `hit` tests an item whose bounds the ray passes
+            // through and gives its distance along the ray. nearer children are visited first so most of the far
+            // ones can be skipped
+            pub fn cast_ray(
+                &self,
+                ray: Ray<$t>,
+                max_t: $t,
+                mut hit: impl FnMut(usize, &T) -> Option<$t>,
+            ) -> Option<(usize, $t)> {
+                let root = self.root?;
+                let mut best = (None, max_t);
+                let mut stack = Vec::new();
+                if let Some(t) = ray.intersect_aabb(self.nodes[root].bounds, max_t) {
+                    stack.push((root, t));
+                }
+                while let Some((node, enter)) = stack.pop() {
+                    if enter > best.1 {
+                        continue;
+                    }
+                    match self.nodes[node].kind {
+                        NodeKind::Leaf(id) => {
+                            if let Some(item) = self.get(id) {
+                                if let Some(t) = hit(id, item).filter(|&t| t <= best.1) {
+                                    best = (Some(id), t);
+                                }
+                            }
+                        }
+                        NodeKind::Branch(left, right) => {
+                            let near = ray
+                                .intersect_aabb(self.nodes[left].bounds, best.1)
+                                .map(|t| (left, t));
+                            let far = ray
+                                .intersect_aabb(self.nodes[right].bounds, best.1)
+                                .map(|t| (right, t));
+                            let (near, far) = match (near, far) {
+                                (Some(a), Some(b)) if b.1 < a.1 => (Some(b), Some(a)),
+                                pair => pair,
+                            };
+                            stack.extend(far);
+                            stack.extend(near);
+                        }
+                        NodeKind::Free => {}
+                    }
+                }
+                best.0.map(|id| (id, best.1))
+            }
+
+            // the closest item to `point` no further than `max_distance`, where `distance` measures the distance to
+            // an item whose bounds are close enough to hold something nearer than the best so far
+            pub fn nearest(
+                &self,
+                point: Vec3<$t>,
+                max_distance: $t,
+                mut distance: impl FnMut(usize, &T) -> $t,
+            ) -> Option<(usize, $t)> {
+                let root = self.root?;
+                let mut best = (None, max_distance);
+                let mut stack = Vec::from([(root, self.nodes[root].bounds.distance_to_point(point))]);
+                while let Some((node, lower)) = stack.pop() {
+                    if lower > best.1 {
+                        continue;
+                    }
+                    match self.nodes[node].kind {
+                        NodeKind::Leaf(id) => {
+                            if let Some(item) = self.get(id) {
+                                let d = distance(id, item);
+                                if d <= best.1 {
+                                    best = (Some(id), d);
+                                }
+                            }
+                        }
+                        NodeKind::Branch(left, right) => {
+                            let mut near = (left, self.nodes[left].bounds.distance_to_point(point));
+                            let mut far = (right, self.nodes[right].bounds.distance_to_point(point));
+                            if far.1 < near.1 {
+                                core::mem::swap(&mut near, &mut far);
+                            }
+                            stack.push(far);
+                            stack.push(near);
+                        }
+                        NodeKind::Free => {}
+                    }
+                }
+                best.0.map(|id| (id, best.1))
+            }
+
+            // depth first through every branch `overlaps` lets through, down to the items
+            fn visit(&self, mut overlaps: impl FnMut(&Aabb3<$t>) -> bool, mut f: impl FnMut(usize, &T)) {
+                let mut stack = Vec::from_iter(self.root);
+                while let Some(node) = stack.pop() {
+                    let node = &self.nodes[node];
+                    if !overlaps(&node.bounds) {
+                        continue;
+                    }
+                    match node.kind {
+                        NodeKind::Leaf(id) => {
+                            if let Some(item) = self.get(id) {
+                                f(id, item);
+                            }
+                        }
+                        NodeKind::Branch(left, right) => stack.extend([right, left]),
+                        NodeKind::Free => {}
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_bvh_query!(f32);
+impl_bvh_query!(f64);
@@ -0,0 +1,75 @@
+use crate::*;
+
+macro_rules! impl_arcball {
+    ($t:ident) => {
+        impl Arcball<$t> {
+            pub fn new(target: Vec3<$t>, distance: $t) -> Self {
+                Self {
+                    target,
+                    distance,
+                    rotation: Quat::<$t>::identity(),
+                    mode: ArcballMode::Shoemake,
+                }
+            }
+
+            pub fn with_mode(mut self, mode: ArcballMode) -> Self {
+                self.mode = mode;
+                self
+            }
+
+            // pixel coordinates with y down to the coordinates the ball works in, with y up and the ball's rim
+            // touching the shorter side of the viewport
+            pub fn to_ndc(pixel: Vec2<$t>, viewport: Vec2<$t>) -> Vec2<$t> {
+                let half = viewport * $t::splat(0.5);
+                let radius = half[0].min(half[1]);
+                Vec2::<$t>::new((pixel[0] - half[0]) / radius, (half[1] - pixel[1]) / radius)
+            }
+
+            // the point on the ball under `p`, in view space with the ball's front towards -z
+            pub fn project(&self, p: Vec2<$t>) -> Vec3<$t> {
+                let r2 = p[0] * p[0] + p[1] * p[1];
+                match self.mode {
+                    ArcballMode::Shoemake if r2 <= $t::one() => {
+                        Vec3::<$t>::new(p[0], p[1], -Float::sqrt($t::one() - r2))
+                    }
+                    ArcballMode::Shoemake => Vec3::<$t>::new(p[0], p[1], $t::zero()).normalized(),
+                    ArcballMode::Bell if r2 <= $t::splat(0.5) => {
+                        Vec3::<$t>::new(p[0], p[1], -Float::sqrt($t::one() - r2))
+                    }
+                    ArcballMode::Bell => Vec3::<$t>::new(p[0], p[1], -$t::splat(0.5) / Float::sqrt(r2)).normalized(),
+                }
+            }
+
+            // turns the view so the point on the ball under `from` ends up under `to`
+            pub fn drag(&mut self, from: Vec2<$t>, to: Vec2<$t>) {
+                let (forward, up) = (self.forward(), self.up());
+                let right = forward.cross(up).normalized();
+                let world = |v: Vec3<$t>| right * v[0] + up * v[1] + forward * v[2];
+                let turn = Quat::<$t>::from_rotation_between(world(self.project(from)), world(self.project(to)));
+                // opposite points on the rim have no single turn between them
+                if turn.length() > $t::zero() {
+                    self.rotation = (turn.conjugate() * self.rotation).normalized();
+                }
+            }
+
+            pub fn forward(&self) -> Vec3<$t> {
+                self.rotation * Vec3::<$t>::unit_z()
+            }
+
+            pub fn up(&self) -> Vec3<$t> {
+                self.rotation * Vec3::<$t>::unit_y()
+            }
+
+            pub fn eye(&self) -> Vec3<$t> {
+                self.target - self.forward() * self.distance
+            }
+
+            pub fn view(&self) -> Mat4<$t> {
+                Mat4::<$t>::look_at(self.eye(), self.target, self.up())
+            }
+        }
+    };
+}
+
+impl_arcball!(f32);
+impl_arcball!(f64);
@@ -0,0 +1,65 @@
+use crate::*;
+
+macro_rules! impl_fly {
+    ($t:ident) => {
+        impl Vec3<$t> {
+            // unit direction for a yaw counterclockwise around y seen from above, starting at +z, and a pitch up
+            // towards +y
+            pub fn from_yaw_pitch(yaw: impl Into<Rad<$t>>, pitch: impl Into<Rad<$t>>) -> Self {
+                let (sin_yaw, cos_yaw) = Float::sin_cos(yaw.into().0);
+                let (sin_pitch, cos_pitch) = Float::sin_cos(pitch.into().0);
+                Self::new(sin_yaw * cos_pitch, sin_pitch, cos_yaw * cos_pitch)
+            }
+        }
+
+        impl FlyCamera<$t> {
+            pub fn new(position: Vec3<$t>, yaw: impl Into<Rad<$t>>, pitch: impl Into<Rad<$t>>) -> Self {
+                let max_pitch = Rad($t::splat(89.0) * core::$t::consts::PI / $t::splat(180.0));
+                Self {
+                    position,
+                    yaw: yaw.into(),
+                    pitch: Rad(pitch.into().0.max(-max_pitch.0).min(max_pitch.0)),
+                    max_pitch,
+                }
+            }
+
+            // turns by the given amounts, say mouse movement times a sensitivity
+            pub fn rotate(&mut self, yaw: impl Into<Rad<$t>>, pitch: impl Into<Rad<$t>>) {
+                let tau = core::$t::consts::TAU;
+                let yaw = self.yaw.0 + yaw.into().0;
+                self.yaw = Rad(yaw - Float::floor(yaw / tau) * tau);
+                self.pitch = Rad((self.pitch.0 + pitch.into().0)
+                    .max(-self.max_pitch.0)
+                    .min(self.max_pitch.0));
+            }
+
+            pub fn forward(&self) -> Vec3<$t> {
+                Vec3::<$t>::from_yaw_pitch(self.yaw, self.pitch)
+            }
+
+            pub fn right(&self) -> Vec3<$t> {
+                self.forward().cross(Vec3::<$t>::unit_y()).normalized()
+            }
+
+            pub fn up(&self) -> Vec3<$t> {
+                self.right().cross(self.forward())
+            }
+
+            // moves by `delta` in camera space, x to the right, y up and z forward
+            pub fn translate_local(&mut self, delta: Vec3<$t>) {
+                self.position += self.right() * delta[0] + self.up() * delta[1] + self.forward() * delta[2];
+            }
+
+            pub fn view(&self) -> Mat4<$t> {
+                Mat4::<$t>::look_at(
+                    self.position,
+                    self.position + self.forward(),
+                    Vec3::<$t>::unit_y(),
+                )
+            }
+        }
+    };
+}
+
+impl_fly!(f32);
+impl_fly!(f64);
@@ -0,0 +1,173 @@
+mod arcball;
+mod fly;
+mod orbit;
+
+use crate::{Quat, Rad, Vec3};
+
+// the cameras all build their view with `Mat4::look_at`, world y is up. yaw turns around y, starting out looking
+// down +z, and positive pitch looks up
+
+// circles `target` at `distance`, for model viewers and third person views
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrbitCamera<S> {
+    pub target: Vec3<S>,
+    pub yaw: Rad<S>,
+    pub pitch: Rad<S>,
+    pub distance: S,
+    pub min_pitch: Rad<S>,
+    pub max_pitch: Rad<S>,
+    pub min_distance: S,
+    pub max_distance: S,
+}
+
+// first person fly camera, moving relative to where it looks
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlyCamera<S> {
+    pub position: Vec3<S>,
+    pub yaw: Rad<S>,
+    pub pitch: Rad<S>,
+    // kept short of straight up and down, where yaw stops making sense
+    pub max_pitch: Rad<S>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArcballMode {
+    // points past the rim of the ball slide along it
+    #[default]
+    Shoemake,
+    // the ball blends into a hyperbolic sheet, so dragging outside it keeps turning smoothly
+    Bell,
+}
+
+// turns the view around `target` by dragging a virtual trackball that fills the viewport
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arcball<S> {
+    pub target: Vec3<S>,
+    pub distance: S,
+    // orientation of the camera, the view looks down its z axis
+    pub rotation: Quat<S>,
+    pub mode: ArcballMode,
+}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn to_view(view: Mat4<f64>, p: Vec3<f64>) -> Vec3<f64> {
+        (view * p.extended(1.0)).truncated()
+    }
+
+    #[test]
+    fn test_camera_orbit() {
+        let target = Vec3::<f64>::new(1.0, 2.0, 3.0);
+        let mut orbit = OrbitCamera::<f64>::new(target, 5.0).with_distance_limits(1.0, 10.0);
+        assert_abs_diff_eq!(orbit.eye(), Vec3::<f64>::new(1.0, 2.0, -2.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            to_view(orbit.view(), target),
+            Vec3::<f64>::new(0.0, 0.0, 5.0),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(to_view(orbit.view(), orbit.eye()), Vec3::<f64>::zero(), epsilon = 1e-12);
+
+        // a quarter turn looks down +x, looking up puts the eye below the target
+        orbit.rotate(Deg(90.0), Deg(30.0));
+        assert!(orbit.eye()[0] < target[0] && orbit.eye()[1] < target[1]);
+        assert_abs_diff_eq!(
+            to_view(orbit.view(), target),
+            Vec3::<f64>::new(0.0, 0.0, 5.0),
+            epsilon = 1e-12
+        );
+
+        orbit.rotate(Deg(360.0), Deg(100.0));
+        assert!((orbit.yaw.0 - 90f64.to_radians()).abs() < 1e-12);
+        assert_eq!(orbit.pitch, orbit.max_pitch);
+        orbit.zoom(0.1);
+        assert_eq!(orbit.distance, 1.0);
+        orbit.zoom(100.0);
+        assert_eq!(orbit.distance, 10.0);
+
+        // panning drags the target sideways across the old view
+        let view = orbit.view();
+        orbit.pan(Vec2::<f64>::new(1.0, -2.0));
+        assert_abs_diff_eq!(
+            to_view(view, orbit.target),
+            Vec3::<f64>::new(1.0, -2.0, 10.0),
+            epsilon = 1e-9
+        );
+
+        let orbit = OrbitCamera::<f64>::new(target, 5.0).with_pitch_limits(Deg(-10.0), Deg(0.0));
+        assert_eq!(orbit.pitch.0, 0.0);
+    }
+
+    #[test]
+    fn test_camera_fly() {
+        let mut fly = FlyCamera::<f64>::new(Vec3::<f64>::zero(), Deg(0.0), Deg(120.0));
+        assert_eq!(fly.pitch, fly.max_pitch);
+        fly.rotate(Deg(-90.0), -fly.pitch);
+        assert_abs_diff_eq!(fly.forward(), -Vec3::<f64>::unit_x(), epsilon = 1e-12);
+        assert!((fly.yaw.0 - 270f64.to_radians()).abs() < 1e-12);
+
+        // moving in camera space shows up the same way in the view
+        let view = fly.view();
+        fly.translate_local(Vec3::<f64>::new(1.0, 2.0, 3.0));
+        assert_abs_diff_eq!(
+            to_view(view, fly.position),
+            Vec3::<f64>::new(1.0, 2.0, 3.0),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(to_view(fly.view(), fly.position), Vec3::<f64>::zero(), epsilon = 1e-12);
+        assert_abs_diff_eq!(fly.right().cross(fly.up()), -fly.forward(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_camera_arcball() {
+        assert_eq!(
+            Arcball::<f64>::to_ndc(Vec2::<f64>::new(200.0, 0.0), Vec2::<f64>::new(400.0, 200.0)),
+            Vec2::<f64>::new(0.0, 1.0)
+        );
+
+        for mode in [ArcballMode::Shoemake, ArcballMode::Bell] {
+            let target = Vec3::<f64>::new(0.0, 1.0, 0.0);
+            let mut ball = Arcball::<f64>::new(target, 4.0).with_mode(mode);
+            let (from, to) = (Vec2::<f64>::new(0.1, -0.2), Vec2::<f64>::new(0.4, 0.3));
+            let (a, b) = (ball.project(from), ball.project(to));
+            assert!((a.length() - 1.0).abs() < 1e-12 && a[2] < 0.0);
+
+            // the point on the ball under the cursor follows it
+            let point = target + ball.rotation * Vec3::<f64>::new(-a[0], a[1], a[2]);
+            assert_abs_diff_eq!(
+                to_view(ball.view(), point),
+                a + Vec3::<f64>::new(0.0, 0.0, 4.0),
+                epsilon = 1e-12
+            );
+            ball.drag(from, to);
+            assert_abs_diff_eq!(
+                to_view(ball.view(), point),
+                b + Vec3::<f64>::new(0.0, 0.0, 4.0),
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(
+                to_view(ball.view(), target),
+                Vec3::<f64>::new(0.0, 0.0, 4.0),
+                epsilon = 1e-12
+            );
+
+            // and back again
+            ball.drag(to, from);
+            assert_abs_diff_eq!(ball.rotation, Quat::<f64>::identity(), epsilon = 1e-12);
+        }
+
+        // outside the ball the two mappings part ways
+        let far = Vec2::<f64>::new(2.0, 0.0);
+        let shoemake = Arcball::<f64>::new(Vec3::<f64>::zero(), 1.0);
+        assert_eq!(shoemake.project(far), Vec3::<f64>::unit_x());
+        assert!(shoemake.with_mode(ArcballMode::Bell).project(far)[2] < 0.0);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::*;
+
+macro_rules! impl_orbit {
+    ($t:ident) => {
+        impl OrbitCamera<$t> {
+            pub fn new(target: Vec3<$t>, distance: $t) -> Self {
+                let max_pitch = $t::splat(89.0) * core::$t::consts::PI / $t::splat(180.0);
+                Self {
+                    target,
+                    yaw: Rad($t::zero()),
+                    pitch: Rad($t::zero()),
+                    distance,
+                    min_pitch: Rad(-max_pitch),
+                    max_pitch: Rad(max_pitch),
+                    min_distance: $t::zero(),
+                    max_distance: $t::MAX,
+                }
+            }
+
+            pub fn with_pitch_limits(mut self, min: impl Into<Rad<$t>>, max: impl Into<Rad<$t>>) -> Self {
+                self.min_pitch = min.into();
+                self.max_pitch = max.into();
+                self.rotate(Rad($t::zero()), Rad($t::zero()));
+                self
+            }
+
+            pub fn with_distance_limits(mut self, min: $t, max: $t) -> Self {
+                self.min_distance = min;
+                self.max_distance = max;
+                self.zoom($t::one());
+                self
+            }
+
+            // turns around the target, positive pitch looks up at it from below
+            pub fn rotate(&mut self, yaw: impl Into<Rad<$t>>, pitch: impl Into<Rad<$t>>) {
+                let tau = core::$t::consts::TAU;
+                let yaw = self.yaw.0 + yaw.into().0;
+                self.yaw = Rad(yaw - Float::floor(yaw / tau) * tau);
+                self.pitch = Rad((self.pitch.0 + pitch.into().0)
+                    .max(self.min_pitch.0)
+                    .min(self.max_pitch.0));
+            }
+
+            // scales the distance, below one moves in
+            pub fn zoom(&mut self, factor: $t) {
+                self.distance = (self.distance * factor)
+                    .max(self.min_distance)
+                    .min(self.max_distance);
+            }
+
+            // slides the target across the view, x to the right and y up
+            pub fn pan(&mut self, delta: Vec2<$t>) {
+                let forward = self.forward();
+                let right = forward.cross(Vec3::<$t>::unit_y()).normalized();
+                self.target += right * delta[0] + right.cross(forward) * delta[1];
+            }
+
+            pub fn forward(&self) -> Vec3<$t> {
+                Vec3::<$t>::from_yaw_pitch(self.yaw, self.pitch)
+            }
+
+            pub fn eye(&self) -> Vec3<$t> {
+                self.target - self.forward() * self.distance
+            }
+
+            pub fn view(&self) -> Mat4<$t> {
+                Mat4::<$t>::look_at(self.eye(), self.target, Vec3::<$t>::unit_y())
+            }
+        }
+    };
+}
+
+impl_orbit!(f32);
+impl_orbit!(f64);
@@ -0,0 +1,70 @@
+use crate::*;
+
+macro_rules! impl_advance {
+    ($t:ident) => {
+        impl Gjk<$t> {
+            // conservative advancement: steps forward in time by the distance between the shapes over the fastest
+            // they could be closing in on each other, which never passes the first contact. they touch once within
+            // `margin`, which should sit well above the tolerance. running out of iterations reports the time
+            // reached so far, still safely before the contact
+            pub fn time_of_impact<A: ConvexShape<$t> + Clone, B: ConvexShape<$t> + Clone>(
+                &self,
+                a: &Posed<A, $t>,
+                motion_a: Motion<$t>,
+                b: &Posed<B, $t>,
+                motion_b: Motion<$t>,
+                margin: $t,
+            ) -> Option<Toi<$t>> {
+                // the farthest any point of each shape is from where it turns, times how far it turns
+                let spin = Self::reach(&a.shape) * motion_a.angular.length()
+                    + Self::reach(&b.shape) * motion_b.angular.length();
+                let mut time = $t::zero();
+                let mut last = None;
+                for _ in 0..self.max_iterations {
+                    let (pa, pb) = (a.moved(motion_a, time), b.moved(motion_b, time));
+                    let Some(closest) = self.distance(&pa, &pb) else {
+                        let hit = self.penetration(&pa, &pb)?;
+                        return Some(Toi {
+                            time,
+                            normal: -hit.normal,
+                            point: (hit.a + hit.b) * $t::splat(0.5),
+                        });
+                    };
+                    let normal = (closest.b - closest.a).normalized();
+                    let toi = Toi {
+                        time,
+                        normal: -normal,
+                        point: (closest.a + closest.b) * $t::splat(0.5),
+                    };
+                    if closest.distance <= margin {
+                        return Some(toi);
+                    }
+                    let closing = (motion_a.linear - motion_b.linear).dot(normal) + spin;
+                    if closing <= $t::zero() {
+                        return None;
+                    }
+                    time += (closest.distance - margin * $t::splat(0.5)) / closing;
+                    if time > $t::one() {
+                        return None;
+                    }
+                    last = Some(toi);
+                }
+                last
+            }
+
+            // bounds the distance of the shape from its origin by the box its support points along the axes span
+            fn reach(shape: &impl ConvexShape<$t>) -> $t {
+                let axes = [Vec3::<$t>::unit_x(), Vec3::<$t>::unit_y(), Vec3::<$t>::unit_z()];
+                let extent = |i: usize| {
+                    shape.support(axes[i])[i]
+                        .abs()
+                        .max(shape.support(-axes[i])[i].abs())
+                };
+                Vec3::<$t>::new(extent(0), extent(1), extent(2)).length()
+            }
+        }
+    };
+}
+
+impl_advance!(f32);
+impl_advance!(f64);
@@ -0,0 +1,244 @@
+use crate::*;
+
+// closest points between segments and triangles, which only need dot products and so are shared between 2d and 3d.
+// segment points come with their parameter along the segment and triangle points with their barycentric weights
+macro_rules! impl_closest {
+    ($seg:ident, $tri:ident, $v:ident, $t:ident) => {
+        impl $seg<$t> {
+            pub fn closest_point(&self, point: $v<$t>) -> ($v<$t>, $t) {
+                let d = self.1 - self.0;
+                let len_sq = d.dot(d);
+                let t = if len_sq > $t::zero() {
+                    ((point - self.0).dot(d) / len_sq).max($t::zero()).min($t::one())
+                } else {
+                    $t::zero()
+                };
+                (self.at(t), t)
+            }
+
+            pub fn distance_to_point(&self, point: $v<$t>) -> $t {
+                (self.closest_point(point).0 - point).length()
+            }
+
+            // the first pair is on self. parallel segments pick one of the closest pairs
+            pub fn closest_to_segment(&self, rhs: Self) -> (($v<$t>, $t), ($v<$t>, $t)) {
+                let (zero, one) = ($t::zero(), $t::one());
+                let clamp = |x: $t| x.max(zero).min(one);
+                let (d1, d2, r) = (self.1 - self.0, rhs.1 - rhs.0, self.0 - rhs.0);
+                let (a, e, f) = (d1.dot(d1), d2.dot(d2), d2.dot(r));
+                let (s, t) = if a == zero && e == zero {
+                    (zero, zero)
+                } else if a == zero {
+                    (zero, clamp(f / e))
+                } else {
+                    let c = d1.dot(r);
+                    if e == zero {
+                        (clamp(-c / a), zero)
+                    } else {
+                        // the closest points of the infinite lines, with each clamped back onto its segment in turn
+                        let b = d1.dot(d2);
+                        let denom = a * e - b * b;
+                        let s = if denom > zero {
+                            clamp((b * f - c * e) / denom)
+                        } else {
+                            zero
+                        };
+                        let t = (b * s + f) / e;
+                        if t < zero {
+                            (clamp(-c / a), zero)
+                        } else if t > one {
+                            (clamp((b - c) / a), one)
+                        } else {
+                            (s, t)
+                        }
+                    }
+                };
+                ((self.at(s), s), (rhs.at(t), t))
+            }
+
+            pub fn distance_to_segment(&self, rhs: Self) -> $t {
+                let ((a, _), (b, _)) = self.closest_to_segment(rhs);
+                (b - a).length()
+            }
+
+            pub fn closest_to_triangle(&self, triangle: $tri<$t>) -> (($v<$t>, $t), ($v<$t>, [$t; 3])) {
+                let (on_triangle, on_self) = triangle.closest_to_segment(*self);
+                (on_self, on_triangle)
+            }
+
+            pub fn distance_to_triangle(&self, triangle: $tri<$t>) -> $t {
+                triangle.distance_to_segment(*self)
+            }
+        }
+
+        impl $tri<$t> {
+            // by the voronoi regions of the corners, edges and face
+            pub fn closest_point(&self, point: $v<$t>) -> ($v<$t>, [$t; 3]) {
+                let weights = self.closest_weights(point);
+                (self.at(weights), weights)
+            }
+
+            pub fn distance_to_point(&self, point: $v<$t>) -> $t {
+                (self.closest_point(point).0 - point).length()
+            }
+
+            pub fn distance_to_segment(&self, segment: $seg<$t>) -> $t {
+                let ((a, _), (b, _)) = self.closest_to_segment(segment);
+                (b - a).length()
+            }
+
+            // the closest pair always has a point on an edge of one of the triangles
+            pub fn closest_to_triangle(&self, rhs: Self) -> (($v<$t>, [$t; 3]), ($v<$t>, [$t; 3])) {
+                let mut best = (
+                    self.closest_point(rhs.0),
+                    (rhs.0, [$t::one(), $t::zero(), $t::zero()]),
+                );
+                for i in 0..3 {
+                    let (on_rhs, (point, t)) = rhs.closest_to_segment(self.edge(i));
+                    let candidate = ((point, Self::edge_weights(i, t)), on_rhs);
+                    best = Self::closer(best, candidate);
+                    let (on_self, (point, t)) = self.closest_to_segment(rhs.edge(i));
+                    best = Self::closer(best, (on_self, (point, Self::edge_weights(i, t))));
+                }
+                best
+            }
+
+            pub fn distance_to_triangle(&self, rhs: Self) -> $t {
+                let ((a, _), (b, _)) = self.closest_to_triangle(rhs);
+                (b - a).length()
+            }
+
+            // edge i runs from corner i to the next one
+            fn edge(&self, i: usize) -> $seg<$t> {
+                let corners = [self.0, self.1, self.2];
+                $seg(corners[i], corners[(i + 1) % 3])
+            }
+
+            fn edge_weights(i: usize, t: $t) -> [$t; 3] {
+                let mut weights = [$t::zero(); 3];
+                weights[i] = $t::one() - t;
+                weights[(i + 1) % 3] = t;
+                weights
+            }
+
+            fn closer<A, B>(
+                best: (($v<$t>, A), ($v<$t>, B)),
+                candidate: (($v<$t>, A), ($v<$t>, B)),
+            ) -> (($v<$t>, A), ($v<$t>, B)) {
+                let dist = |pair: &(($v<$t>, A), ($v<$t>, B))| {
+                    let d = (pair.1).0 - (pair.0).0;
+                    d.dot(d)
+                };
+                if dist(&candidate) < dist(&best) {
+                    candidate
+                } else {
+                    best
+                }
+            }
+
+            // the segment against each edge and each of its ends against the whole triangle, which finds the closest
+            // points unless the segment passes through the face
+            fn closest_to_boundary(&self, segment: $seg<$t>) -> (($v<$t>, [$t; 3]), ($v<$t>, $t)) {
+                let mut best = (self.closest_point(segment.0), (segment.0, $t::zero()));
+                best = Self::closer(best, (self.closest_point(segment.1), (segment.1, $t::one())));
+                for i in 0..3 {
+                    let ((point, t), on_segment) = self.edge(i).closest_to_segment(segment);
+                    best = Self::closer(best, ((point, Self::edge_weights(i, t)), on_segment));
+                }
+                best
+            }
+
+            fn closest_weights(&self, p: $v<$t>) -> [$t; 3] {
+                let (zero, one) = ($t::zero(), $t::one());
+                let (a, b, c) = (self.0, self.1, self.2);
+                let (ab, ac, ap) = (b - a, c - a, p - a);
+                let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+                if d1 <= zero && d2 <= zero {
+                    return [one, zero, zero];
+                }
+                let bp = p - b;
+                let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+                if d3 >= zero && d4 <= d3 {
+                    return [zero, one, zero];
+                }
+                let vc = d1 * d4 - d3 * d2;
+                if vc <= zero && d1 >= zero && d3 <= zero {
+                    let v = if d1 > d3 { d1 / (d1 - d3) } else { zero };
+                    return [one - v, v, zero];
+                }
+                let cp = p - c;
+                let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+                if d6 >= zero && d5 <= d6 {
+                    return [zero, zero, one];
+                }
+                let vb = d5 * d2 - d1 * d6;
+                if vb <= zero && d2 >= zero && d6 <= zero {
+                    let w = if d2 > d6 { d2 / (d2 - d6) } else { zero };
+                    return [one - w, zero, w];
+                }
+                let va = d3 * d6 - d5 * d4;
+                if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+                    let sum = (d4 - d3) + (d5 - d6);
+                    let w = if sum > zero { (d4 - d3) / sum } else { zero };
+                    return [zero, one - w, w];
+                }
+                let denom = va + vb + vc;
+                if denom == zero {
+                    // no area, so the closest of its edges
+                    let (on_ab, v) = $seg(a, b).closest_point(p);
+                    let (on_ac, w) = $seg(a, c).closest_point(p);
+                    return if (on_ac - p).length() < (on_ab - p).length() {
+                        [one - w, zero, w]
+                    } else {
+                        [one - v, v, zero]
+                    };
+                }
+                let (v, w) = (vb / denom, vc / denom);
+                [one - v - w, v, w]
+            }
+        }
+    };
+}
+
+impl_closest!(Segment2, Triangle2, Vec2, f32);
+impl_closest!(Segment2, Triangle2, Vec2, f64);
+impl_closest!(Segment3, Triangle3, Vec3, f32);
+impl_closest!(Segment3, Triangle3, Vec3, f64);
+
+macro_rules! impl_closest_triangle {
+    ($t:ident) => {
+        impl Triangle2<$t> {
+            // the first pair is on self
+            pub fn closest_to_segment(&self, segment: Segment2<$t>) -> ((Vec2<$t>, [$t; 3]), (Vec2<$t>, $t)) {
+                self.closest_to_boundary(segment)
+            }
+        }
+
+        impl Triangle3<$t> {
+            // the first pair is on self
+            pub fn closest_to_segment(&self, segment: Segment3<$t>) -> ((Vec3<$t>, [$t; 3]), (Vec3<$t>, $t)) {
+                // a segment through the face touches it where it crosses the plane
+                let (a, b, c) = (self.0, self.1, self.2);
+                let n = (b - a).cross(c - a);
+                let (dp, dq) = (n.dot(segment.0 - a), n.dot(segment.1 - a));
+                if dp * dq <= $t::zero() && dp != dq {
+                    let t = dp / (dp - dq);
+                    let x = segment.at(t);
+                    let weights = [
+                        n.dot((b - x).cross(c - x)),
+                        n.dot((c - x).cross(a - x)),
+                        n.dot((a - x).cross(b - x)),
+                    ];
+                    if weights.iter().all(|&w| w >= $t::zero()) {
+                        let area = n.dot(n);
+                        return ((x, weights.map(|w| w / area)), (x, t));
+                    }
+                }
+                self.closest_to_boundary(segment)
+            }
+        }
+    };
+}
+
+impl_closest_triangle!(f32);
+impl_closest_triangle!(f64);
@@ -0,0 +1,179 @@
+use super::Vertex;
+use crate::*;
+
+// room for the expanding polytope, fixed so the query needs no allocation. hitting it ends the search with the best
+// face found so far
+const MAX_VERTICES: usize = 128;
+const MAX_FACES: usize = 2 * MAX_VERTICES;
+
+// expanding polytope: starts from gjk's tetrahedron around the origin and keeps pushing out the face closest to the
+// origin with the support point beyond it, until that face is on the surface of the minkowski difference
+macro_rules! impl_epa {
+    ($t:ident) => {
+        impl Gjk<$t> {
+            // none when the shapes are apart
+            pub fn penetration(&self, a: &impl ConvexShape<$t>, b: &impl ConvexShape<$t>) -> Option<Penetration<$t>> {
+                let (simplex, overlap) = self.simplex(a, b);
+                if !overlap {
+                    return None;
+                }
+                let mut vertices = [Vertex::<$t>::default(); MAX_VERTICES];
+                vertices[..simplex.len].copy_from_slice(&simplex.vertices[..simplex.len]);
+                let mut len = simplex.len;
+                if let Err(normal) = Self::grow_tetrahedron(a, b, &mut vertices, &mut len) {
+                    // flat, so the shapes only just touch
+                    let (pa, pb) = simplex.witnesses();
+                    return Some(Penetration {
+                        depth: $t::zero(),
+                        normal,
+                        a: pa,
+                        b: pb,
+                    });
+                }
+
+                let center = (0..4).fold(Vec3::<$t>::zero(), |c, i| c + vertices[i].w) * $t::splat(0.25);
+                let mut faces = [[0usize; 3]; MAX_FACES];
+                for (n, [i, j, k]) in [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+                    .into_iter()
+                    .enumerate()
+                {
+                    let normal = (vertices[j].w - vertices[i].w).cross(vertices[k].w - vertices[i].w);
+                    faces[n] = if normal.dot(vertices[i].w - center) < $t::zero() {
+                        [i, k, j]
+                    } else {
+                        [i, j, k]
+                    };
+                }
+                let mut face_count = 4;
+
+                // keeps the face's vertices rather than its slot, the slots get reshuffled once it's expanded
+                let mut best = ([0usize; 3], Vec3::<$t>::zero(), $t::zero());
+                for _ in 0..self.max_iterations {
+                    best = ([0; 3], Vec3::<$t>::zero(), $t::INFINITY);
+                    for &[i, j, k] in &faces[..face_count] {
+                        let normal = (vertices[j].w - vertices[i].w)
+                            .cross(vertices[k].w - vertices[i].w)
+                            .normalized();
+                        let dist = normal.dot(vertices[i].w);
+                        if normal != Vec3::<$t>::zero() && dist < best.2 {
+                            best = ([i, j, k], normal, dist);
+                        }
+                    }
+                    let (_, normal, dist) = best;
+                    let w = Self::vertex(a, b, normal);
+                    if w.w.dot(normal) - dist <= self.tolerance || len == MAX_VERTICES {
+                        break;
+                    }
+
+                    // drop every face the new point can see, the edges around the hole are the ones only one of them
+                    // had, and they join up with the new point
+                    let mut edges = [[0usize; 2]; MAX_FACES];
+                    let mut edge_count = 0;
+                    let mut f = 0;
+                    while f < face_count {
+                        let [i, j, k] = faces[f];
+                        let n = (vertices[j].w - vertices[i].w).cross(vertices[k].w - vertices[i].w);
+                        if n.dot(w.w - vertices[i].w) <= $t::zero() {
+                            f += 1;
+                            continue;
+                        }
+                        for edge in [[i, j], [j, k], [k, i]] {
+                            if let Some(e) = edges[..edge_count].iter().position(|&e| e == [edge[1], edge[0]]) {
+                                edge_count -= 1;
+                                edges[e] = edges[edge_count];
+                            } else if edge_count < MAX_FACES {
+                                edges[edge_count] = edge;
+                                edge_count += 1;
+                            }
+                        }
+                        face_count -= 1;
+                        faces[f] = faces[face_count];
+                    }
+                    if face_count + edge_count > MAX_FACES {
+                        break;
+                    }
+                    vertices[len] = w;
+                    for &[i, j] in &edges[..edge_count] {
+                        faces[face_count] = [i, j, len];
+                        face_count += 1;
+                    }
+                    len += 1;
+                }
+
+                let ([i, j, k], normal, dist) = best;
+                let weights = Triangle3(vertices[i].w, vertices[j].w, vertices[k].w)
+                    .closest_point(normal * dist)
+                    .1;
+                let point = |pick: fn(&Vertex<$t>) -> Vec3<$t>| {
+                    pick(&vertices[i]) * weights[0]
+                        + pick(&vertices[j]) * weights[1]
+                        + pick(&vertices[k]) * weights[2]
+                };
+                Some(Penetration {
+                    depth: dist.max($t::zero()),
+                    normal,
+                    a: point(|v| v.a),
+                    b: point(|v| v.b),
+                })
+            }
+
+            // fills gjk's simplex out to a tetrahedron with volume, or gives the normal of the plane the minkowski
+            // difference is stuck in
+            fn grow_tetrahedron(
+                a: &impl ConvexShape<$t>,
+                b: &impl ConvexShape<$t>,
+                vertices: &mut [Vertex<$t>; MAX_VERTICES],
+                len: &mut usize,
+            ) -> Result<(), Vec3<$t>> {
+                let axes = [Vec3::<$t>::unit_x(), Vec3::<$t>::unit_y(), Vec3::<$t>::unit_z()];
+                let scale = (0..*len).fold($t::one(), |s, i| s.max(vertices[i].w.length()));
+                let tiny = scale * Float::sqrt($t::EPSILON);
+                while *len < 4 {
+                    let w = [vertices[0].w, vertices[1].w, vertices[2].w];
+                    let directions: [Vec3<$t>; 6] = match *len {
+                        1 => [axes[0], -axes[0], axes[1], -axes[1], axes[2], -axes[2]],
+                        2 => {
+                            let d = w[1] - w[0];
+                            let side = axes.iter().fold(axes[0], |best, &x| {
+                                if x.dot(d).abs() < best.dot(d).abs() {
+                                    x
+                                } else {
+                                    best
+                                }
+                            });
+                            let (u, v) = (d.cross(side).normalized(), d.cross(d.cross(side)).normalized());
+                            [u, -u, v, -v, u + v, -u - v]
+                        }
+                        _ => {
+                            let n = (w[1] - w[0]).cross(w[2] - w[0]).normalized();
+                            [n, -n, n, -n, n, -n]
+                        }
+                    };
+                    // the first direction that gives a point off the line or plane so far
+                    let off = |p: Vec3<$t>| match *len {
+                        1 => (p - w[0]).length(),
+                        2 => (p - w[0]).cross((w[1] - w[0]).normalized()).length(),
+                        _ => (p - w[0])
+                            .dot((w[1] - w[0]).cross(w[2] - w[0]).normalized())
+                            .abs(),
+                    };
+                    let found = directions
+                        .iter()
+                        .map(|&d| Self::vertex(a, b, d))
+                        .find(|v| off(v.w) > tiny);
+                    match found {
+                        Some(v) => {
+                            vertices[*len] = v;
+                            *len += 1;
+                        }
+                        None => return Err(if *len == 1 { axes[0] } else { directions[0] }),
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_epa!(f32);
+impl_epa!(f64);
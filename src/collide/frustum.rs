@@ -0,0 +1,46 @@
+use crate::*;
+
+macro_rules! impl_frustum {
+    ($t:ident) => {
+        impl Frustum<$t> {
+            // the planes of the clip volume of a view projection matrix, with x and y in [-w, w] and depth in [0, w]
+            pub fn from_matrix(m: Mat4<$t>) -> Self {
+                let row = |r: usize| Vec4::<$t>::new(m[0][r], m[1][r], m[2][r], m[3][r]);
+                let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+                let plane = |p: Vec4<$t>| {
+                    let normal = Vec3::<$t>::new(p[0], p[1], p[2]);
+                    let len = normal.length();
+                    Plane::<$t>::new(normal / len, -p[3] / len)
+                };
+                Self([
+                    plane(w + x),
+                    plane(w - x),
+                    plane(w + y),
+                    plane(w - y),
+                    plane(z),
+                    plane(w - z),
+                ])
+            }
+
+            pub fn contains(&self, point: Vec3<$t>) -> bool {
+                self.0.iter().all(|p| p.signed_distance(point) >= $t::zero())
+            }
+
+            pub fn intersects_sphere(&self, sphere: Sphere<$t>) -> bool {
+                self.0.iter().all(|p| p.signed_distance(sphere.0) >= -sphere.1)
+            }
+
+            // conservative: a box that is outside no single plane counts as intersecting, even when it is past a
+            // corner of the frustum
+            pub fn intersects_aabb(&self, aabb: Aabb3<$t>) -> bool {
+                self.0.iter().all(|p| {
+                    let pick = |i: usize| if p.0[i] < $t::zero() { aabb.0[i] } else { aabb.1[i] };
+                    p.signed_distance(Vec3::<$t>::new(pick(0), pick(1), pick(2))) >= $t::zero()
+                })
+            }
+        }
+    };
+}
+
+impl_frustum!(f32);
+impl_frustum!(f64);
@@ -0,0 +1,160 @@
+use super::{Simplex, Vertex};
+use crate::*;
+
+// gilbert-johnson-keerthi on the minkowski difference a - b: the shapes overlap when it holds the origin, and
+// otherwise the point of it closest to the origin gives their distance. each step adds the support point towards
+// the origin and shrinks the simplex down to the feature closest to it
+macro_rules! impl_gjk {
+    ($t:ident) => {
+        impl Gjk<$t> {
+            pub fn new(tolerance: $t, max_iterations: u32) -> Self {
+                Self {
+                    tolerance,
+                    max_iterations,
+                }
+            }
+
+            // none when the shapes touch or overlap
+            pub fn distance(&self, a: &impl ConvexShape<$t>, b: &impl ConvexShape<$t>) -> Option<ClosestPoints<$t>> {
+                let (simplex, overlap) = self.simplex(a, b);
+                if overlap {
+                    return None;
+                }
+                let (pa, pb) = simplex.witnesses();
+                Some(ClosestPoints {
+                    distance: (pb - pa).length(),
+                    a: pa,
+                    b: pb,
+                })
+            }
+
+            pub fn intersects(&self, a: &impl ConvexShape<$t>, b: &impl ConvexShape<$t>) -> bool {
+                self.simplex(a, b).1
+            }
+
+            pub(super) fn vertex(
+                a: &impl ConvexShape<$t>,
+                b: &impl ConvexShape<$t>,
+                direction: Vec3<$t>,
+            ) -> Vertex<$t> {
+                let (pa, pb) = (a.support(direction), b.support(-direction));
+                Vertex {
+                    w: pa - pb,
+                    a: pa,
+                    b: pb,
+                }
+            }
+
+            // the last simplex, and whether it got within tolerance of the origin
+            pub(super) fn simplex(&self, a: &impl ConvexShape<$t>, b: &impl ConvexShape<$t>) -> (Simplex<$t>, bool) {
+                let first = Self::vertex(a, b, Vec3::<$t>::unit_x());
+                let mut simplex = Simplex {
+                    vertices: [first; 4],
+                    weights: [$t::one(), $t::zero(), $t::zero(), $t::zero()],
+                    len: 1,
+                };
+                let mut v = first.w;
+                for _ in 0..self.max_iterations {
+                    let vv = v.dot(v);
+                    if vv <= self.tolerance * self.tolerance {
+                        return (simplex, true);
+                    }
+                    let w = Self::vertex(a, b, -v);
+                    // nothing lies meaningfully further towards the origin than v, so v is the closest point
+                    let repeated = simplex.vertices[..simplex.len].iter().any(|x| x.w == w.w);
+                    if repeated || vv - v.dot(w.w) <= self.tolerance * Float::sqrt(vv) {
+                        break;
+                    }
+                    simplex.vertices[simplex.len] = w;
+                    simplex.len += 1;
+                    if !simplex.reduce() {
+                        return (simplex, true);
+                    }
+                    v = simplex.closest();
+                }
+                (simplex, false)
+            }
+        }
+
+        impl Default for Gjk<$t> {
+            fn default() -> Self {
+                Self::new(Float::sqrt($t::EPSILON), 64)
+            }
+        }
+
+        impl Simplex<$t> {
+            pub(super) fn closest(&self) -> Vec3<$t> {
+                (0..self.len).fold(Vec3::<$t>::zero(), |p, i| {
+                    p + self.vertices[i].w * self.weights[i]
+                })
+            }
+
+            // the points on a and b that make up the closest point
+            pub(super) fn witnesses(&self) -> (Vec3<$t>, Vec3<$t>) {
+                (0..self.len).fold((Vec3::<$t>::zero(), Vec3::<$t>::zero()), |(pa, pb), i| {
+                    let v = self.vertices[i];
+                    (pa + v.a * self.weights[i], pb + v.b * self.weights[i])
+                })
+            }
+
+            // keeps only the vertices of the feature closest to the origin and sets their weights, false when the
+            // origin is inside the tetrahedron
+            fn reduce(&mut self) -> bool {
+                let w = self.vertices.map(|v| v.w);
+                match self.len {
+                    1 => self.keep(&[(0, $t::one())]),
+                    2 => {
+                        let t = Segment3(w[0], w[1]).closest_point(Vec3::<$t>::zero()).1;
+                        self.keep(&[(0, $t::one() - t), (1, t)]);
+                    }
+                    3 => {
+                        let [u, v, x] = Triangle3(w[0], w[1], w[2]).closest_point(Vec3::<$t>::zero()).1;
+                        self.keep(&[(0, u), (1, v), (2, x)]);
+                    }
+                    _ => {
+                        // each face with the origin on the far side from the fourth vertex
+                        let faces = [[0, 1, 2, 3], [0, 1, 3, 2], [0, 2, 3, 1], [1, 2, 3, 0]];
+                        let mut best: Option<($t, [usize; 3], [$t; 3])> = None;
+                        for [i, j, k, l] in faces {
+                            let n = (w[j] - w[i]).cross(w[k] - w[i]);
+                            let (origin_side, other_side) = (-n.dot(w[i]), n.dot(w[l] - w[i]));
+                            if origin_side * other_side < $t::zero() || other_side == $t::zero() {
+                                let weights = Triangle3(w[i], w[j], w[k]).closest_point(Vec3::<$t>::zero()).1;
+                                let p = w[i] * weights[0] + w[j] * weights[1] + w[k] * weights[2];
+                                if best.map_or(true, |b| p.dot(p) < b.0) {
+                                    best = Some((p.dot(p), [i, j, k], weights));
+                                }
+                            }
+                        }
+                        let Some((_, [i, j, k], [u, v, x])) = best else {
+                            return false;
+                        };
+                        self.keep(&[(i, u), (j, v), (k, x)]);
+                    }
+                }
+                true
+            }
+
+            fn keep(&mut self, weighted: &[(usize, $t)]) {
+                let old = self.vertices;
+                self.len = 0;
+                for &(i, weight) in weighted {
+                    if weight > $t::zero() {
+                        self.vertices[self.len] = old[i];
+                        self.weights[self.len] = weight;
+                        self.len += 1;
+                    }
+                }
+                // a point that only a degenerate feature reaches, keep the first vertex
+                if self.len == 0 {
+                    self.vertices[0] = old[weighted[0].0];
+                    self.weights[0] = $t::one();
+                    self.len = 1;
+                }
+            }
+        }
+    };
+}
+
+impl_gjk!(f32);
+impl_gjk!(f64);
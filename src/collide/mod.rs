@@ -0,0 +1,540 @@
+mod advance;
+mod closest;
+mod epa;
+mod frustum;
+mod gjk;
+mod ray;
+mod shapes;
+mod sweep;
+
+use crate::{Quat, Vec2, Vec3};
+
+// a convex shape described by its support function, the point of the shape farthest along `direction`. any of them
+// can be handed to gjk and epa, which only ever look at shapes through it
+pub trait ConvexShape<S> {
+    fn support(&self, direction: Vec3<S>) -> Vec3<S>;
+}
+
+impl<S, T: ConvexShape<S> + ?Sized> ConvexShape<S> for &T {
+    fn support(&self, direction: Vec3<S>) -> Vec3<S> {
+        (**self).support(direction)
+    }
+}
+
+// the segment between two points, inflated by a radius
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capsule<S>(pub Vec3<S>, pub Vec3<S>, pub S);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Capsule<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Capsule<S> where S: bytemuck::Pod {}
+
+// the straight line between two points, parameterized from 0 at the first to 1 at the second
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment2<S>(pub Vec2<S>, pub Vec2<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Segment2<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Segment2<S> where S: bytemuck::Pod {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment3<S>(pub Vec3<S>, pub Vec3<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Segment3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Segment3<S> where S: bytemuck::Pod {}
+
+// points on a triangle are given by barycentric weights, one per corner and summing to 1
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle2<S>(pub Vec2<S>, pub Vec2<S>, pub Vec2<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Triangle2<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Triangle2<S> where S: bytemuck::Pod {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle3<S>(pub Vec3<S>, pub Vec3<S>, pub Vec3<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Triangle3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Triangle3<S> where S: bytemuck::Pod {}
+
+// the points p with `normal.dot(p) == offset`, for a unit normal
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane<S>(pub Vec3<S>, pub S);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Plane<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Plane<S> where S: bytemuck::Pod {}
+
+// the half line from an origin along a direction, which doesn't need to be unit length. distances along it are in
+// multiples of the direction
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray<S>(pub Vec3<S>, pub Vec3<S>);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Ray<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Ray<S> where S: bytemuck::Pod {}
+
+// the left, right, bottom, top, near and far planes of a view volume, with their normals facing inwards
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum<S>(pub [Plane<S>; 6]);
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Frustum<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Frustum<S> where S: bytemuck::Pod {}
+
+// the convex hull of a point cloud, which doesn't need to be reduced to the hull's vertices first
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConvexHull<'a, S>(pub &'a [Vec3<S>]);
+
+// every point of one shape offset by every point of the other, such as a box with rounded edges from a box and a
+// sphere
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MinkowskiSum<A, B>(pub A, pub B);
+
+// a shape turned by `rotation` about its own origin and then moved to `position`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Posed<T, S> {
+    pub shape: T,
+    pub position: Vec3<S>,
+    pub rotation: Quat<S>,
+}
+
+// how a posed shape moves over one step: its position moves by `linear` and it turns about its position by
+// `angular`, an axis scaled by the angle
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Motion<S> {
+    pub linear: Vec3<S>,
+    pub angular: Vec3<S>,
+}
+
+// settings for the gjk and epa queries, `tolerance` is how close a distance or depth has to get to count as found
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gjk<S> {
+    pub tolerance: S,
+    pub max_iterations: u32,
+}
+
+// closest points between two separated shapes
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClosestPoints<S> {
+    pub distance: S,
+    pub a: Vec3<S>,
+    pub b: Vec3<S>,
+}
+
+// how far two overlapping shapes overlap. `normal` points from a towards b, and moving b by `normal * depth`
+// separates them. `a` and `b` are the deepest points of each shape inside the other
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Penetration<S> {
+    pub depth: S,
+    pub normal: Vec3<S>,
+    pub a: Vec3<S>,
+    pub b: Vec3<S>,
+}
+
+// when and where a moving shape first touches another. `time` is the fraction of the motion done, 0 when they start
+// out touching, and `normal` faces back towards the moving shape, from b towards a when both move
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Toi<S> {
+    pub time: S,
+    pub normal: Vec3<S>,
+    pub point: Vec3<S>,
+}
+
+// a point of the minkowski difference a - b along with the points of a and b it came from
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Vertex<S> {
+    w: Vec3<S>,
+    a: Vec3<S>,
+    b: Vec3<S>,
+}
+
+// up to four vertices with the barycentric weights of the point closest to the origin
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Simplex<S> {
+    vertices: [Vertex<S>; 4],
+    weights: [S; 4],
+    len: usize,
+}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_gjk_spheres() {
+        let gjk = Gjk::<f64>::default();
+        let a = Sphere::<f64>::new(Vec3::<f64>::zero(), 1.0);
+        let b = Sphere::<f64>::new(Vec3::<f64>::new(3.0, 0.0, 0.0), 1.0);
+        let closest = gjk.distance(&a, &b).unwrap();
+        assert!((closest.distance - 1.0).abs() < 1e-6);
+        assert_abs_diff_eq!(closest.a, Vec3::<f64>::unit_x(), epsilon = 1e-3);
+        assert_abs_diff_eq!(closest.b, Vec3::<f64>::new(2.0, 0.0, 0.0), epsilon = 1e-3);
+        assert!(!gjk.intersects(&a, &b) && gjk.penetration(&a, &b).is_none());
+
+        // pushing b out along the normal by the depth leaves them just touching
+        let b = Sphere::<f64>::new(Vec3::<f64>::new(1.5, 0.0, 0.0), 1.0);
+        assert!(gjk.intersects(&a, &b) && gjk.distance(&a, &b).is_none());
+        let hit = gjk.penetration(&a, &b).unwrap();
+        assert!((hit.depth - 0.5).abs() < 1e-3);
+        assert_abs_diff_eq!(hit.normal, Vec3::<f64>::unit_x(), epsilon = 1e-2);
+        assert_abs_diff_eq!(hit.a, Vec3::<f64>::unit_x(), epsilon = 1e-2);
+        assert_abs_diff_eq!(hit.b, Vec3::<f64>::new(0.5, 0.0, 0.0), epsilon = 1e-2);
+
+        // running out of iterations still takes the witnesses from the face the normal came from, so they're
+        // separated by exactly the reported depth along it
+        let b = Sphere::<f64>::new(Vec3::<f64>::new(1.2, 0.7, 0.3), 1.0);
+        for max_iterations in 3..24 {
+            let hit = Gjk::<f64> { max_iterations, ..gjk }.penetration(&a, &b).unwrap();
+            assert_abs_diff_eq!(hit.a - hit.b, hit.normal * hit.depth, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_gjk_boxes() {
+        let gjk = Gjk::<f64>::default();
+        let a = Aabb3::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::one());
+        let b = Obb3::<f64>::new(
+            Vec3::<f64>::new(3.0, 0.5, 0.5),
+            Vec3::<f64>::one() * 0.5,
+            Quat::<f64>::from_rotation_axis(Deg(45.0), Vec3::<f64>::unit_z()),
+        );
+        // b stands on an edge, half a diagonal from its center
+        let closest = gjk.distance(&a, &b).unwrap();
+        assert!((closest.distance - (2.0 - 0.5 * 2f64.sqrt())).abs() < 1e-9);
+        assert!((closest.b[0] - (3.0 - 0.5 * 2f64.sqrt())).abs() < 1e-9);
+
+        // a box sunk 0.25 into the top face of another comes out straight up, not sideways
+        let b = Aabb3::<f64>::new(Vec3::<f64>::new(0.2, 0.3, 0.75), Vec3::<f64>::new(0.7, 0.6, 1.5));
+        let hit = gjk.penetration(&a, &b).unwrap();
+        assert!((hit.depth - 0.25).abs() < 1e-9);
+        assert_abs_diff_eq!(hit.normal, Vec3::<f64>::unit_z(), epsilon = 1e-9);
+
+        // faces touching exactly count as overlapping with no depth
+        let b = Aabb3::<f64>::new(Vec3::<f64>::new(1.0, 0.0, 0.0), Vec3::<f64>::new(2.0, 1.0, 1.0));
+        assert!(gjk.intersects(&a, &b));
+        assert!(gjk.penetration(&a, &b).unwrap().depth < 1e-6);
+    }
+
+    #[test]
+    fn test_gjk_capsules_and_hulls() {
+        let gjk = Gjk::<f64>::default();
+        let a = Capsule::<f64>::new(Vec3::<f64>::new(-1.0, 0.0, 0.0), Vec3::<f64>::new(1.0, 0.0, 0.0), 0.25);
+        let b = Capsule::<f64>::new(Vec3::<f64>::new(0.5, 1.0, -1.0), Vec3::<f64>::new(0.5, 1.0, 1.0), 0.25);
+        let closest = gjk.distance(&a, &b).unwrap();
+        assert!((closest.distance - 0.5).abs() < 1e-9);
+        assert_abs_diff_eq!(closest.a, Vec3::<f64>::new(0.5, 0.25, 0.0), epsilon = 1e-5);
+
+        // a tetrahedron with a corner poking 0.2 below the capsule's top
+        let points = [
+            Vec3::<f64>::new(0.0, 0.05, 0.0),
+            Vec3::<f64>::new(-1.0, 1.0, -1.0),
+            Vec3::<f64>::new(1.0, 1.0, -1.0),
+            Vec3::<f64>::new(0.0, 1.0, 1.0),
+        ];
+        let hull = ConvexHull(&points[..]);
+        let hit = gjk.penetration(&a, &hull).unwrap();
+        // the capsule is round, so the normal only settles to about the square root of the tolerance
+        assert!((hit.depth - 0.2).abs() < 1e-6);
+        assert_abs_diff_eq!(hit.normal, Vec3::<f64>::unit_y(), epsilon = 1e-3);
+        assert_abs_diff_eq!(hit.b, points[0], epsilon = 1e-3);
+
+        // a box grown by a sphere has rounded edges, so its corner is a radius from the original corner
+        let rounded = MinkowskiSum(
+            Aabb3::<f64>::new(-Vec3::<f64>::one(), Vec3::<f64>::one()),
+            Sphere::<f64>::new(Vec3::<f64>::zero(), 0.5),
+        );
+        let far = Vec3::<f64>::one() * 3.0;
+        let closest = gjk.distance(&rounded, &far).unwrap();
+        assert!((closest.distance - (2.0 * 3f64.sqrt() - 0.5)).abs() < 1e-6);
+        assert!(gjk.intersects(&rounded, &Vec3::<f64>::new(1.4, 0.0, 0.0)));
+        assert!(!gjk.intersects(&rounded, &Vec3::<f64>::new(1.4, 1.4, 1.4)));
+    }
+
+    #[test]
+    fn test_closest_segments() {
+        let s = Segment3::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::new(2.0, 0.0, 0.0));
+        assert_eq!(
+            s.closest_point(Vec3::<f64>::new(0.5, 1.0, 0.0)),
+            (Vec3::<f64>::new(0.5, 0.0, 0.0), 0.25)
+        );
+        assert_eq!(s.closest_point(Vec3::<f64>::new(-1.0, 1.0, 0.0)).1, 0.0);
+
+        // skew segments, one above the other
+        let r = Segment3::<f64>::new(Vec3::<f64>::new(1.5, -1.0, 1.0), Vec3::<f64>::new(1.5, 1.0, 1.0));
+        let ((a, s_t), (b, r_t)) = s.closest_to_segment(r);
+        assert_eq!((a, s_t), (Vec3::<f64>::new(1.5, 0.0, 0.0), 0.75));
+        assert_eq!((b, r_t), (Vec3::<f64>::new(1.5, 0.0, 1.0), 0.5));
+        assert_eq!(s.distance_to_segment(r), 1.0);
+
+        // past the end of one, and parallel overlapping ones
+        let r = Segment3::<f64>::new(Vec3::<f64>::new(3.0, -1.0, 0.0), Vec3::<f64>::new(3.0, 1.0, 0.0));
+        assert_eq!(s.closest_to_segment(r).0, (s.1, 1.0));
+        let r = Segment3::<f64>::new(Vec3::<f64>::new(1.0, 1.0, 0.0), Vec3::<f64>::new(4.0, 1.0, 0.0));
+        assert_eq!(s.distance_to_segment(r), 1.0);
+
+        // crossing in 2d, and a point
+        let s = Segment2::<f64>::new(Vec2::<f64>::new(0.0, 0.0), Vec2::<f64>::new(2.0, 2.0));
+        let r = Segment2::<f64>::new(Vec2::<f64>::new(0.0, 2.0), Vec2::<f64>::new(2.0, 0.0));
+        let ((a, s_t), (b, r_t)) = s.closest_to_segment(r);
+        assert_eq!((a, b, s_t, r_t), (Vec2::<f64>::one(), Vec2::<f64>::one(), 0.5, 0.5));
+        let point = Segment2::<f64>::new(Vec2::<f64>::new(3.0, 0.0), Vec2::<f64>::new(3.0, 0.0));
+        assert_eq!(s.closest_to_segment(point).0 .0, Vec2::<f64>::new(1.5, 1.5));
+    }
+
+    #[test]
+    fn test_closest_triangles() {
+        let tri = Triangle3::<f64>::new(
+            Vec3::<f64>::zero(),
+            Vec3::<f64>::new(2.0, 0.0, 0.0),
+            Vec3::<f64>::new(0.0, 2.0, 0.0),
+        );
+        assert_eq!(tri.normal(), Vec3::<f64>::unit_z());
+        let (point, weights) = tri.closest_point(Vec3::<f64>::new(0.5, 0.5, 3.0));
+        assert_eq!(point, Vec3::<f64>::new(0.5, 0.5, 0.0));
+        assert_eq!(tri.at(weights), point);
+        assert_eq!(
+            tri.closest_point(Vec3::<f64>::new(2.0, 2.0, 0.0)).0,
+            Vec3::<f64>::new(1.0, 1.0, 0.0)
+        );
+        assert_eq!(tri.closest_point(Vec3::<f64>::new(-1.0, -1.0, 1.0)).1, [1.0, 0.0, 0.0]);
+
+        // a segment through the face meets it, one beside it comes closest at an edge
+        let through = Segment3::<f64>::new(Vec3::<f64>::new(0.5, 0.5, -1.0), Vec3::<f64>::new(0.5, 0.5, 3.0));
+        let ((a, weights), (b, t)) = tri.closest_to_segment(through);
+        assert_eq!(
+            (a, b, t),
+            (Vec3::<f64>::new(0.5, 0.5, 0.0), Vec3::<f64>::new(0.5, 0.5, 0.0), 0.25)
+        );
+        assert_abs_diff_eq!(tri.at(weights), a, epsilon = 1e-12);
+        let beside = Segment3::<f64>::new(Vec3::<f64>::new(2.0, 2.0, -1.0), Vec3::<f64>::new(2.0, 2.0, 1.0));
+        assert!((beside.distance_to_triangle(tri) - 2f64.sqrt()).abs() < 1e-12);
+        assert_eq!(beside.closest_to_triangle(tri).1 .0, Vec3::<f64>::new(1.0, 1.0, 0.0));
+
+        // a corner of one hovering over the face of the other, and then poking through it
+        let mut other = Triangle3::<f64>::new(
+            Vec3::<f64>::new(0.5, 0.5, 0.25),
+            Vec3::<f64>::new(0.5, 0.0, 2.0),
+            Vec3::<f64>::new(0.0, 0.5, 2.0),
+        );
+        let ((a, _), (b, weights)) = tri.closest_to_triangle(other);
+        assert_abs_diff_eq!(a, Vec3::<f64>::new(0.5, 0.5, 0.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(b, other.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(other.at(weights), b, epsilon = 1e-12);
+        other.0[2] = -0.25;
+        assert!(tri.distance_to_triangle(other) < 1e-12);
+
+        // 2d triangles and boxes
+        let tri = Triangle2::<f64>::new(
+            Vec2::<f64>::zero(),
+            Vec2::<f64>::new(2.0, 0.0),
+            Vec2::<f64>::new(0.0, 2.0),
+        );
+        assert_eq!(
+            tri.closest_point(Vec2::<f64>::new(0.5, 0.5)).0,
+            Vec2::<f64>::new(0.5, 0.5)
+        );
+        let inside = Triangle2::<f64>::new(
+            Vec2::<f64>::new(0.2, 0.2),
+            Vec2::<f64>::new(0.5, 0.2),
+            Vec2::<f64>::new(0.2, 0.5),
+        );
+        assert_eq!(tri.distance_to_triangle(inside), 0.0);
+        let apart = Segment2::<f64>::new(Vec2::<f64>::new(3.0, -1.0), Vec2::<f64>::new(3.0, 3.0));
+        assert_eq!(tri.distance_to_segment(apart), 1.0);
+        let aabb = Aabb2::<f64>::new(Vec2::<f64>::zero(), Vec2::<f64>::one());
+        assert_eq!(
+            aabb.closest_point(Vec2::<f64>::new(2.0, 0.5)),
+            Vec2::<f64>::new(1.0, 0.5)
+        );
+        assert_eq!(aabb.distance_to_point(Vec2::<f64>::new(0.25, 0.5)), 0.0);
+        let aabb = Aabb3::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::one());
+        assert_eq!(aabb.distance_to_point(Vec3::<f64>::new(1.0, 4.0, 5.0)), 5.0);
+    }
+
+    #[test]
+    fn test_sweep_sphere() {
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(0.0, 5.0, 0.0), 1.0);
+        let ground = Plane::<f64>::new(Vec3::<f64>::unit_y(), 0.0);
+        let hit = ball.sweep_plane(Vec3::<f64>::new(0.0, -10.0, 0.0), ground).unwrap();
+        assert_eq!(
+            (hit.time, hit.normal, hit.point),
+            (0.4, Vec3::<f64>::unit_y(), Vec3::<f64>::zero())
+        );
+        assert!(ball.sweep_plane(Vec3::<f64>::new(0.0, 10.0, 0.0), ground).is_none());
+        assert!(ball.sweep_plane(Vec3::<f64>::new(0.0, -3.0, 0.0), ground).is_none());
+
+        // square onto the face, then sideways into an edge and a corner
+        let tri = Triangle3::<f64>::new(
+            Vec3::<f64>::zero(),
+            Vec3::<f64>::new(2.0, 0.0, 0.0),
+            Vec3::<f64>::new(0.0, 2.0, 0.0),
+        );
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(0.5, 0.5, 5.0), 1.0);
+        let hit = ball.sweep_triangle(Vec3::<f64>::new(0.0, 0.0, -10.0), tri).unwrap();
+        assert_eq!((hit.time, hit.normal), (0.4, Vec3::<f64>::unit_z()));
+        assert_abs_diff_eq!(hit.point, Vec3::<f64>::new(0.5, 0.5, 0.0), epsilon = 1e-12);
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(1.0, -3.0, 0.0), 0.5);
+        let hit = ball.sweep_triangle(Vec3::<f64>::new(0.0, 4.0, 0.0), tri).unwrap();
+        assert!((hit.time - 0.625).abs() < 1e-12);
+        assert_abs_diff_eq!(hit.normal, -Vec3::<f64>::unit_y(), epsilon = 1e-12);
+        assert_abs_diff_eq!(hit.point, Vec3::<f64>::unit_x(), epsilon = 1e-12);
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(-3.0, -3.0, 0.0), 0.5);
+        let hit = ball.sweep_triangle(Vec3::<f64>::new(4.0, 4.0, 0.0), tri).unwrap();
+        assert!((hit.time - (18f64.sqrt() - 0.5) / 32f64.sqrt()).abs() < 1e-12);
+        assert_eq!(hit.point, Vec3::<f64>::zero());
+
+        // onto a face of a box, an edge, and just past a corner that the grown box would have caught
+        let aabb = Aabb3::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::one());
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(0.5, 0.5, 3.0), 0.5);
+        let hit = ball.sweep_aabb(Vec3::<f64>::new(0.0, 0.0, -4.0), aabb).unwrap();
+        assert_eq!((hit.time, hit.normal), (0.375, Vec3::<f64>::unit_z()));
+        assert_eq!(hit.point, Vec3::<f64>::new(0.5, 0.5, 1.0));
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(3.0, 0.5, 3.0), 0.5);
+        let hit = ball.sweep_aabb(Vec3::<f64>::new(-4.0, 0.0, -4.0), aabb).unwrap();
+        assert!((hit.time - (2.0 - 0.5 / 2f64.sqrt()) / 4.0).abs() < 1e-12);
+        assert_abs_diff_eq!(
+            hit.normal,
+            Vec3::<f64>::new(1.0, 0.0, 1.0).normalized(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(hit.point, Vec3::<f64>::new(1.0, 0.5, 1.0), epsilon = 1e-12);
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(1.4, 1.4, -2.0), 0.5);
+        assert!(ball.sweep_aabb(Vec3::<f64>::new(0.0, 0.0, 4.0), aabb).is_none());
+        let ball = Sphere::<f64>::new(Vec3::<f64>::new(0.5, 0.5, 0.9), 0.5);
+        let hit = ball.sweep_aabb(Vec3::<f64>::new(0.0, 0.0, 4.0), aabb).unwrap();
+        assert_eq!((hit.time, hit.normal), (0.0, Vec3::<f64>::unit_z()));
+
+        // boxes
+        let other = Aabb3::<f64>::new(Vec3::<f64>::new(3.0, 0.0, 0.0), Vec3::<f64>::new(4.0, 1.0, 1.0));
+        let hit = aabb.sweep_aabb(Vec3::<f64>::new(4.0, 0.0, 0.0), other).unwrap();
+        assert_eq!((hit.time, hit.normal), (0.5, -Vec3::<f64>::unit_x()));
+        assert_eq!(hit.point, Vec3::<f64>::new(3.0, 0.5, 0.5));
+        assert!(aabb.sweep_aabb(Vec3::<f64>::new(4.0, 3.0, 0.0), other).is_none());
+        assert!(aabb.sweep_aabb(Vec3::<f64>::new(1.0, 0.0, 0.0), other).is_none());
+    }
+
+    #[test]
+    fn test_time_of_impact() {
+        let gjk = Gjk::<f64>::default();
+        let ball = |x: f64| {
+            Posed::<Sphere<f64>, f64>::new(
+                Sphere::<f64>::new(Vec3::<f64>::zero(), 1.0),
+                Vec3::<f64>::new(x, 0.0, 0.0),
+                Quat::<f64>::identity(),
+            )
+        };
+        let fast = Motion {
+            linear: Vec3::<f64>::new(10.0, 0.0, 0.0),
+            angular: Vec3::<f64>::zero(),
+        };
+        let hit = gjk
+            .time_of_impact(&ball(0.0), fast, &ball(5.0), Motion::default(), 1e-4)
+            .unwrap();
+        assert!(hit.time <= 0.3 && hit.time > 0.3 - 1e-4);
+        assert_abs_diff_eq!(hit.normal, -Vec3::<f64>::unit_x(), epsilon = 1e-6);
+        assert_abs_diff_eq!(hit.point, Vec3::<f64>::new(4.0, 0.0, 0.0), epsilon = 1e-3);
+        assert!(gjk
+            .time_of_impact(&ball(0.0), Motion::default(), &ball(5.0), fast, 1e-4)
+            .is_none());
+        assert_eq!(
+            gjk.time_of_impact(&ball(0.0), fast, &ball(1.5), Motion::default(), 1e-4)
+                .unwrap()
+                .time,
+            0.0
+        );
+
+        // a bar swinging a quarter turn about z clips a small sphere near the end of the turn
+        let bar = Posed::<Aabb3<f64>, f64>::new(
+            Aabb3::<f64>::new(Vec3::<f64>::new(-2.0, -0.1, -0.1), Vec3::<f64>::new(2.0, 0.1, 0.1)),
+            Vec3::<f64>::zero(),
+            Quat::<f64>::identity(),
+        );
+        let swing = Motion {
+            linear: Vec3::<f64>::zero(),
+            angular: Vec3::<f64>::unit_z() * core::f64::consts::FRAC_PI_2,
+        };
+        let target = Posed::<Sphere<f64>, f64>::new(
+            Sphere::<f64>::new(Vec3::<f64>::zero(), 0.1),
+            Vec3::<f64>::new(0.0, 1.5, 0.0),
+            Quat::<f64>::identity(),
+        );
+        let hit = gjk
+            .time_of_impact(&bar, swing, &target, Motion::default(), 1e-4)
+            .unwrap();
+        let expected = (0.2f64 / 1.5).acos() / core::f64::consts::FRAC_PI_2;
+        assert!(hit.time <= expected && hit.time > expected - 1e-3);
+        assert!(gjk.distance(&bar.moved(swing, hit.time), &target).unwrap().distance <= 1e-4);
+    }
+
+    #[test]
+    fn test_ray() {
+        let ray = Ray::<f64>::new(Vec3::<f64>::new(0.0, 0.0, -5.0), Vec3::<f64>::new(0.0, 0.0, 2.0));
+        assert_eq!(
+            ray.intersect_sphere(Sphere::<f64>::new(Vec3::<f64>::zero(), 1.0), 10.0),
+            Some(2.0)
+        );
+        assert_eq!(
+            ray.intersect_sphere(Sphere::<f64>::new(Vec3::<f64>::zero(), 1.0), 1.0),
+            None
+        );
+        assert_eq!(ray.intersect_sphere(Sphere::<f64>::new(ray.0, 1.0), 1.0), Some(0.0));
+        assert_eq!(
+            ray.intersect_plane(Plane::<f64>::new(Vec3::<f64>::unit_z(), 1.0), 10.0),
+            Some(3.0)
+        );
+        assert_eq!(
+            ray.intersect_plane(Plane::<f64>::new(Vec3::<f64>::unit_x(), 1.0), 10.0),
+            None
+        );
+        let aabb = Aabb3::<f64>::new(-Vec3::<f64>::one(), Vec3::<f64>::one());
+        assert_eq!(ray.intersect_aabb(aabb, 10.0), Some(2.0));
+        assert_eq!(
+            Ray::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::unit_x()).intersect_aabb(aabb, 10.0),
+            Some(0.0)
+        );
+
+        let tri = Triangle3::<f64>::new(
+            Vec3::<f64>::new(-1.0, -1.0, 0.0),
+            Vec3::<f64>::new(3.0, -1.0, 0.0),
+            Vec3::<f64>::new(-1.0, 3.0, 0.0),
+        );
+        let (t, weights) = ray.intersect_triangle(tri, 10.0).unwrap();
+        assert_eq!(t, 2.5);
+        assert_eq!(tri.at(weights), Vec3::<f64>::zero());
+        assert!(ray
+            .intersect_triangle(Triangle3::<f64>::new(tri.0 * -1.0, tri.1 * -1.0, tri.2 * -1.0), 10.0)
+            .is_some());
+        let beside = Ray::<f64>::new(Vec3::<f64>::new(2.0, 2.0, -5.0), Vec3::<f64>::unit_z());
+        assert!(beside.intersect_triangle(tri, 10.0).is_none());
+
+        // a frustum looking down -z
+        let frustum = Frustum::<f64>::from_matrix(Mat4::<f64>::perspective(Deg(90.0), 1.0, 0.1, 100.0));
+        assert!(frustum.contains(Vec3::<f64>::new(4.9, 0.0, -5.0)));
+        assert!(!frustum.contains(Vec3::<f64>::new(5.1, 0.0, -5.0)));
+        assert!(!frustum.contains(Vec3::<f64>::new(0.0, 0.0, 5.0)));
+        assert!(!frustum.contains(Vec3::<f64>::new(0.0, 0.0, -0.05)));
+        assert!(!frustum.contains(Vec3::<f64>::new(0.0, 0.0, -101.0)));
+        assert!(frustum.intersects_sphere(Sphere::<f64>::new(Vec3::<f64>::new(0.0, 0.0, 1.0), 1.5)));
+        assert!(!frustum.intersects_sphere(Sphere::<f64>::new(Vec3::<f64>::new(0.0, 0.0, 1.0), 0.5)));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::*;
+
+// each returns the distance along the ray of the first hit no further than `max_t`, 0 when the ray starts inside
+macro_rules! impl_ray {
+    ($t:ident) => {
+        impl Ray<$t> {
+            pub fn new(origin: Vec3<$t>, direction: Vec3<$t>) -> Self {
+                Self(origin, direction)
+            }
+
+            pub fn origin(&self) -> Vec3<$t> {
+                self.0
+            }
+
+            pub fn direction(&self) -> Vec3<$t> {
+                self.1
+            }
+
+            pub fn at(&self, t: $t) -> Vec3<$t> {
+                self.0 + self.1 * t
+            }
+
+            // the slabs between each pair of faces, the ray is inside the box where it is inside all three
+            pub fn intersect_aabb(&self, aabb: Aabb3<$t>, max_t: $t) -> Option<$t> {
+                let (mut enter, mut exit) = ($t::zero(), max_t);
+                for i in 0..3 {
+                    if self.1[i] == $t::zero() {
+                        if self.0[i] < aabb.0[i] || self.0[i] > aabb.1[i] {
+                            return None;
+                        }
+                        continue;
+                    }
+                    let inv = $t::one() / self.1[i];
+                    let (t0, t1) = ((aabb.0[i] - self.0[i]) * inv, (aabb.1[i] - self.0[i]) * inv);
+                    enter = enter.max(t0.min(t1));
+                    exit = exit.min(t0.max(t1));
+                    if enter > exit {
+                        return None;
+                    }
+                }
+                Some(enter)
+            }
+
+            pub fn intersect_sphere(&self, sphere: Sphere<$t>, max_t: $t) -> Option<$t> {
+                let m = self.0 - sphere.0;
+                let (a, b, c) = (self.1.dot(self.1), m.dot(self.1), m.dot(m) - sphere.1 * sphere.1);
+                if c <= $t::zero() {
+                    return Some($t::zero());
+                }
+                let disc = b * b - a * c;
+                if a == $t::zero() || b >= $t::zero() || disc < $t::zero() {
+                    return None;
+                }
+                let t = (-b - Float::sqrt(disc)) / a;
+                (t <= max_t).then_some(t)
+            }
+
+            // either side of the plane
+            pub fn intersect_plane(&self, plane: Plane<$t>, max_t: $t) -> Option<$t> {
+                let (dist, speed) = (plane.signed_distance(self.0), plane.0.dot(self.1));
+                if dist == $t::zero() {
+                    return Some($t::zero());
+                }
+                if speed == $t::zero() {
+                    return None;
+                }
+                let t = -dist / speed;
+                (t >= $t::zero() && t <= max_t).then_some(t)
+            }
+
+            // moller-trumbore, from either side, with the barycentric weights of the hit
+            pub fn intersect_triangle(&self, triangle: Triangle3<$t>, max_t: $t) -> Option<($t, [$t; 3])> {
+                let (e1, e2) = (triangle.1 - triangle.0, triangle.2 - triangle.0);
+                let p = self.1.cross(e2);
+                let det = e1.dot(p);
+                if det == $t::zero() {
+                    return None;
+                }
+                let inv = $t::one() / det;
+                let s = self.0 - triangle.0;
+                let u = s.dot(p) * inv;
+                let q = s.cross(e1);
+                let v = self.1.dot(q) * inv;
+                if u < $t::zero() || v < $t::zero() || u + v > $t::one() {
+                    return None;
+                }
+                let t = e2.dot(q) * inv;
+                (t >= $t::zero() && t <= max_t).then_some((t, [$t::one() - u - v, u, v]))
+            }
+        }
+    };
+}
+
+impl_ray!(f32);
+impl_ray!(f64);
@@ -0,0 +1,235 @@
+use crate::*;
+
+macro_rules! impl_shapes {
+    ($t:ident) => {
+        impl Capsule<$t> {
+            pub fn new(a: Vec3<$t>, b: Vec3<$t>, radius: $t) -> Self {
+                Self(a, b, radius)
+            }
+
+            pub fn a(&self) -> Vec3<$t> {
+                self.0
+            }
+
+            pub fn b(&self) -> Vec3<$t> {
+                self.1
+            }
+
+            pub fn radius(&self) -> $t {
+                self.2
+            }
+
+            pub fn to_aabb(&self) -> Aabb3<$t> {
+                let reach = Vec3::<$t>::one() * self.2;
+                Aabb3::<$t>::from_points(&[self.0 - reach, self.0 + reach, self.1 - reach, self.1 + reach])
+            }
+        }
+
+        impl Segment2<$t> {
+            pub fn new(a: Vec2<$t>, b: Vec2<$t>) -> Self {
+                Self(a, b)
+            }
+
+            pub fn at(&self, t: $t) -> Vec2<$t> {
+                self.0 + (self.1 - self.0) * t
+            }
+
+            pub fn length(&self) -> $t {
+                (self.1 - self.0).length()
+            }
+        }
+
+        impl Segment3<$t> {
+            pub fn new(a: Vec3<$t>, b: Vec3<$t>) -> Self {
+                Self(a, b)
+            }
+
+            pub fn at(&self, t: $t) -> Vec3<$t> {
+                self.0 + (self.1 - self.0) * t
+            }
+
+            pub fn length(&self) -> $t {
+                (self.1 - self.0).length()
+            }
+        }
+
+        impl Triangle2<$t> {
+            pub fn new(a: Vec2<$t>, b: Vec2<$t>, c: Vec2<$t>) -> Self {
+                Self(a, b, c)
+            }
+
+            // the point with the given barycentric weights
+            pub fn at(&self, weights: [$t; 3]) -> Vec2<$t> {
+                self.0 * weights[0] + self.1 * weights[1] + self.2 * weights[2]
+            }
+        }
+
+        impl Triangle3<$t> {
+            pub fn new(a: Vec3<$t>, b: Vec3<$t>, c: Vec3<$t>) -> Self {
+                Self(a, b, c)
+            }
+
+            pub fn at(&self, weights: [$t; 3]) -> Vec3<$t> {
+                self.0 * weights[0] + self.1 * weights[1] + self.2 * weights[2]
+            }
+
+            // unit normal, facing the side the corners wind counterclockwise around
+            pub fn normal(&self) -> Vec3<$t> {
+                (self.1 - self.0).cross(self.2 - self.0).normalized()
+            }
+        }
+
+        impl Plane<$t> {
+            pub fn new(normal: Vec3<$t>, offset: $t) -> Self {
+                Self(normal, offset)
+            }
+
+            pub fn from_point_normal(point: Vec3<$t>, normal: Vec3<$t>) -> Self {
+                Self(normal, normal.dot(point))
+            }
+
+            pub fn normal(&self) -> Vec3<$t> {
+                self.0
+            }
+
+            pub fn offset(&self) -> $t {
+                self.1
+            }
+
+            // positive on the side the normal faces
+            pub fn signed_distance(&self, point: Vec3<$t>) -> $t {
+                self.0.dot(point) - self.1
+            }
+
+            pub fn closest_point(&self, point: Vec3<$t>) -> Vec3<$t> {
+                point - self.0 * self.signed_distance(point)
+            }
+        }
+
+        impl<T: Clone> Posed<T, $t> {
+            pub fn new(shape: T, position: Vec3<$t>, rotation: Quat<$t>) -> Self {
+                Self {
+                    shape,
+                    position,
+                    rotation,
+                }
+            }
+
+            // where `motion` has taken it after the fraction `t` of the step
+            pub fn moved(&self, motion: Motion<$t>, t: $t) -> Self {
+                Self {
+                    shape: self.shape.clone(),
+                    position: self.position + motion.linear * t,
+                    rotation: (Quat::<$t>::from_scaled_axis(motion.angular * t) * self.rotation).normalized(),
+                }
+            }
+        }
+
+        // support functions
+        // ---------------------------------------------------------------------------------------------------
+        impl ConvexShape<$t> for Vec3<$t> {
+            fn support(&self, _: Vec3<$t>) -> Vec3<$t> {
+                *self
+            }
+        }
+
+        impl ConvexShape<$t> for Sphere<$t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                self.0 + direction.normalized() * self.1
+            }
+        }
+
+        impl ConvexShape<$t> for Capsule<$t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                let end = if direction.dot(self.1 - self.0) > $t::zero() {
+                    self.1
+                } else {
+                    self.0
+                };
+                end + direction.normalized() * self.2
+            }
+        }
+
+        impl ConvexShape<$t> for Segment3<$t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                if direction.dot(self.1 - self.0) > $t::zero() {
+                    self.1
+                } else {
+                    self.0
+                }
+            }
+        }
+
+        impl ConvexShape<$t> for Triangle3<$t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                let (a, b, c) = (
+                    direction.dot(self.0),
+                    direction.dot(self.1),
+                    direction.dot(self.2),
+                );
+                if a >= b && a >= c {
+                    self.0
+                } else if b >= c {
+                    self.1
+                } else {
+                    self.2
+                }
+            }
+        }
+
+        impl ConvexShape<$t> for Aabb3<$t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                let pick = |i: usize| {
+                    if direction[i] < $t::zero() {
+                        self.0[i]
+                    } else {
+                        self.1[i]
+                    }
+                };
+                Vec3::<$t>::new(pick(0), pick(1), pick(2))
+            }
+        }
+
+        impl ConvexShape<$t> for Obb3<$t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                let axes = self.axes();
+                (0..3).fold(self.0, |p, i| {
+                    let reach = if direction.dot(axes[i]) < $t::zero() {
+                        -self.1[i]
+                    } else {
+                        self.1[i]
+                    };
+                    p + axes[i] * reach
+                })
+            }
+        }
+
+        impl<T: ConvexShape<$t>> ConvexShape<$t> for Posed<T, $t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                self.position + self.rotation * self.shape.support(self.rotation.conjugate() * direction)
+            }
+        }
+
+        impl ConvexShape<$t> for ConvexHull<'_, $t> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                let mut best = (Vec3::<$t>::zero(), $t::NEG_INFINITY);
+                for &p in self.0 {
+                    let d = p.dot(direction);
+                    if d > best.1 {
+                        best = (p, d);
+                    }
+                }
+                best.0
+            }
+        }
+
+        impl<A: ConvexShape<$t>, B: ConvexShape<$t>> ConvexShape<$t> for MinkowskiSum<A, B> {
+            fn support(&self, direction: Vec3<$t>) -> Vec3<$t> {
+                self.0.support(direction) + self.1.support(direction)
+            }
+        }
+    };
+}
+
+impl_shapes!(f32);
+impl_shapes!(f64);
@@ -0,0 +1,286 @@
+use crate::*;
+
+// swept tests move one shape along a straight line over the step, with the other one standing still. for two moving
+// shapes, sweep by the difference of their motions
+macro_rules! impl_sweep {
+    ($t:ident) => {
+        impl Sphere<$t> {
+            pub fn sweep_plane(&self, motion: Vec3<$t>, plane: Plane<$t>) -> Option<Toi<$t>> {
+                let dist = plane.signed_distance(self.0);
+                let normal = if dist < $t::zero() { -plane.0 } else { plane.0 };
+                let time = if dist.abs() <= self.1 {
+                    $t::zero()
+                } else {
+                    let (gap, approach) = (dist.abs() - self.1, -normal.dot(motion));
+                    if approach <= $t::zero() || gap > approach {
+                        return None;
+                    }
+                    gap / approach
+                };
+                Some(Toi {
+                    time,
+                    normal,
+                    point: plane.closest_point(self.0 + motion * time),
+                })
+            }
+
+            // the face if the sphere reaches it square on, otherwise the edges and corners as capsules around them
+            pub fn sweep_triangle(&self, motion: Vec3<$t>, triangle: Triangle3<$t>) -> Option<Toi<$t>> {
+                let (center, radius) = (self.0, self.1);
+                let (closest, _) = triangle.closest_point(center);
+                if (center - closest).length() <= radius {
+                    let normal = (center - closest).normalized();
+                    return Some(Toi {
+                        time: $t::zero(),
+                        normal: if normal == Vec3::<$t>::zero() {
+                            triangle.normal()
+                        } else {
+                            normal
+                        },
+                        point: closest,
+                    });
+                }
+
+                let n = triangle.normal();
+                if n != Vec3::<$t>::zero() {
+                    if let Some(hit) = self.sweep_plane(motion, Plane::<$t>::from_point_normal(triangle.0, n)) {
+                        if triangle
+                            .closest_point(hit.point)
+                            .1
+                            .iter()
+                            .all(|&w| w > $t::zero())
+                        {
+                            return Some(hit);
+                        }
+                    }
+                }
+                let edges = [
+                    (triangle.0, triangle.1),
+                    (triangle.1, triangle.2),
+                    (triangle.2, triangle.0),
+                ];
+                Self::sweep_edges(center, motion, radius, &edges)
+            }
+
+            pub fn sweep_aabb(&self, motion: Vec3<$t>, aabb: Aabb3<$t>) -> Option<Toi<$t>> {
+                let (center, radius) = (self.0, self.1);
+                let closest = aabb.closest_point(center);
+                if (center - closest).length() <= radius {
+                    let normal = if closest == center {
+                        aabb.escape_normal(center)
+                    } else {
+                        (center - closest).normalized()
+                    };
+                    return Some(Toi {
+                        time: $t::zero(),
+                        normal,
+                        point: closest,
+                    });
+                }
+
+                // the box grown by the radius is right apart from its corners, where the center enters it outside
+                // the box on two or three axes and has to get past the rounded edges instead
+                let reach = Vec3::<$t>::one() * radius;
+                let grown = Aabb3::<$t>::new(aabb.0 - reach, aabb.1 + reach);
+                let hit = Aabb3::<$t>::new(center, center).sweep_aabb(motion, grown)?;
+                let p = center + motion * hit.time;
+                let (mut corner, mut outside) = (p, [false; 3]);
+                for i in 0..3 {
+                    if p[i] < aabb.0[i] {
+                        corner[i] = aabb.0[i];
+                        outside[i] = true;
+                    } else if p[i] > aabb.1[i] {
+                        corner[i] = aabb.1[i];
+                        outside[i] = true;
+                    }
+                }
+                let flipped = |corner: Vec3<$t>, i: usize| {
+                    let mut end = corner;
+                    end[i] = if corner[i] == aabb.0[i] {
+                        aabb.1[i]
+                    } else {
+                        aabb.0[i]
+                    };
+                    end
+                };
+                match outside.iter().filter(|&&o| o).count() {
+                    0 | 1 => Some(Toi {
+                        time: hit.time,
+                        normal: hit.normal,
+                        point: p - hit.normal * radius,
+                    }),
+                    2 => {
+                        let i = outside.iter().position(|&o| !o).unwrap_or(0);
+                        corner[i] = aabb.0[i];
+                        Self::sweep_edges(center, motion, radius, &[(corner, flipped(corner, i))])
+                    }
+                    _ => Self::sweep_edges(
+                        center,
+                        motion,
+                        radius,
+                        &[
+                            (corner, flipped(corner, 0)),
+                            (corner, flipped(corner, 1)),
+                            (corner, flipped(corner, 2)),
+                        ],
+                    ),
+                }
+            }
+
+            // the earliest of the capsules of `radius` around the edges
+            fn sweep_edges(
+                center: Vec3<$t>,
+                motion: Vec3<$t>,
+                radius: $t,
+                edges: &[(Vec3<$t>, Vec3<$t>)],
+            ) -> Option<Toi<$t>> {
+                let mut best: Option<($t, Vec3<$t>)> = None;
+                for &(a, b) in edges {
+                    if let Some((time, point)) = Capsule::<$t>::new(a, b, radius).entry(center, motion) {
+                        if best.map_or(true, |(t, _)| time < t) {
+                            best = Some((time, point));
+                        }
+                    }
+                }
+                let (time, point) = best?;
+                Some(Toi {
+                    time,
+                    normal: (center + motion * time - point).normalized(),
+                    point,
+                })
+            }
+
+            // when a point moving by `motion` from `origin` first gets inside
+            fn entry(&self, origin: Vec3<$t>, motion: Vec3<$t>) -> Option<$t> {
+                let m = origin - self.0;
+                let (a, b, c) = (motion.dot(motion), m.dot(motion), m.dot(m) - self.1 * self.1);
+                if c <= $t::zero() {
+                    return Some($t::zero());
+                }
+                let disc = b * b - a * c;
+                if a == $t::zero() || b >= $t::zero() || disc < $t::zero() {
+                    return None;
+                }
+                let t = (-b - Float::sqrt(disc)) / a;
+                (t <= $t::one()).then_some(t)
+            }
+        }
+
+        impl Capsule<$t> {
+            // when a point moving by `motion` from `origin` first gets inside, along with the point of the axis it
+            // is then closest to
+            fn entry(&self, origin: Vec3<$t>, motion: Vec3<$t>) -> Option<($t, Vec3<$t>)> {
+                let (a, b, radius) = (self.0, self.1, self.2);
+                let mut best = None;
+                let e = b - a;
+                let ee = e.dot(e);
+                if ee > $t::zero() {
+                    // the side, as an infinite cylinder cut back to the segment
+                    let w = origin - a;
+                    let (d, w_off) = (motion - e * (motion.dot(e) / ee), w - e * (w.dot(e) / ee));
+                    let (qa, qb, qc) = (d.dot(d), w_off.dot(d), w_off.dot(w_off) - radius * radius);
+                    let disc = qb * qb - qa * qc;
+                    if qa > $t::zero() && qc > $t::zero() && disc >= $t::zero() {
+                        let t = (-qb - Float::sqrt(disc)) / qa;
+                        let s = (w + motion * t).dot(e) / ee;
+                        let inside = |x: $t| x >= $t::zero() && x <= $t::one();
+                        if inside(t) && inside(s) {
+                            best = Some((t, a + e * s));
+                        }
+                    }
+                }
+                for end in [a, b] {
+                    if let Some(t) = Sphere::<$t>::new(end, radius).entry(origin, motion) {
+                        if best.map_or(true, |(b, _)| t < b) {
+                            best = Some((t, end));
+                        }
+                    }
+                }
+                best
+            }
+        }
+
+        impl Aabb3<$t> {
+            // sweeps the box along `motion` past `rhs` by the times it enters and leaves the slab of each axis
+            pub fn sweep_aabb(&self, motion: Vec3<$t>, rhs: Self) -> Option<Toi<$t>> {
+                if self.intersects(rhs) {
+                    // out along the axis they overlap least on
+                    let overlap = |i: usize| (self.1[i] - rhs.0[i]).min(rhs.1[i] - self.0[i]);
+                    let axis = (1..3).fold(0, |best, i| if overlap(i) < overlap(best) { i } else { best });
+                    let mut normal = Vec3::<$t>::zero();
+                    normal[axis] = if self.center()[axis] < rhs.center()[axis] {
+                        -$t::one()
+                    } else {
+                        $t::one()
+                    };
+                    return Some(Toi {
+                        time: $t::zero(),
+                        normal,
+                        point: self.overlap_center(rhs),
+                    });
+                }
+
+                let (mut enter, mut exit, mut axis) = ($t::NEG_INFINITY, $t::INFINITY, 0);
+                for i in 0..3 {
+                    if motion[i] == $t::zero() {
+                        if self.1[i] < rhs.0[i] || rhs.1[i] < self.0[i] {
+                            return None;
+                        }
+                        continue;
+                    }
+                    let (t0, t1) = (
+                        (rhs.0[i] - self.1[i]) / motion[i],
+                        (rhs.1[i] - self.0[i]) / motion[i],
+                    );
+                    if t0.min(t1) > enter {
+                        enter = t0.min(t1);
+                        axis = i;
+                    }
+                    exit = exit.min(t0.max(t1));
+                }
+                if enter > exit || enter > $t::one() || enter < $t::zero() {
+                    return None;
+                }
+                let mut normal = Vec3::<$t>::zero();
+                normal[axis] = if motion[axis] > $t::zero() {
+                    -$t::one()
+                } else {
+                    $t::one()
+                };
+                let moved = Self::new(self.0 + motion * enter, self.1 + motion * enter);
+                Some(Toi {
+                    time: enter,
+                    normal,
+                    point: moved.overlap_center(rhs),
+                })
+            }
+
+            // the center of the box two touching or overlapping boxes share
+            fn overlap_center(&self, rhs: Self) -> Vec3<$t> {
+                let mid = |i: usize| (self.0[i].max(rhs.0[i]) + self.1[i].min(rhs.1[i])) * $t::splat(0.5);
+                Vec3::<$t>::new(mid(0), mid(1), mid(2))
+            }
+
+            // the way out of the box through its nearest face, for a point inside
+            fn escape_normal(&self, point: Vec3<$t>) -> Vec3<$t> {
+                let mut best = ($t::INFINITY, Vec3::<$t>::zero());
+                for i in 0..3 {
+                    let mut normal = Vec3::<$t>::zero();
+                    for (dist, sign) in [
+                        (point[i] - self.0[i], -$t::one()),
+                        (self.1[i] - point[i], $t::one()),
+                    ] {
+                        if dist < best.0 {
+                            normal[i] = sign;
+                            best = (dist, normal);
+                        }
+                    }
+                }
+                best.1
+            }
+        }
+    };
+}
+
+impl_sweep!(f32);
+impl_sweep!(f64);
@@ -0,0 +1,77 @@
+#![allow(clippy::excessive_precision)]
+
+use crate::*;
+
+macro_rules! impl_grading {
+    ($t:ident) => {
+        impl Vec3<$t> {
+            // rec. 709 / srgb primaries
+            pub fn luminance(&self) -> $t {
+                self.dot(Self::new(0.2126, 0.7152, 0.0722))
+            }
+
+            pub fn with_luminance(&self, luminance: $t) -> Self {
+                let current = self.luminance();
+                if current == $t::zero() {
+                    Self::one() * luminance
+                } else {
+                    *self * (luminance / current)
+                }
+            }
+
+            // scales by 2^ev
+            pub fn exposed(&self, ev: $t) -> Self {
+                *self * Float::exp2(ev)
+            }
+
+            // linear srgb of a planckian radiator, normalized so the brightest channel is 1.0
+            // valid between 1667k and 25000k, temperatures outside that range are clamped
+            pub fn from_temperature(kelvin: $t) -> Self {
+                let t = kelvin.clamp(1667.0, 25000.0);
+                let (t2, t3) = (t * t, t * t * t);
+
+                let x = if t <= 4000.0 {
+                    -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910
+                } else {
+                    -3.0258469e9 / t3 + 2.1070379e6 / t2 + 0.2226347e3 / t + 0.240390
+                };
+
+                let (x2, x3) = (x * x, x * x * x);
+                let y = if t <= 2222.0 {
+                    -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+                } else if t <= 4000.0 {
+                    -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+                } else {
+                    3.0817580 * x3 - 5.87338670 * x2 + 3.75112997 * x - 0.37001483
+                };
+
+                let xyz = Self::new(x / y, $t::one(), ($t::one() - x - y) / y);
+                #[rustfmt::skip]
+                let xyz_to_rgb = Mat3::<$t>::new(
+                    3.2404542, -0.9692660, 0.0556434,
+                    -1.5371385, 1.8760108, -0.2040259,
+                    -0.4985314, 0.0415560, 1.0572252,
+                );
+
+                let rgb = Self((xyz_to_rgb * xyz).0.map(|c| c.max($t::zero())));
+                rgb / rgb[0].max(rgb[1]).max(rgb[2])
+            }
+
+            // neutralizes light of the given temperature so it renders as d65 white, keeping luminance
+            pub fn white_balanced(&self, kelvin: $t) -> Self {
+                let reference = Self::from_temperature(6504.0);
+                // below about 1950k the blue channel is zero, so floor the divisors to keep the gains finite
+                let source = Self(Self::from_temperature(kelvin).0.map(|c| c.max(0.001)));
+                let balanced = Self([
+                    self[0] * reference[0] / source[0],
+                    self[1] * reference[1] / source[1],
+                    self[2] * reference[2] / source[2],
+                ]);
+                balanced.with_luminance(self.luminance())
+            }
+        }
+    };
+}
+
+impl_grading!(f32);
+impl_grading!(f64);
@@ -0,0 +1,67 @@
+mod grading;
+mod tonemap;
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn close(a: Vec3<f32>, b: Vec3<f32>, eps: f32) -> bool {
+        (a - b).0.iter().all(|d| d.abs() <= eps)
+    }
+
+    #[test]
+    fn test_tonemap_curves() {
+        let black = Vec3::<f32>::zero();
+        let grey = Vec3::<f32>::new(0.18, 0.18, 0.18);
+        let hot = Vec3::<f32>::new(16.0, 4.0, 1.0);
+
+        assert_eq!(Vec3::<f32>::one().reinhard(), Vec3::<f32>::one() * 0.5);
+        assert_eq!(Vec3::<f32>::one().reinhard_extended(1.0), Vec3::<f32>::one());
+
+        for c in [hot.reinhard(), hot.aces_fitted(), hot.agx()] {
+            assert!(c.0.iter().all(|&v| (0.0..=1.0001).contains(&v)), "{}", c);
+        }
+
+        assert!(close(black.aces_fitted(), black, 1e-3));
+        assert!(close(black.hable(), black, 1e-6));
+        assert!(close((Vec3::<f32>::one() * 5.6).hable(), Vec3::<f32>::one(), 1e-5));
+        assert!(hot.hable()[0] > 1.0 && hot.hable()[1] < hot.hable()[0]);
+
+        // reference values from the hlsl / glsl implementations evaluated in double precision
+        let c = Vec3::<f32>::new(1.0, 0.5, 0.25);
+        assert!(close(grey.aces_fitted(), Vec3::<f32>::one() * 0.105591, 1e-5));
+        assert!(close(
+            c.aces_fitted(),
+            Vec3::<f32>::new(0.634990, 0.384600, 0.203160),
+            1e-5
+        ));
+        assert!(close(grey.agx(), Vec3::<f32>::new(0.214467, 0.214533, 0.214537), 1e-4));
+        assert!(close(c.agx(), Vec3::<f32>::new(0.610114, 0.431149, 0.291676), 1e-4));
+    }
+
+    #[test]
+    fn test_color_grading() {
+        let c = Vec3::<f32>::new(0.5, 0.25, 0.125);
+
+        assert_eq!(Vec3::<f32>::one().luminance(), 1.0);
+        assert_eq!(c.exposed(1.0), c * 2.0);
+        assert_eq!(c.exposed(-2.0), c * 0.25);
+        assert!((c.with_luminance(0.5).luminance() - 0.5).abs() < 1e-6);
+
+        let warm = Vec3::<f32>::from_temperature(2000.0);
+        let cool = Vec3::<f32>::from_temperature(12000.0);
+        let d65 = Vec3::<f32>::from_temperature(6504.0);
+        assert!(warm[0] > warm[2] && cool[2] > cool[0]);
+        assert!(close(d65, Vec3::<f32>::one(), 0.1));
+
+        let balanced = warm.white_balanced(2000.0);
+        assert!(close(balanced / balanced[0], d65 / d65[0], 1e-4));
+        assert!((balanced.luminance() - warm.luminance()).abs() < 1e-5);
+        let candle = Vec3::<f32>::new(0.5, 0.4, 0.3);
+        assert!(candle.white_balanced(1700.0).0.iter().all(|c| c.is_finite()));
+        assert!(candle.white_balanced(1900.0).0.iter().all(|c| c.is_finite()));
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::excessive_precision)]
+
+use crate::*;
+
+macro_rules! impl_tonemap {
+    ($t:ident) => {
+        impl Vec3<$t> {
+            pub fn reinhard(&self) -> Self {
+                Self(self.0.map(|c| c / ($t::one() + c)))
+            }
+
+            pub fn reinhard_extended(&self, white: $t) -> Self {
+                let white_sq = white * white;
+                Self(self.0.map(|c| c * ($t::one() + c / white_sq) / ($t::one() + c)))
+            }
+
+            // stephen hill's fit of the aces rrt + odt, output in linear srgb
+            pub fn aces_fitted(&self) -> Self {
+                #[rustfmt::skip]
+                let input = Mat3::<$t>::new(
+                    0.59719, 0.07600, 0.02840,
+                    0.35458, 0.90834, 0.13383,
+                    0.04823, 0.01566, 0.83777,
+                );
+                #[rustfmt::skip]
+                let output = Mat3::<$t>::new(
+                    1.60475, -0.10208, -0.00327,
+                    -0.53108, 1.10813, -0.07276,
+                    -0.07367, -0.00605, 1.07602,
+                );
+
+                let v = input * *self;
+                let a = v * (v + Self::one() * 0.0245786) - Self::one() * 0.000090537;
+                let b = v * (v * 0.983729 + Self::one() * 0.4329510) + Self::one() * 0.238081;
+                let fitted = Self([a[0] / b[0], a[1] / b[1], a[2] / b[2]]);
+                Self((output * fitted).0.map(|c| c.clamp($t::zero(), $t::one())))
+            }
+
+            // minimal agx with the default 6th order contrast approximation, output in linear srgb
+            pub fn agx(&self) -> Self {
+                #[rustfmt::skip]
+                let inset = Mat3::<$t>::new(
+                    0.842479062253094, 0.0423282422610123, 0.0423756549057051,
+                    0.0784335999999992, 0.878468636469772, 0.0784336,
+                    0.0792237451477643, 0.0791661274605434, 0.879142973793104,
+                );
+                #[rustfmt::skip]
+                let outset = Mat3::<$t>::new(
+                    1.19687900512017, -0.0528968517574562, -0.0529716355144438,
+                    -0.0980208811401368, 1.15190312990417, -0.0980434501171241,
+                    -0.0990297440797205, -0.0989611768448433, 1.15107367264116,
+                );
+                let min_ev: $t = -12.47393;
+                let max_ev: $t = 4.026069;
+
+                let encoded = (inset * *self).0.map(|c| {
+                    let x = (Float::log2(c.max($t::MIN_POSITIVE)).clamp(min_ev, max_ev) - min_ev) / (max_ev - min_ev);
+                    let x2 = x * x;
+                    let x4 = x2 * x2;
+                    15.5 * x4 * x2 - 40.14 * x4 * x + 31.96 * x4 - 6.868 * x2 * x + 0.4298 * x2 + 0.1191 * x - 0.00232
+                });
+
+                Self(
+                    (outset * Self(encoded))
+                        .0
+                        .map(|c| Float::powf(c.clamp($t::zero(), $t::one()), 2.2)),
+                )
+            }
+
+            // john hable's uncharted 2 filmic curve with its exposure bias of 2, so the curve's white point of 11.2
+            // is reached at an input of 5.6
+            pub fn hable(&self) -> Self {
+                let curve = |x: $t| {
+                    let (a, b, c, d, e, f) = (0.15, 0.50, 0.10, 0.20, 0.02, 0.30);
+                    ((x * (a * x + c * b) + d * e) / (x * (a * x + b) + d * f)) - e / f
+                };
+                let white_scale = $t::one() / curve(11.2);
+                Self(self.0.map(|c| curve(c * 2.0) * white_scale))
+            }
+        }
+    };
+}
+
+impl_tonemap!(f32);
+impl_tonemap!(f64);
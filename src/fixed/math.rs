@@ -0,0 +1,245 @@
+use crate::*;
+
+// sqrt, trig, exp2 and log2 in pure integer arithmetic so results are identical on every target. the series are
+// evaluated in the type itself, 16.16 is good to a few 1e-4 and 32.32 to a few 1e-9
+macro_rules! impl_fixed_math {
+    ($t:ident, $raw:ident, $uwide:ident, $frac:expr) => {
+        impl $t {
+            // horner's rule with the highest order coefficient first
+            fn poly(self, coeffs: &[f64]) -> Self {
+                coeffs
+                    .iter()
+                    .fold(Self::ZERO, |acc, &c| acc * self + Self::from_f64(c))
+            }
+
+            // negative inputs give zero
+            pub fn sqrt(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::ZERO;
+                }
+                Self((((self.0 as $uwide) << $frac).isqrt()) as $raw)
+            }
+
+            pub fn sin_cos(self) -> (Self, Self) {
+                // reduce to [-pi/4, pi/4] and a quadrant
+                let k = (self * Self::from_f64(core::f64::consts::FRAC_2_PI)).round();
+                let r = self - k * Self::FRAC_PI_2;
+                let r2 = r * r;
+                let sin = r * r2.poly(&[
+                    1.0 / 6227020800.0,
+                    -1.0 / 39916800.0,
+                    1.0 / 362880.0,
+                    -1.0 / 5040.0,
+                    1.0 / 120.0,
+                    -1.0 / 6.0,
+                    1.0,
+                ]);
+                let cos = r2.poly(&[
+                    -1.0 / 87178291200.0,
+                    1.0 / 479001600.0,
+                    -1.0 / 3628800.0,
+                    1.0 / 40320.0,
+                    -1.0 / 720.0,
+                    1.0 / 24.0,
+                    -1.0 / 2.0,
+                    1.0,
+                ]);
+                match k.to_int() & 3 {
+                    0 => (sin, cos),
+                    1 => (cos, -sin),
+                    2 => (-sin, -cos),
+                    _ => (-cos, sin),
+                }
+            }
+
+            pub fn sin(self) -> Self {
+                self.sin_cos().0
+            }
+
+            pub fn cos(self) -> Self {
+                self.sin_cos().1
+            }
+
+            pub fn tan(self) -> Self {
+                let (sin, cos) = self.sin_cos();
+                sin / cos
+            }
+
+            // atan of z in [-tan(pi/8), tan(pi/8)]
+            fn atan_small(z: Self) -> Self {
+                z * (z * z).poly(&[
+                    -1.0 / 23.0,
+                    1.0 / 21.0,
+                    -1.0 / 19.0,
+                    1.0 / 17.0,
+                    -1.0 / 15.0,
+                    1.0 / 13.0,
+                    -1.0 / 11.0,
+                    1.0 / 9.0,
+                    -1.0 / 7.0,
+                    1.0 / 5.0,
+                    -1.0 / 3.0,
+                    1.0,
+                ])
+            }
+
+            pub fn atan2(self, x: Self) -> Self {
+                let y = self;
+                if x.0 == 0 && y.0 == 0 {
+                    return Self::ZERO;
+                }
+
+                // atan of t in [0, 1], shifted by pi/4 above tan(pi/8)
+                let (ax, ay) = (x.abs(), y.abs());
+                let t = if ay > ax { ax / ay } else { ay / ax };
+                let mut angle = if t > Self::from_f64(0.41421356237309503) {
+                    Self::FRAC_PI_4 + Self::atan_small((t - Self::ONE) / (t + Self::ONE))
+                } else {
+                    Self::atan_small(t)
+                };
+
+                if ay > ax {
+                    angle = Self::FRAC_PI_2 - angle;
+                }
+                if x.0 < 0 {
+                    angle = Self::PI - angle;
+                }
+                if y.0 < 0 {
+                    angle = -angle;
+                }
+                angle
+            }
+
+            pub fn asin(self) -> Self {
+                self.atan2(((Self::ONE - self) * (Self::ONE + self)).sqrt())
+            }
+
+            pub fn acos(self) -> Self {
+                ((Self::ONE - self) * (Self::ONE + self)).sqrt().atan2(self)
+            }
+
+            // saturates to MAX when the result does not fit
+            pub fn exp2(self) -> Self {
+                let n = self.to_int();
+                let u = (self - self.floor()) * Self::from_f64(core::f64::consts::LN_2);
+                let m = u.poly(&[
+                    1.0 / 479001600.0,
+                    1.0 / 39916800.0,
+                    1.0 / 3628800.0,
+                    1.0 / 362880.0,
+                    1.0 / 40320.0,
+                    1.0 / 5040.0,
+                    1.0 / 720.0,
+                    1.0 / 120.0,
+                    1.0 / 24.0,
+                    1.0 / 6.0,
+                    1.0 / 2.0,
+                    1.0,
+                    1.0,
+                ]);
+                if n >= 0 {
+                    m.0.checked_shl(n as u32)
+                        .filter(|&bits| bits >> n == m.0)
+                        .map_or(Self::MAX, Self)
+                } else {
+                    Self(m.0.checked_shr(-n as u32).unwrap_or(0))
+                }
+            }
+
+            // non positive inputs give MIN
+            pub fn log2(self) -> Self {
+                if self.0 <= 0 {
+                    return Self::MIN;
+                }
+
+                // split into m * 2^e with m in [1, 2)
+                let e = ($raw::BITS - 1 - self.0.leading_zeros()) as $raw - $frac;
+                let m = Self(if e >= 0 { self.0 >> e } else { self.0 << -e });
+                let z = (m - Self::ONE) / (m + Self::ONE);
+                let ln = z
+                    * (z * z).poly(&[
+                        2.0 / 21.0,
+                        2.0 / 19.0,
+                        2.0 / 17.0,
+                        2.0 / 15.0,
+                        2.0 / 13.0,
+                        2.0 / 11.0,
+                        2.0 / 9.0,
+                        2.0 / 7.0,
+                        2.0 / 5.0,
+                        2.0 / 3.0,
+                        2.0,
+                    ]);
+                Self::from_int(e) + ln * Self::from_f64(core::f64::consts::LOG2_E)
+            }
+
+            // non positive bases give zero
+            pub fn powf(self, n: Self) -> Self {
+                if self.0 <= 0 {
+                    return Self::ZERO;
+                }
+                (n * self.log2()).exp2()
+            }
+
+            pub fn cbrt(self) -> Self {
+                if self.0 == 0 {
+                    return Self::ZERO;
+                }
+                let r = (self.abs().log2() / Self::from_int(3)).exp2();
+                if self.0 < 0 {
+                    -r
+                } else {
+                    r
+                }
+            }
+        }
+
+        impl Float for $t {
+            fn sqrt(self) -> Self {
+                self.sqrt()
+            }
+            fn sin(self) -> Self {
+                self.sin()
+            }
+            fn cos(self) -> Self {
+                self.cos()
+            }
+            fn tan(self) -> Self {
+                self.tan()
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                self.sin_cos()
+            }
+            fn asin(self) -> Self {
+                self.asin()
+            }
+            fn acos(self) -> Self {
+                self.acos()
+            }
+            fn atan2(self, other: Self) -> Self {
+                self.atan2(other)
+            }
+            fn powf(self, n: Self) -> Self {
+                self.powf(n)
+            }
+            fn exp2(self) -> Self {
+                self.exp2()
+            }
+            fn log2(self) -> Self {
+                self.log2()
+            }
+            fn cbrt(self) -> Self {
+                self.cbrt()
+            }
+            fn floor(self) -> Self {
+                self.floor()
+            }
+            fn round(self) -> Self {
+                self.round()
+            }
+        }
+    };
+}
+
+impl_fixed_math!(I16F16, i32, u64, 16);
+impl_fixed_math!(I32F32, i64, u128, 32);
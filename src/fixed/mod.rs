@@ -0,0 +1,137 @@
+mod math;
+mod scalar;
+
+// signed fixed point scalars, 16.16 in an i32 and 32.32 in an i64. arithmetic saturates at MIN and MAX instead of
+// wrapping, and dividing by zero gives MAX or MIN by the sign of the dividend, or zero for zero over zero
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct I16F16(pub i32);
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for I16F16 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for I16F16 {}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct I32F32(pub i64);
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for I32F32 {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for I32F32 {}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_fixed_scalar() {
+        let a = I16F16::from_f32(1.5);
+        let b = I16F16::from_int(-2);
+
+        assert_eq!(a.to_bits(), 0x18000);
+        assert_eq!((a + b).to_f32(), -0.5);
+        assert_eq!((a - b).to_f32(), 3.5);
+        assert_eq!((a * b).to_f32(), -3.0);
+        assert_eq!((b / a).to_f64(), -87381.0 / 65536.0);
+        assert_eq!(-a, I16F16::from_f64(-1.5));
+        assert_eq!(I16F16::from_f64(-1.25).floor(), I16F16::from_int(-2));
+        assert_eq!(I16F16::from_f64(-1.25).round(), I16F16::from_int(-1));
+        assert_eq!(I32F32::from_f64(0.1).to_bits(), 429496730);
+        assert_eq!(format!("{:.3}", I32F32::from_f64(-2.5)), "-2.500");
+        assert!(I16F16::from_int(3) > I16F16::ONE);
+
+        // saturating, where floats would overflow to infinity or give nan
+        assert_eq!(I16F16::MAX + I16F16::ONE, I16F16::MAX);
+        assert_eq!(I16F16::MIN - I16F16::ONE, I16F16::MIN);
+        assert_eq!(-I16F16::MIN, I16F16::MAX);
+        assert_eq!(I16F16::from_int(300) * I16F16::from_int(-300), I16F16::MIN);
+        assert_eq!(I16F16::from_int(40000), I16F16::MAX);
+        assert_eq!(a / I16F16::ZERO, I16F16::MAX);
+        assert_eq!(b / I16F16::ZERO, I16F16::MIN);
+        assert_eq!(I16F16::ZERO / I16F16::ZERO, I16F16::ZERO);
+        assert_eq!(I16F16::MAX / I16F16::EPSILON, I16F16::MAX);
+    }
+
+    #[test]
+    fn test_fixed_math() {
+        // every function is checked against f64 over a grid, relative to the result once it grows past one
+        fn check(f: impl Fn(f64) -> (f64, f64), range: (f64, f64), tol: f64) {
+            for i in 0..=200 {
+                let x = range.0 + (range.1 - range.0) * i as f64 / 200.0;
+                let (got, want) = f(x);
+                assert!(
+                    (got - want).abs() <= tol * want.abs().max(1.0),
+                    "x = {}, got {}, want {}",
+                    x,
+                    got,
+                    want
+                );
+            }
+        }
+
+        let q = I32F32::from_f64;
+        check(|x| (q(x).sqrt().to_f64(), x.sqrt()), (0.0, 1000.0), 1e-8);
+        check(|x| (q(x).sin().to_f64(), x.sin()), (-20.0, 20.0), 1e-8);
+        check(|x| (q(x).cos().to_f64(), x.cos()), (-20.0, 20.0), 1e-8);
+        check(|x| (q(x).atan2(q(0.7)).to_f64(), x.atan2(0.7)), (-5.0, 5.0), 1e-8);
+        check(|x| (q(0.3).atan2(q(x)).to_f64(), 0.3f64.atan2(x)), (-5.0, 5.0), 1e-8);
+        check(|x| (q(x).acos().to_f64(), x.acos()), (-1.0, 1.0), 1e-4);
+        check(|x| (q(x).asin().to_f64(), x.asin()), (-0.99, 0.99), 1e-7);
+        check(|x| (q(x).exp2().to_f64(), x.exp2()), (-10.0, 10.0), 1e-7);
+        check(|x| (q(x).log2().to_f64(), x.log2()), (0.01, 1000.0), 1e-8);
+        check(|x| (q(x).cbrt().to_f64(), x.cbrt()), (-8.0, 8.0), 1e-7);
+
+        let q = I16F16::from_f64;
+        check(|x| (q(x).sqrt().to_f64(), x.sqrt()), (0.0, 1000.0), 1e-4);
+        check(|x| (q(x).sin_cos().0.to_f64(), x.sin()), (-20.0, 20.0), 1e-3);
+        check(|x| (q(x).sin_cos().1.to_f64(), x.cos()), (-20.0, 20.0), 1e-3);
+        check(|x| (q(x).atan2(q(-0.4)).to_f64(), x.atan2(-0.4)), (-5.0, 5.0), 1e-4);
+        check(|x| (q(x).tan().to_f64(), x.tan()), (-1.2, 1.2), 1e-3);
+        check(|x| (q(x).powf(q(1.5)).to_f64(), x.powf(1.5)), (0.0, 20.0), 1e-2);
+    }
+
+    #[test]
+    fn test_fixed_types() {
+        type F = I32F32;
+        let f = F::from_f64;
+
+        let v = Vec3::<F>::new(f(3.0), f(0.0), f(4.0));
+        assert_eq!(v.length(), f(5.0));
+        assert!((v.normalized() - Vec3::<F>::new(f(0.6), f(0.0), f(0.8))).length() < f(1e-9));
+        assert_eq!(v.cross(Vec3::<F>::unit_y()), Vec3::<F>::new(f(-4.0), f(0.0), f(3.0)));
+        assert_eq!(
+            Vec2::<I16F16>::new(I16F16::ONE, I16F16::ZERO).dot(Vec2::<I16F16>::one()),
+            I16F16::ONE
+        );
+
+        let q = Quat::<F>::from_rotation_axis(Rad(F::FRAC_PI_2), Vec3::<F>::unit_z());
+        let r = q * Vec3::<F>::unit_x();
+        assert!((r - Vec3::<F>::unit_y()).length() < f(1e-8));
+
+        // same convention as the float instantiation
+        let m = Mat3::<F>::from_rotation_z(Rad(f(0.7))) * Vec3::<F>::new(f(1.0), f(2.0), f(3.0));
+        let expected = Mat3::<f64>::from_rotation_z(Rad(0.7)) * Vec3::<f64>::new(1.0, 2.0, 3.0);
+        for i in 0..3 {
+            assert!((m[i].to_f64() - expected[i]).abs() < 1e-8);
+        }
+
+        let m = Mat4::<F>::from_translation(Vec3::<F>::new(f(1.0), f(2.0), f(3.0)));
+        let p = m * Vec4::<F>::new(f(1.0), f(1.0), f(1.0), f(1.0));
+        assert_eq!(p, Vec4::<F>::new(f(2.0), f(3.0), f(4.0), f(1.0)));
+        assert_eq!(m * m.inversed().unwrap(), Mat4::<F>::identity());
+
+        // 200² doesn't fit in 16.16, so the length saturates instead of wrapping round to zero
+        let long = Vec3::<I16F16>::new(I16F16::from_int(200), I16F16::ZERO, I16F16::ZERO);
+        assert_eq!(long.length(), I16F16::MAX.sqrt());
+
+        // the identity has no axis, which comes out as zero rather than the floats' nan
+        let (angle, axis) = Quat::<I16F16>::identity().get_rotation_axis();
+        assert_eq!((angle, axis), (I16F16::ZERO, Vec3::<I16F16>::zero()));
+        assert_eq!(Quat::<F>::identity().get_rotation_axis().1, Vec3::<F>::zero());
+    }
+}
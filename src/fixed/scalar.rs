@@ -0,0 +1,217 @@
+use crate::*;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+macro_rules! impl_fixed {
+    ($t:ident, $raw:ident, $wide:ident, $frac:expr) => {
+        impl $t {
+            pub const FRAC_BITS: u32 = $frac;
+            pub const ZERO: Self = Self(0);
+            pub const ONE: Self = Self(1 << $frac);
+            pub const EPSILON: Self = Self(1);
+            pub const MIN: Self = Self($raw::MIN);
+            pub const MAX: Self = Self($raw::MAX);
+            pub const PI: Self = Self::from_f64(core::f64::consts::PI);
+            pub const TAU: Self = Self::from_f64(core::f64::consts::TAU);
+            pub const FRAC_PI_2: Self = Self::from_f64(core::f64::consts::FRAC_PI_2);
+            pub const FRAC_PI_4: Self = Self::from_f64(core::f64::consts::FRAC_PI_4);
+
+            pub const fn from_bits(bits: $raw) -> Self {
+                Self(bits)
+            }
+
+            pub const fn to_bits(self) -> $raw {
+                self.0
+            }
+
+            // saturates out of range values
+            pub const fn from_int(n: $raw) -> Self {
+                if n > $raw::MAX >> $frac {
+                    Self::MAX
+                } else if n < $raw::MIN >> $frac {
+                    Self::MIN
+                } else {
+                    Self(n << $frac)
+                }
+            }
+
+            // rounds to nearest, ties away from zero, saturates out of range values
+            pub const fn from_f64(x: f64) -> Self {
+                let scaled = x * (1u64 << $frac) as f64;
+                Self(if scaled < 0.0 {
+                    (scaled - 0.5) as $raw
+                } else {
+                    (scaled + 0.5) as $raw
+                })
+            }
+
+            pub const fn from_f32(x: f32) -> Self {
+                Self::from_f64(x as f64)
+            }
+
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / (1u64 << $frac) as f64
+            }
+
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            // rounds toward negative infinity
+            pub fn to_int(self) -> $raw {
+                self.0 >> $frac
+            }
+
+            pub fn abs(self) -> Self {
+                Self(self.0.saturating_abs())
+            }
+
+            pub fn signum(self) -> Self {
+                Self::from_int(self.0.signum())
+            }
+
+            pub fn floor(self) -> Self {
+                Self(self.0 & !((1 << $frac) - 1))
+            }
+
+            fn saturate(wide: $wide) -> Self {
+                Self(wide.clamp($raw::MIN as $wide, $raw::MAX as $wide) as $raw)
+            }
+
+            pub fn round(self) -> Self {
+                let half = Self(1 << ($frac - 1));
+                if self.0 < 0 {
+                    -(-self + half).floor()
+                } else {
+                    (self + half).floor()
+                }
+            }
+        }
+
+        impl Num<f64> for $t {
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn splat(num: f64) -> Self {
+                Self::from_f64(num)
+            }
+        }
+
+        // display
+        // ---------------------------------------------------------------------------------------------------
+        impl core::fmt::Display for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.to_f64(), f)
+            }
+        }
+
+        impl core::fmt::Debug for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.to_f64(), f)
+            }
+        }
+
+        // from
+        // ---------------------------------------------------------------------------------------------------
+        impl From<f32> for $t {
+            fn from(x: f32) -> Self {
+                Self::from_f32(x)
+            }
+        }
+
+        impl From<f64> for $t {
+            fn from(x: f64) -> Self {
+                Self::from_f64(x)
+            }
+        }
+
+        impl From<$t> for f32 {
+            fn from(x: $t) -> Self {
+                x.to_f32()
+            }
+        }
+
+        impl From<$t> for f64 {
+            fn from(x: $t) -> Self {
+                x.to_f64()
+            }
+        }
+
+        // arithmetic
+        // ---------------------------------------------------------------------------------------------------
+        impl Add for $t {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        // products are rounded to nearest, quotients truncate toward zero
+        impl Mul for $t {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                let product = self.0 as $wide * rhs.0 as $wide;
+                Self::saturate((product + (1 << ($frac - 1))) >> $frac)
+            }
+        }
+
+        impl MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl Div for $t {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self {
+                if rhs.0 == 0 {
+                    return match self.0 {
+                        0 => Self::ZERO,
+                        x if x > 0 => Self::MAX,
+                        _ => Self::MIN,
+                    };
+                }
+                Self::saturate(((self.0 as $wide) << $frac) / rhs.0 as $wide)
+            }
+        }
+
+        impl DivAssign for $t {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+    };
+}
+
+impl_fixed!(I16F16, i32, i64, 16);
+impl_fixed!(I32F32, i64, i128, 32);
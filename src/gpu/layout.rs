@@ -0,0 +1,220 @@
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutRules {
+    Std140,
+    Std430,
+}
+
+const fn round_up(n: usize, align: usize) -> usize {
+    n.div_ceil(align) * align
+}
+
+// base alignment and size of the shader side type, following the rules in the glsl spec section 7.6.2.2
+pub trait GpuLayout {
+    fn align(rules: LayoutRules) -> usize;
+    fn size(rules: LayoutRules) -> usize;
+
+    // std140 rounds the stride of array elements up to a vec4
+    fn array_stride(rules: LayoutRules) -> usize {
+        let stride = round_up(Self::size(rules), Self::align(rules));
+        match rules {
+            LayoutRules::Std140 => round_up(stride, 16),
+            LayoutRules::Std430 => stride,
+        }
+    }
+}
+
+// walks the members of a block or struct in declaration order, returning the offset of each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructLayout {
+    rules: LayoutRules,
+    offset: usize,
+    align: usize,
+}
+
+impl StructLayout {
+    pub fn new(rules: LayoutRules) -> Self {
+        Self {
+            rules,
+            offset: 0,
+            align: 1,
+        }
+    }
+
+    pub fn field<T: GpuLayout>(&mut self) -> usize {
+        self.field_sized(T::align(self.rules), T::size(self.rules))
+    }
+
+    // a member with an explicit alignment and size, such as a nested struct taken from another `StructLayout`
+    pub fn field_sized(&mut self, align: usize, size: usize) -> usize {
+        let offset = round_up(self.offset, align);
+        self.offset = offset + size;
+        self.align = self.align.max(align);
+        offset
+    }
+
+    // std140 rounds the alignment of structs up to a vec4
+    pub fn align(&self) -> usize {
+        match self.rules {
+            LayoutRules::Std140 => round_up(self.align, 16),
+            LayoutRules::Std430 => self.align,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        round_up(self.offset, self.align())
+    }
+}
+
+// scalars and vectors
+// -----------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_gpu_layout {
+    ($($t:ident),*) => {
+        $(
+            impl GpuLayout for $t {
+                fn align(_: LayoutRules) -> usize {
+                    4
+                }
+
+                fn size(_: LayoutRules) -> usize {
+                    4
+                }
+            }
+
+            impl GpuLayout for Vec2<$t> {
+                fn align(_: LayoutRules) -> usize {
+                    8
+                }
+
+                fn size(_: LayoutRules) -> usize {
+                    8
+                }
+            }
+
+            impl GpuLayout for Vec3<$t> {
+                fn align(_: LayoutRules) -> usize {
+                    16
+                }
+
+                fn size(_: LayoutRules) -> usize {
+                    12
+                }
+            }
+
+            impl GpuLayout for Vec4<$t> {
+                fn align(_: LayoutRules) -> usize {
+                    16
+                }
+
+                fn size(_: LayoutRules) -> usize {
+                    16
+                }
+            }
+        )*
+    };
+}
+
+impl_gpu_layout!(f32, i32, u32);
+
+// matrices and arrays
+// -----------------------------------------------------------------------------------------------------------
+
+// column major matrices are laid out as an array of their columns
+impl GpuLayout for Mat2<f32> {
+    fn align(rules: LayoutRules) -> usize {
+        <[Vec2<f32>; 2]>::align(rules)
+    }
+
+    fn size(rules: LayoutRules) -> usize {
+        <[Vec2<f32>; 2]>::size(rules)
+    }
+}
+
+impl GpuLayout for Mat3<f32> {
+    fn align(rules: LayoutRules) -> usize {
+        <[Vec3<f32>; 3]>::align(rules)
+    }
+
+    fn size(rules: LayoutRules) -> usize {
+        <[Vec3<f32>; 3]>::size(rules)
+    }
+}
+
+impl GpuLayout for Mat4<f32> {
+    fn align(rules: LayoutRules) -> usize {
+        <[Vec4<f32>; 4]>::align(rules)
+    }
+
+    fn size(rules: LayoutRules) -> usize {
+        <[Vec4<f32>; 4]>::size(rules)
+    }
+}
+
+impl<T: GpuLayout, const N: usize> GpuLayout for [T; N] {
+    fn align(rules: LayoutRules) -> usize {
+        match rules {
+            LayoutRules::Std140 => round_up(T::align(rules), 16),
+            LayoutRules::Std430 => T::align(rules),
+        }
+    }
+
+    fn size(rules: LayoutRules) -> usize {
+        T::array_stride(rules) * N
+    }
+}
+
+// padded types
+// -----------------------------------------------------------------------------------------------------------
+
+// the padded types report the layout of the shader type they stand in for
+macro_rules! impl_gpu_layout_padded {
+    ($($t:ident => $shader:ty),*) => {
+        $(
+            impl GpuLayout for $t {
+                fn align(rules: LayoutRules) -> usize {
+                    <$shader>::align(rules)
+                }
+
+                fn size(rules: LayoutRules) -> usize {
+                    <$shader>::size(rules)
+                }
+            }
+        )*
+    };
+}
+
+impl_gpu_layout_padded!(
+    Std140Vec2 => Vec2<f32>,
+    Std140Vec3 => Vec3<f32>,
+    Std140Vec4 => Vec4<f32>,
+    Std140Mat2 => Mat2<f32>,
+    Std140Mat3 => Mat3<f32>,
+    Std140Mat4 => Mat4<f32>,
+    Std430Vec2 => Vec2<f32>,
+    Std430Vec3 => Vec3<f32>,
+    Std430Vec4 => Vec4<f32>,
+    Std430Mat2 => Mat2<f32>,
+    Std430Mat3 => Mat3<f32>,
+    Std430Mat4 => Mat4<f32>
+);
+
+// checks every listed field of a `#[repr(C)]` struct against the offset its shader type gets under the rules, and
+// the size of the whole struct, panicking on the first mismatch
+#[macro_export]
+macro_rules! assert_gpu_layout {
+    ($s:ty, $rules:expr, { $($field:ident: $shader:ty),* $(,)? }) => {{
+        let mut layout = $crate::StructLayout::new($rules);
+        $(
+            assert_eq!(
+                core::mem::offset_of!($s, $field),
+                layout.field::<$shader>(),
+                "offset of `{}::{}`",
+                stringify!($s),
+                stringify!($field)
+            );
+        )*
+        assert_eq!(core::mem::size_of::<$s>(), layout.size(), "size of `{}`", stringify!($s));
+    }};
+}
@@ -0,0 +1,179 @@
+mod layout;
+mod padded;
+pub use layout::*;
+
+use crate::{Vec2, Vec3, Vec4};
+
+// f32 vectors and matrices padded and aligned for glsl std140 / std430 blocks. matrix columns are stored as
+// `[f32; 4]` where the rules pad them. wgsl uniform buffers don't pad as much as std140 does: a mat2x2<f32> there is
+// 16 bytes with 8 byte columns, not the 32 that `Std140Mat2` takes
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(8))]
+pub struct Std140Vec2(pub Vec2<f32>);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Vec3(pub Vec3<f32>, f32);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Vec4(pub Vec4<f32>);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Mat2(pub [[f32; 4]; 2]);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Mat3(pub [[f32; 4]; 3]);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std140Mat4(pub [[f32; 4]; 4]);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(8))]
+pub struct Std430Vec2(pub Vec2<f32>);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std430Vec3(pub Vec3<f32>, f32);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std430Vec4(pub Vec4<f32>);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(8))]
+pub struct Std430Mat2(pub [[f32; 2]; 2]);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std430Mat3(pub [[f32; 4]; 3]);
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C, align(16))]
+pub struct Std430Mat4(pub [[f32; 4]; 4]);
+
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_pod {
+    ($($t:ident),*) => {
+        $(
+            unsafe impl bytemuck::Zeroable for $t {}
+            unsafe impl bytemuck::Pod for $t {}
+        )*
+    };
+}
+
+#[cfg(feature = "bytemuck")]
+impl_pod!(Std140Vec2, Std140Vec3, Std140Vec4, Std140Mat2, Std140Mat3, Std140Mat4);
+#[cfg(feature = "bytemuck")]
+impl_pod!(Std430Vec2, Std430Vec3, Std430Vec4, Std430Mat2, Std430Mat3, Std430Mat4);
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_gpu_layout_rules() {
+        // the example block from the glsl spec, section 7.6.2.2
+        //
+        // layout(std140) uniform Example {
+        //     float a; vec2 b; vec3 c;
+        //     struct { int d; bvec2 e; } f;
+        //     float g; float h[2]; mat2x3 i;
+        //     struct { uvec3 j; vec2 k; float l[2]; vec2 m; mat3 n[2]; } o[2];
+        // };
+        let mut f = StructLayout::new(LayoutRules::Std140);
+        assert_eq!(f.field::<i32>(), 0);
+        assert_eq!(f.field::<Vec2<u32>>(), 8);
+        assert_eq!((f.align(), f.size()), (16, 16));
+
+        let mut o = StructLayout::new(LayoutRules::Std140);
+        assert_eq!(o.field::<Vec3<u32>>(), 0);
+        assert_eq!(o.field::<Vec2<f32>>(), 16);
+        assert_eq!(o.field::<[f32; 2]>(), 32);
+        assert_eq!(o.field::<Vec2<f32>>(), 64);
+        assert_eq!(o.field::<[Mat3<f32>; 2]>(), 80);
+        assert_eq!(o.size(), 176);
+
+        let mut example = StructLayout::new(LayoutRules::Std140);
+        assert_eq!(example.field::<f32>(), 0);
+        assert_eq!(example.field::<Vec2<f32>>(), 8);
+        assert_eq!(example.field::<Vec3<f32>>(), 16);
+        assert_eq!(example.field_sized(f.align(), f.size()), 32);
+        assert_eq!(example.field::<f32>(), 48);
+        assert_eq!(example.field::<[f32; 2]>(), 64);
+        assert_eq!(example.field_sized(16, 32), 96); // mat2x3, two padded vec3 columns
+        assert_eq!(example.field_sized(o.align(), o.size() * 2), 128);
+        assert_eq!(example.size(), 480);
+
+        // std430 drops the rounding of arrays and structs up to vec4
+        let mut example = StructLayout::new(LayoutRules::Std430);
+        assert_eq!(example.field::<f32>(), 0);
+        assert_eq!(example.field::<[f32; 3]>(), 4);
+        assert_eq!(example.field::<Vec2<f32>>(), 16);
+        assert_eq!(example.field::<Mat2<f32>>(), 24);
+        assert_eq!(example.field::<Vec3<f32>>(), 48);
+        assert_eq!(example.field::<f32>(), 60);
+        assert_eq!(example.field::<[Vec3<f32>; 2]>(), 64);
+        assert_eq!(example.size(), 96);
+
+        assert_eq!(<Mat2<f32>>::size(LayoutRules::Std140), 32);
+        assert_eq!(<Mat2<f32>>::size(LayoutRules::Std430), 16);
+        assert_eq!(<Mat3<f32>>::size(LayoutRules::Std430), 48);
+        assert_eq!(<[f32; 4]>::array_stride(LayoutRules::Std140), 64);
+    }
+
+    #[test]
+    fn test_gpu_padded_types() {
+        #[repr(C)]
+        struct Uniforms {
+            model: Std140Mat4,
+            normal: Std140Mat3,
+            tint: Std140Vec3,
+            light_dir: Vec3<f32>,
+            intensity: f32,
+            uv_scale: Std140Vec2,
+            rotation: Std140Mat2,
+        }
+
+        assert_gpu_layout!(Uniforms, LayoutRules::Std140, {
+            model: Mat4<f32>,
+            normal: Mat3<f32>,
+            tint: Vec3<f32>,
+            light_dir: Vec3<f32>,
+            intensity: f32,
+            uv_scale: Vec2<f32>,
+            rotation: Mat2<f32>,
+        });
+
+        #[repr(C)]
+        struct Particle {
+            position: Std430Vec3,
+            velocity: Vec3<f32>,
+            age: f32,
+            basis: Std430Mat2,
+        }
+
+        assert_gpu_layout!(Particle, LayoutRules::Std430, {
+            position: Vec3<f32>,
+            velocity: Vec3<f32>,
+            age: f32,
+            basis: Mat2<f32>,
+        });
+
+        let m = Mat3::<f32>([
+            Vec3::<f32>::new(1.0, 2.0, 3.0),
+            Vec3::<f32>::new(4.0, 5.0, 6.0),
+            Vec3::<f32>::new(7.0, 8.0, 9.0),
+        ]);
+        let padded = Std140Mat3::from(m);
+        assert_eq!(padded.0[1], [4.0, 5.0, 6.0, 0.0]);
+        assert_eq!(Mat3::<f32>::from(padded), m);
+        assert_eq!(
+            Mat2::<f32>::from(Std430Mat2::from(Mat2::<f32>::identity())),
+            Mat2::<f32>::identity()
+        );
+        assert_eq!(
+            Vec3::<f32>::from(Std430Vec3::from(Vec3::<f32>::unit_y())),
+            Vec3::<f32>::unit_y()
+        );
+        assert_eq!(core::mem::size_of::<Std140Mat2>(), 32);
+        assert_eq!(core::mem::size_of::<Std430Mat2>(), 16);
+        assert_eq!(core::mem::size_of::<Std140Vec3>(), 16);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::*;
+
+// vectors
+// -----------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_padded_vec {
+    ($vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl From<Vec2<f32>> for $vec2 {
+            fn from(v: Vec2<f32>) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$vec2> for Vec2<f32> {
+            fn from(v: $vec2) -> Self {
+                v.0
+            }
+        }
+
+        impl From<Vec3<f32>> for $vec3 {
+            fn from(v: Vec3<f32>) -> Self {
+                Self(v, 0.0)
+            }
+        }
+
+        impl From<$vec3> for Vec3<f32> {
+            fn from(v: $vec3) -> Self {
+                v.0
+            }
+        }
+
+        impl From<Vec4<f32>> for $vec4 {
+            fn from(v: Vec4<f32>) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$vec4> for Vec4<f32> {
+            fn from(v: $vec4) -> Self {
+                v.0
+            }
+        }
+    };
+}
+
+impl_padded_vec!(Std140Vec2, Std140Vec3, Std140Vec4);
+impl_padded_vec!(Std430Vec2, Std430Vec3, Std430Vec4);
+
+// matrices
+// -----------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_padded_mat {
+    ($mat3:ident, $mat4:ident) => {
+        impl From<Mat3<f32>> for $mat3 {
+            fn from(m: Mat3<f32>) -> Self {
+                Self(m.0.map(|c| [c[0], c[1], c[2], 0.0]))
+            }
+        }
+
+        impl From<$mat3> for Mat3<f32> {
+            fn from(m: $mat3) -> Self {
+                Self(m.0.map(|c| Vec3::<f32>::new(c[0], c[1], c[2])))
+            }
+        }
+
+        impl From<Mat4<f32>> for $mat4 {
+            fn from(m: Mat4<f32>) -> Self {
+                Self(m.0.map(|c| c.0))
+            }
+        }
+
+        impl From<$mat4> for Mat4<f32> {
+            fn from(m: $mat4) -> Self {
+                Self(m.0.map(Vec4))
+            }
+        }
+    };
+}
+
+impl_padded_mat!(Std140Mat3, Std140Mat4);
+impl_padded_mat!(Std430Mat3, Std430Mat4);
+
+// std140 pads the columns of a mat2 to a vec4, std430 keeps them tight
+impl From<Mat2<f32>> for Std140Mat2 {
+    fn from(m: Mat2<f32>) -> Self {
+        Self(m.0.map(|c| [c[0], c[1], 0.0, 0.0]))
+    }
+}
+
+impl From<Std140Mat2> for Mat2<f32> {
+    fn from(m: Std140Mat2) -> Self {
+        Self(m.0.map(|c| Vec2::<f32>::new(c[0], c[1])))
+    }
+}
+
+impl From<Mat2<f32>> for Std430Mat2 {
+    fn from(m: Mat2<f32>) -> Self {
+        Self(m.0.map(|c| c.0))
+    }
+}
+
+impl From<Std430Mat2> for Mat2<f32> {
+    fn from(m: Std430Mat2) -> Self {
+        Self(m.0.map(Vec2))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::*;
+
+macro_rules! impl_ccd {
+    ($t:ident) => {
+        impl IkChain<'_, $t> {
+            // cyclic coordinate descent: each sweep turns every joint from the end effector back to the root so the
+            // end points at the target. constraints hold after every step, since children keep their local rotations
+            pub fn solve_ccd(&mut self, target: Vec3<$t>, settings: IkSettings<$t>) -> IkOutcome<$t> {
+                let mut iterations = 0;
+                while iterations < settings.max_iterations && self.error(target) > settings.tolerance {
+                    for i in (0..self.len() - 1).rev() {
+                        let pivot = self.positions[i];
+                        let aim = Self::arc(self.end() - pivot, target - pivot) * self.rotations[i];
+                        let rotation = self.constrained(i, aim.normalized());
+                        self.rotate_joint(i, rotation * self.rotations[i].conjugate());
+                    }
+                    iterations += 1;
+                }
+
+                let error = self.error(target);
+                IkOutcome {
+                    iterations,
+                    error,
+                    converged: error <= settings.tolerance,
+                }
+            }
+        }
+    };
+}
+
+impl_ccd!(f32);
+impl_ccd!(f64);
@@ -0,0 +1,129 @@
+use crate::*;
+
+macro_rules! impl_chain {
+    ($t:ident) => {
+        impl<'a> IkChain<'a, $t> {
+            pub fn new(positions: &'a mut [Vec3<$t>], rotations: &'a mut [Quat<$t>]) -> Self {
+                assert!(positions.len() >= 2, "an ik chain needs at least two joints");
+                assert_eq!(positions.len(), rotations.len(), "one rotation per joint");
+                Self {
+                    positions,
+                    rotations,
+                    constraints: &[],
+                    parent: Quat::<$t>::identity(),
+                }
+            }
+
+            pub fn with_constraints(mut self, constraints: &'a [JointConstraint<$t>]) -> Self {
+                assert_eq!(
+                    constraints.len(),
+                    self.positions.len(),
+                    "one constraint per joint"
+                );
+                self.constraints = constraints;
+                self
+            }
+
+            pub fn with_parent(mut self, parent: Quat<$t>) -> Self {
+                self.parent = parent;
+                self
+            }
+
+            pub fn len(&self) -> usize {
+                self.positions.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.positions.is_empty()
+            }
+
+            pub fn end(&self) -> Vec3<$t> {
+                self.positions[self.len() - 1]
+            }
+
+            pub fn error(&self, target: Vec3<$t>) -> $t {
+                (target - self.end()).length()
+            }
+
+            // the world orientation `rotation` would give joint `i` once its constraint is applied
+            pub(crate) fn constrained(&self, i: usize, rotation: Quat<$t>) -> Quat<$t> {
+                match self.constraints.get(i) {
+                    None | Some(JointConstraint::Free) => rotation,
+                    Some(c) => {
+                        let parent = if i == 0 {
+                            self.parent
+                        } else {
+                            self.rotations[i - 1]
+                        };
+                        (parent * c.apply(parent.conjugate() * rotation)).normalized()
+                    }
+                }
+            }
+
+            // turns joint `i` by the world space rotation `delta`, carrying everything past it along
+            pub(crate) fn rotate_joint(&mut self, i: usize, delta: Quat<$t>) {
+                let pivot = self.positions[i];
+                for j in i..self.len() {
+                    self.positions[j] = pivot + delta * (self.positions[j] - pivot);
+                    self.rotations[j] = (delta * self.rotations[j]).normalized();
+                }
+            }
+
+            // shortest rotation taking the direction of `from` to that of `to`, including opposite directions
+            pub(crate) fn arc(from: Vec3<$t>, to: Vec3<$t>) -> Quat<$t> {
+                let (from, to) = (from.normalized(), to.normalized());
+                if from == Vec3::<$t>::zero() || to == Vec3::<$t>::zero() {
+                    return Quat::<$t>::identity();
+                }
+                if from.dot(to) > $t::splat(-0.9999) {
+                    return Quat::<$t>::from_rotation_between(from, to);
+                }
+                let side = if from[0].abs() < $t::splat(0.9) {
+                    Vec3::<$t>::unit_x()
+                } else {
+                    Vec3::<$t>::unit_y()
+                };
+                let axis = from.cross(side).normalized();
+                // a half turn flips `from`, what is left is a short rotation
+                Quat::<$t>::from_rotation_between(-from, to)
+                    * Quat::<$t>::from_rotation_axis(Rad(core::$t::consts::PI), axis)
+            }
+        }
+
+        impl JointConstraint<$t> {
+            // clamps an orientation relative to the parent joint
+            pub fn apply(&self, local: Quat<$t>) -> Quat<$t> {
+                match *self {
+                    JointConstraint::Free => local,
+                    JointConstraint::Ball {
+                        axis,
+                        max_swing,
+                        min_twist,
+                        max_twist,
+                    } => local
+                        .clamp_swing(axis, max_swing)
+                        .clamp_twist(axis, min_twist, max_twist),
+                    JointConstraint::Hinge { axis, min, max } => local.clamp_hinge(axis, min, max),
+                }
+            }
+        }
+
+        impl IkSettings<$t> {
+            pub fn new(tolerance: $t, max_iterations: u32) -> Self {
+                Self {
+                    tolerance,
+                    max_iterations,
+                }
+            }
+        }
+
+        impl Default for IkSettings<$t> {
+            fn default() -> Self {
+                Self::new($t::splat(1e-3), 16)
+            }
+        }
+    };
+}
+
+impl_chain!(f32);
+impl_chain!(f64);
@@ -0,0 +1,58 @@
+use crate::*;
+
+macro_rules! impl_fabrik {
+    ($t:ident) => {
+        impl IkChain<'_, $t> {
+            // forward and backward reaching: drags the end effector onto the target and the rest of the chain after
+            // it, then drags the root back into place. orientations follow every move of a bone, and constraints
+            // are applied on the way back out from the root
+            pub fn solve_fabrik(&mut self, target: Vec3<$t>, settings: IkSettings<$t>) -> IkOutcome<$t> {
+                let n = self.len();
+                let root = self.positions[0];
+                let mut iterations = 0;
+                while iterations < settings.max_iterations && self.error(target) > settings.tolerance {
+                    let mut old_next = self.positions[n - 1];
+                    self.positions[n - 1] = target;
+                    for i in (0..n - 1).rev() {
+                        let (old, next) = (self.positions[i], self.positions[i + 1]);
+                        let bone = old_next - old;
+                        self.positions[i] = next + (old - next).normalized() * bone.length();
+                        self.turn_bone(i, Self::arc(bone, next - self.positions[i]));
+                        old_next = old;
+                    }
+
+                    let mut old = self.positions[0];
+                    self.positions[0] = root;
+                    for i in 0..n - 1 {
+                        let (pivot, next) = (self.positions[i], self.positions[i + 1]);
+                        let bone = next - old;
+                        let aim = Self::arc(bone, next - pivot) * self.rotations[i];
+                        let delta = self.constrained(i, aim.normalized()) * self.rotations[i].conjugate();
+                        self.turn_bone(i, delta);
+                        self.positions[i + 1] = pivot + delta * bone;
+                        old = next;
+                    }
+                    iterations += 1;
+                }
+
+                let error = self.error(target);
+                IkOutcome {
+                    iterations,
+                    error,
+                    converged: error <= settings.tolerance,
+                }
+            }
+
+            // the end effector has no bone of its own and turns with the last one
+            fn turn_bone(&mut self, i: usize, delta: Quat<$t>) {
+                self.rotations[i] = (delta * self.rotations[i]).normalized();
+                if i + 2 == self.len() {
+                    self.rotations[i + 1] = (delta * self.rotations[i + 1]).normalized();
+                }
+            }
+        }
+    };
+}
+
+impl_fabrik!(f32);
+impl_fabrik!(f64);
@@ -0,0 +1,160 @@
+mod ccd;
+mod chain;
+mod fabrik;
+mod two_bone;
+
+use crate::{Quat, Rad, Vec3};
+
+// a chain of joints from the root to the end effector, with world space positions and orientations. bone i runs
+// from joint i to joint i + 1 and moves rigidly with the orientation of joint i, the solvers keep bone lengths and
+// the end effector's orientation relative to its parent
+#[derive(Debug)]
+pub struct IkChain<'a, S> {
+    pub positions: &'a mut [Vec3<S>],
+    pub rotations: &'a mut [Quat<S>],
+    // one per joint, or empty for an unconstrained chain
+    pub constraints: &'a [JointConstraint<S>],
+    // orientation of whatever the root hangs off, the root's constraint is relative to it
+    pub parent: Quat<S>,
+}
+
+// limits on a joint's orientation relative to its parent, with identity as the rest pose
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JointConstraint<S> {
+    #[default]
+    Free,
+    // `axis` swings within a cone of `max_swing` and twists around itself within the range
+    Ball {
+        axis: Vec3<S>,
+        max_swing: Rad<S>,
+        min_twist: Rad<S>,
+        max_twist: Rad<S>,
+    },
+    // only turns around `axis`, within the range
+    Hinge {
+        axis: Vec3<S>,
+        min: Rad<S>,
+        max: Rad<S>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IkSettings<S> {
+    // distance from the end effector to the target that counts as reached
+    pub tolerance: S,
+    pub max_iterations: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IkOutcome<S> {
+    pub iterations: u32,
+    // distance left between the end effector and the target
+    pub error: S,
+    pub converged: bool,
+}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    // joints along x with unit bones and identity orientations, so every bone's local direction is x
+    fn straight(n: usize) -> ([Vec3<f64>; 8], [Quat<f64>; 8]) {
+        let positions = core::array::from_fn(|i| Vec3::<f64>::new(i.min(n - 1) as f64, 0.0, 0.0));
+        (positions, [Quat::<f64>::identity(); 8])
+    }
+
+    fn assert_rigid(positions: &[Vec3<f64>], rotations: &[Quat<f64>], local: Vec3<f64>) {
+        for i in 0..positions.len() - 1 {
+            let bone = positions[i + 1] - positions[i];
+            assert!((bone.length() - 1.0).abs() < 1e-9);
+            assert_abs_diff_eq!(rotations[i] * local, bone, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_ik_two_bone() {
+        let down = Vec3::<f64>::new(0.0, -1.0, 0.0);
+        let mut positions = [Vec3::<f64>::zero(), down, down * 2.0];
+        let mut rotations = [Quat::<f64>::identity(); 3];
+        let mut chain = IkChain::<f64>::new(&mut positions, &mut rotations);
+
+        // a knee pointing forward at the pole
+        let target = Vec3::<f64>::new(0.3, -1.4, 0.2);
+        let out = chain.solve_two_bone(target, Vec3::<f64>::new(0.0, -1.0, 2.0));
+        assert!(out.converged && out.error < 1e-12);
+        assert!(chain.positions[1][2] > 0.5);
+        assert_rigid(chain.positions, chain.rotations, down);
+
+        // the plane of the bend follows the pole, and the end effector turns with the lower bone
+        let out = chain.solve_two_bone(target, Vec3::<f64>::new(2.0, -1.0, 0.0));
+        assert!(out.converged && out.error < 1e-12);
+        assert!(chain.positions[1][0] > 0.5);
+        assert_rigid(chain.positions, chain.rotations, down);
+        assert_eq!(chain.rotations[2], chain.rotations[1]);
+
+        let out = chain.solve_two_bone(Vec3::<f64>::new(0.0, -5.0, 0.0), Vec3::<f64>::unit_z());
+        assert!(!out.converged);
+        assert!((out.error - 3.0).abs() < 1e-12);
+        assert_abs_diff_eq!(chain.positions[2], down * 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ik_ccd() {
+        let (mut positions, mut rotations) = straight(5);
+        let mut chain = IkChain::<f64>::new(&mut positions[..5], &mut rotations[..5]);
+        let settings = IkSettings::<f64>::new(1e-6, 100);
+
+        let out = chain.solve_ccd(Vec3::<f64>::new(1.5, 2.0, -1.0), settings);
+        assert!(out.converged && out.error <= 1e-6 && out.iterations > 1);
+        assert_rigid(chain.positions, chain.rotations, Vec3::<f64>::unit_x());
+
+        // out of reach, the chain straightens out towards the target until the iteration limit
+        let out = chain.solve_ccd(Vec3::<f64>::new(0.0, 10.0, 0.0), IkSettings::<f64>::new(1e-6, 7));
+        assert!(!out.converged && out.iterations == 7);
+        assert!(out.error >= 6.0 - 1e-9 && out.error < 6.5);
+
+        let out = chain.solve_ccd(chain.end(), settings);
+        assert_eq!(out.iterations, 0);
+    }
+
+    #[test]
+    fn test_ik_fabrik() {
+        let (mut positions, mut rotations) = straight(4);
+        let mut chain = IkChain::<f64>::new(&mut positions[..4], &mut rotations[..4]);
+        let out = chain.solve_fabrik(Vec3::<f64>::new(-0.5, 2.0, 1.0), IkSettings::<f64>::new(1e-6, 100));
+        assert!(out.converged && out.error <= 1e-6);
+        assert_rigid(chain.positions, chain.rotations, Vec3::<f64>::unit_x());
+
+        // hinges around z only bend one way, so the chain curls up within the xy plane
+        let hinge = JointConstraint::Hinge {
+            axis: Vec3::<f64>::unit_z(),
+            min: Deg(0.0).into(),
+            max: Deg(120.0).into(),
+        };
+        let constraints = [hinge, hinge, hinge, JointConstraint::Free];
+        let (mut positions, mut rotations) = straight(4);
+        let mut chain = IkChain::<f64>::new(&mut positions[..4], &mut rotations[..4]).with_constraints(&constraints);
+        let out = chain.solve_fabrik(Vec3::<f64>::new(0.5, 1.8, 0.0), IkSettings::<f64>::new(1e-4, 200));
+        assert!(out.converged);
+        assert_rigid(chain.positions, chain.rotations, Vec3::<f64>::unit_x());
+        for i in 0..3 {
+            let parent = if i == 0 { chain.parent } else { chain.rotations[i - 1] };
+            let (angle, axis) = (parent.conjugate() * chain.rotations[i]).get_rotation_axis();
+            assert!(angle <= 120f64.to_radians() + 1e-9);
+            assert!(angle < 1e-9 || axis.abs_diff_eq(&Vec3::<f64>::unit_z(), 1e-9));
+        }
+
+        // the same hinges can't reach below the x axis, ccd respects them just the same
+        let (mut positions, mut rotations) = straight(4);
+        let mut chain = IkChain::<f64>::new(&mut positions[..4], &mut rotations[..4]).with_constraints(&constraints);
+        let out = chain.solve_ccd(Vec3::<f64>::new(1.0, -1.0, 0.0), IkSettings::<f64>::new(1e-4, 50));
+        assert!(!out.converged);
+        assert!(chain.positions.iter().all(|p| p[1] >= -1e-9));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::*;
+
+macro_rules! impl_two_bone {
+    ($t:ident) => {
+        impl IkChain<'_, $t> {
+            // closed form for a three joint chain such as hip, knee and ankle: the law of cosines gives the bend
+            // at the root and the middle joint bends towards `pole`. targets out of reach leave the chain pointing at
+            // them, fully stretched or folded. constraints are not applied
+            pub fn solve_two_bone(&mut self, target: Vec3<$t>, pole: Vec3<$t>) -> IkOutcome<$t> {
+                assert_eq!(self.len(), 3, "two bone ik needs exactly three joints");
+                let [a, b, c] = [self.positions[0], self.positions[1], self.positions[2]];
+                let (upper, lower) = ((b - a).length(), (c - b).length());
+
+                let to_target = target - a;
+                let dir = if to_target.length() > $t::zero() {
+                    to_target.normalized()
+                } else {
+                    (c - a).normalized()
+                };
+                let dist = to_target.length().max((upper - lower).abs()).min(upper + lower);
+
+                // the bend direction is the pole, or failing that the current bend, projected off the reach line
+                let off_line = |v: Vec3<$t>| (v - dir * v.dot(dir)).normalized();
+                let mut bend = off_line(pole - a);
+                if bend == Vec3::<$t>::zero() {
+                    bend = off_line(b - a);
+                }
+                if bend == Vec3::<$t>::zero() {
+                    bend = off_line(Vec3::<$t>::unit_y());
+                }
+                if bend == Vec3::<$t>::zero() {
+                    bend = off_line(Vec3::<$t>::unit_x());
+                }
+
+                let cos = if dist > $t::zero() {
+                    ((upper * upper + dist * dist - lower * lower) / (upper * dist * $t::splat(2.0)))
+                        .max(-$t::one())
+                        .min($t::one())
+                } else {
+                    $t::zero()
+                };
+                let sin = Float::sqrt(($t::one() - cos * cos).max($t::zero()));
+                let new_b = a + (dir * cos + bend * sin) * upper;
+                let new_c = a + dir * dist;
+
+                // the root turns its bone into place, then around it so the plane of the two bones lines up
+                let mut root = Self::arc(b - a, new_b - a);
+                let (old_normal, new_normal) = ((b - a).cross(c - b), (new_b - a).cross(new_c - new_b));
+                if old_normal != Vec3::<$t>::zero() && new_normal != Vec3::<$t>::zero() {
+                    let (m, axis) = (root * old_normal, (new_b - a).normalized());
+                    let angle = Float::atan2(m.cross(new_normal).dot(axis), m.dot(new_normal));
+                    root = Quat::<$t>::from_rotation_axis(Rad(angle), axis) * root;
+                }
+                let mid = Self::arc(root * (c - b), new_c - new_b) * root;
+
+                self.rotations[0] = (root * self.rotations[0]).normalized();
+                self.rotations[1] = (mid * self.rotations[1]).normalized();
+                self.rotations[2] = (mid * self.rotations[2]).normalized();
+                self.positions[1] = new_b;
+                self.positions[2] = new_c;
+
+                let error = self.error(target);
+                IkOutcome {
+                    iterations: 1,
+                    error,
+                    converged: dist == to_target.length(),
+                }
+            }
+        }
+    };
+}
+
+impl_two_bone!(f32);
+impl_two_bone!(f64);
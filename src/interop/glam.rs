@@ -0,0 +1,94 @@
+use crate::*;
+
+// glam stores matrices by column like we do and quaternions as x, y, z, w, so everything maps across unchanged
+macro_rules! impl_glam {
+    ($t:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident) => {
+        impl From<glam::$vec2> for Vec2<$t> {
+            fn from(v: glam::$vec2) -> Self {
+                Self(v.to_array())
+            }
+        }
+
+        impl From<Vec2<$t>> for glam::$vec2 {
+            fn from(v: Vec2<$t>) -> Self {
+                Self::from_array(v.0)
+            }
+        }
+
+        impl From<glam::$vec3> for Vec3<$t> {
+            fn from(v: glam::$vec3) -> Self {
+                Self(v.to_array())
+            }
+        }
+
+        impl From<Vec3<$t>> for glam::$vec3 {
+            fn from(v: Vec3<$t>) -> Self {
+                Self::from_array(v.0)
+            }
+        }
+
+        impl From<glam::$vec4> for Vec4<$t> {
+            fn from(v: glam::$vec4) -> Self {
+                Self(v.to_array())
+            }
+        }
+
+        impl From<Vec4<$t>> for glam::$vec4 {
+            fn from(v: Vec4<$t>) -> Self {
+                Self::from_array(v.0)
+            }
+        }
+
+        impl From<glam::$mat2> for Mat2<$t> {
+            fn from(m: glam::$mat2) -> Self {
+                Self(m.to_cols_array_2d().map(Vec2))
+            }
+        }
+
+        impl From<Mat2<$t>> for glam::$mat2 {
+            fn from(m: Mat2<$t>) -> Self {
+                Self::from_cols_array_2d(&m.0.map(|c| c.0))
+            }
+        }
+
+        impl From<glam::$mat3> for Mat3<$t> {
+            fn from(m: glam::$mat3) -> Self {
+                Self(m.to_cols_array_2d().map(Vec3))
+            }
+        }
+
+        impl From<Mat3<$t>> for glam::$mat3 {
+            fn from(m: Mat3<$t>) -> Self {
+                Self::from_cols_array_2d(&m.0.map(|c| c.0))
+            }
+        }
+
+        impl From<glam::$mat4> for Mat4<$t> {
+            fn from(m: glam::$mat4) -> Self {
+                Self(m.to_cols_array_2d().map(Vec4))
+            }
+        }
+
+        impl From<Mat4<$t>> for glam::$mat4 {
+            fn from(m: Mat4<$t>) -> Self {
+                Self::from_cols_array_2d(&m.0.map(|c| c.0))
+            }
+        }
+
+        impl From<glam::$quat> for Quat<$t> {
+            fn from(q: glam::$quat) -> Self {
+                Self(q.to_array())
+            }
+        }
+
+        // glam expects unit quaternions but does not normalize on the way in, so neither do we
+        impl From<Quat<$t>> for glam::$quat {
+            fn from(q: Quat<$t>) -> Self {
+                Self::from_array(q.0)
+            }
+        }
+    };
+}
+
+impl_glam!(f32, Vec2, Vec3, Vec4, Mat2, Mat3, Mat4, Quat);
+impl_glam!(f64, DVec2, DVec3, DVec4, DMat2, DMat3, DMat4, DQuat);
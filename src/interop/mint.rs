@@ -0,0 +1,81 @@
+use crate::*;
+
+// mint is plain data, so these work for any scalar. its matrices come in column and row flavours, the column ones
+// match our layout and the row ones are transposed on the way through
+
+// vectors and quaternions
+// -----------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_mint_vec {
+    ($($v:ident => $m:ident),*) => {
+        $(
+            impl<S> From<mint::$m<S>> for $v<S> {
+                fn from(v: mint::$m<S>) -> Self {
+                    Self(v.into())
+                }
+            }
+
+            impl<S> From<$v<S>> for mint::$m<S> {
+                fn from(v: $v<S>) -> Self {
+                    v.0.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_mint_vec!(Vec2 => Vector2, Vec3 => Vector3, Vec4 => Vector4);
+
+impl<S> From<mint::Quaternion<S>> for Quat<S> {
+    fn from(q: mint::Quaternion<S>) -> Self {
+        let [x, y, z] = q.v.into();
+        Self([x, y, z, q.s])
+    }
+}
+
+impl<S> From<Quat<S>> for mint::Quaternion<S> {
+    fn from(q: Quat<S>) -> Self {
+        let [x, y, z, s] = q.0;
+        Self { v: [x, y, z].into(), s }
+    }
+}
+
+// matrices
+// -----------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_mint_mat {
+    ($($mat:ident, $vec:ident => $col:ident, $row:ident, [$($c:ident),*]);*) => {
+        $(
+            impl<S> From<mint::$col<S>> for $mat<S> {
+                fn from(m: mint::$col<S>) -> Self {
+                    Self([$($vec::from(m.$c)),*])
+                }
+            }
+
+            impl<S> From<$mat<S>> for mint::$col<S> {
+                fn from(m: $mat<S>) -> Self {
+                    let [$($c),*] = m.0;
+                    Self { $($c: $c.into()),* }
+                }
+            }
+
+            impl<S: Copy> From<mint::$row<S>> for $mat<S> {
+                fn from(m: mint::$row<S>) -> Self {
+                    mint::$col::from(m).into()
+                }
+            }
+
+            impl<S: Copy> From<$mat<S>> for mint::$row<S> {
+                fn from(m: $mat<S>) -> Self {
+                    mint::$col::from(m).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_mint_mat!(
+    Mat2, Vec2 => ColumnMatrix2, RowMatrix2, [x, y];
+    Mat3, Vec3 => ColumnMatrix3, RowMatrix3, [x, y, z];
+    Mat4, Vec4 => ColumnMatrix4, RowMatrix4, [x, y, z, w]
+);
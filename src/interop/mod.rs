@@ -0,0 +1,122 @@
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, any(feature = "mint", feature = "glam", feature = "nalgebra")))]
+mod tests {
+    use crate::*;
+
+    // distinct entries so a transposed matrix can't pass, columns are (1 2 3), (4 5 6), (7 8 9)
+    fn mat3() -> Mat3<f32> {
+        Mat3::<f32>([
+            Vec3::<f32>::new(1.0, 2.0, 3.0),
+            Vec3::<f32>::new(4.0, 5.0, 6.0),
+            Vec3::<f32>::new(7.0, 8.0, 9.0),
+        ])
+    }
+
+    fn mat4() -> Mat4<f64> {
+        Mat4::<f64>(core::array::from_fn(|c| {
+            Vec4::<f64>(core::array::from_fn(|r| (c * 4 + r) as f64))
+        }))
+    }
+
+    fn quat() -> Quat<f32> {
+        Quat::<f32>::from_rotation_axis(Rad(0.9), Vec3::<f32>::new(2.0, -1.0, 2.0).normalized())
+    }
+
+    #[test]
+    #[cfg(feature = "mint")]
+    fn test_interop_mint() {
+        let v: mint::Vector3<f32> = Vec3::<f32>::new(1.0, 2.0, 3.0).into();
+        assert_eq!((v.x, v.y, v.z), (1.0, 2.0, 3.0));
+        assert_eq!(
+            Vec4::<i32>::from(mint::Vector4::from([1, 2, 3, 4])),
+            Vec4::<i32>([1, 2, 3, 4])
+        );
+        assert_eq!(
+            Vec2::<f64>::from(mint::Vector2::<f64>::from(Vec2::<f64>::new(5.0, 6.0))),
+            Vec2::<f64>::new(5.0, 6.0)
+        );
+
+        let q: mint::Quaternion<f32> = Quat::<f32>::new(1.0, 2.0, 3.0, 4.0).into();
+        assert_eq!((q.v.x, q.v.y, q.v.z, q.s), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Quat::<f32>::from(q), Quat::<f32>::new(1.0, 2.0, 3.0, 4.0));
+
+        // column matrices hold columns, row matrices hold rows
+        let c: mint::ColumnMatrix3<f32> = mat3().into();
+        assert_eq!((c.y.x, c.y.y, c.y.z), (4.0, 5.0, 6.0));
+        let r: mint::RowMatrix3<f32> = mat3().into();
+        assert_eq!((r.x.x, r.x.y, r.x.z), (1.0, 4.0, 7.0));
+        assert_eq!(Mat3::<f32>::from(c), mat3());
+        assert_eq!(Mat3::<f32>::from(r), mat3());
+        assert_eq!(Mat4::<f64>::from(mint::RowMatrix4::from(mat4())), mat4());
+        let m2 = Mat2::<f32>([Vec2::<f32>::new(1.0, 2.0), Vec2::<f32>::new(3.0, 4.0)]);
+        assert_eq!(Mat2::<f32>::from(mint::ColumnMatrix2::from(m2)), m2);
+    }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn test_interop_glam() {
+        let g: glam::Mat3 = mat3().into();
+        assert_eq!(g.col(1), glam::Vec3::new(4.0, 5.0, 6.0));
+        assert_eq!(g.row(0), glam::Vec3::new(1.0, 4.0, 7.0));
+        assert_eq!(Mat3::<f32>::from(g), mat3());
+
+        // both sides transform a vector the same way
+        let v = Vec3::<f32>::new(1.0, -2.0, 0.5);
+        assert_eq!(Vec3::<f32>::from(g * glam::Vec3::from(v)), mat3() * v);
+        let g4: glam::DMat4 = mat4().into();
+        assert_eq!(g4.w_axis, glam::DVec4::new(12.0, 13.0, 14.0, 15.0));
+        assert_eq!(
+            Vec4::<f64>::from(g4 * glam::DVec4::new(1.0, 2.0, 3.0, 4.0)),
+            mat4() * Vec4::<f64>::new(1.0, 2.0, 3.0, 4.0)
+        );
+        let m2 = Mat2::<f32>([Vec2::<f32>::new(1.0, 2.0), Vec2::<f32>::new(3.0, 4.0)]);
+        assert_eq!(glam::Mat2::from(m2).x_axis, glam::Vec2::new(1.0, 2.0));
+
+        let q: glam::Quat = Quat::<f32>::new(1.0, 2.0, 3.0, 4.0).into();
+        assert_eq!((q.x, q.y, q.z, q.w), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Quat::<f32>::from(q), Quat::<f32>::new(1.0, 2.0, 3.0, 4.0));
+        let rotated = Vec3::<f32>::from(glam::Quat::from(quat()) * glam::Vec3::from(v));
+        assert!((rotated - quat() * v).length() < 1e-5);
+        assert_eq!(
+            Vec2::<f64>::from(glam::DVec2::new(1.0, 2.0)),
+            Vec2::<f64>::new(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn test_interop_nalgebra() {
+        let n: nalgebra::Matrix3<f32> = mat3().into();
+        assert_eq!(n[(0, 1)], 4.0);
+        assert_eq!(n.column(2), nalgebra::Vector3::new(7.0, 8.0, 9.0));
+        assert_eq!(Mat3::<f32>::from(n), mat3());
+
+        let v = Vec3::<f32>::new(1.0, -2.0, 0.5);
+        assert_eq!(Vec3::<f32>::from(n * nalgebra::Vector3::from(v)), mat3() * v);
+        let n4: nalgebra::Matrix4<f64> = mat4().into();
+        assert_eq!(n4[(1, 3)], 13.0);
+        assert_eq!(Mat4::<f64>::from(n4), mat4());
+        let m2 = Mat2::<f32>([Vec2::<f32>::new(1.0, 2.0), Vec2::<f32>::new(3.0, 4.0)]);
+        assert_eq!(nalgebra::Matrix2::from(m2)[(1, 0)], 2.0);
+
+        // nalgebra takes w first in its constructor but reads the same components back
+        let q: nalgebra::Quaternion<f32> = Quat::<f32>::new(1.0, 2.0, 3.0, 4.0).into();
+        assert_eq!(q, nalgebra::Quaternion::new(4.0, 1.0, 2.0, 3.0));
+        assert_eq!((q.i, q.j, q.k, q.w), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Quat::<f32>::from(q), Quat::<f32>::new(1.0, 2.0, 3.0, 4.0));
+
+        let unit: nalgebra::UnitQuaternion<f32> = (quat() * 3.0).into();
+        assert!((Quat::<f32>::from(unit) - quat()).length() < 1e-6);
+        let rotated = Vec3::<f32>::from(unit * nalgebra::Vector3::from(v));
+        assert!((rotated - quat() * v).length() < 1e-5);
+    }
+}
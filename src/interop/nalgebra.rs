@@ -0,0 +1,77 @@
+use crate::*;
+use nalgebra::{RealField, SMatrix, SVector, Scalar};
+
+// nalgebra matrices are column major as well. its quaternions are built as (w, i, j, k) but stored as (i, j, k, w),
+// which is the order we use, so the conversions go through the stored coordinates
+
+// vectors and matrices
+// -----------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_nalgebra_vec {
+    ($($v:ident, $n:literal);*) => {
+        $(
+            impl<S: Scalar> From<SVector<S, $n>> for $v<S> {
+                fn from(v: SVector<S, $n>) -> Self {
+                    Self(v.into())
+                }
+            }
+
+            impl<S: Scalar> From<$v<S>> for SVector<S, $n> {
+                fn from(v: $v<S>) -> Self {
+                    v.0.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_nalgebra_vec!(Vec2, 2; Vec3, 3; Vec4, 4);
+
+macro_rules! impl_nalgebra_mat {
+    ($($mat:ident, $vec:ident, $n:literal);*) => {
+        $(
+            impl<S: Scalar> From<SMatrix<S, $n, $n>> for $mat<S> {
+                fn from(m: SMatrix<S, $n, $n>) -> Self {
+                    let cols: [[S; $n]; $n] = m.into();
+                    Self(cols.map($vec))
+                }
+            }
+
+            impl<S: Scalar> From<$mat<S>> for SMatrix<S, $n, $n> {
+                fn from(m: $mat<S>) -> Self {
+                    m.0.map(|c| c.0).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_nalgebra_mat!(Mat2, Vec2, 2; Mat3, Vec3, 3; Mat4, Vec4, 4);
+
+// quaternions
+// -----------------------------------------------------------------------------------------------------------
+
+impl<S: Scalar> From<nalgebra::Quaternion<S>> for Quat<S> {
+    fn from(q: nalgebra::Quaternion<S>) -> Self {
+        Self(q.coords.into())
+    }
+}
+
+impl<S: Scalar> From<Quat<S>> for nalgebra::Quaternion<S> {
+    fn from(q: Quat<S>) -> Self {
+        Self::from_vector(q.0.into())
+    }
+}
+
+impl<S: Scalar> From<nalgebra::UnitQuaternion<S>> for Quat<S> {
+    fn from(q: nalgebra::UnitQuaternion<S>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+// normalizes, as a unit quaternion has to be
+impl<S: RealField> From<Quat<S>> for nalgebra::UnitQuaternion<S> {
+    fn from(q: Quat<S>) -> Self {
+        Self::new_normalize(q.into())
+    }
+}
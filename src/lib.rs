@@ -1,14 +1,59 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("carbon-math needs either the `std` or the `libm` feature for float math");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod util;
 pub use util::*;
+mod aabb;
+pub use aabb::*;
+mod angle;
+pub use angle::*;
 mod bivec;
 pub use bivec::*;
+mod bounds;
+pub use bounds::*;
+#[cfg(feature = "alloc")]
+mod bvh;
+#[cfg(feature = "alloc")]
+pub use bvh::*;
+mod camera;
+pub use camera::*;
+mod collide;
+pub use collide::*;
+mod color;
+mod fixed;
+pub use fixed::*;
+mod gpu;
+pub use gpu::*;
+mod ik;
+pub use ik::*;
+mod interop;
 mod mat;
 pub use mat::*;
+mod noise;
+pub use noise::*;
+mod pack;
+pub use pack::*;
 mod quat;
 pub use quat::*;
 mod rot;
 pub use rot::*;
+mod sample;
+mod smooth;
+pub use smooth::*;
+#[cfg(feature = "alloc")]
+mod spatial;
+#[cfg(feature = "alloc")]
+pub use spatial::*;
 mod vec;
 pub use vec::*;
+#[cfg(feature = "serde")]
+pub mod verbose;
+#[cfg(feature = "serde")]
+pub use verbose::{Verbose, VerboseRepr};
 
 pub fn hello() {}
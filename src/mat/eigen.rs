@@ -0,0 +1,71 @@
+use crate::*;
+
+// cyclic jacobi: each rotation zeroes one off-diagonal pair and the rotations pile up into the eigenvectors. it
+// converges quadratically and stays accurate for the small symmetric matrices covariance and inertia give
+macro_rules! impl_eigen {
+    ($t:ident) => {
+        impl Mat3<$t> {
+            // eigenvalues of a symmetric matrix, largest first, and the matching unit eigenvectors as the columns
+            // of a rotation. only the lower triangle is read
+            pub fn symmetric_eigen(&self) -> (Vec3<$t>, Mat3<$t>) {
+                let at = |row: usize, col: usize| self.0[col.min(row)].0[col.max(row)];
+                let mut a = [0, 1, 2].map(|r| [0, 1, 2].map(|c| at(r, c)));
+                let mut v = [0, 1, 2].map(|r| [0, 1, 2].map(|c| if r == c { $t::one() } else { $t::zero() }));
+
+                for _ in 0..32 {
+                    let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+                    let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+                    if off <= diagonal * $t::EPSILON * $t::EPSILON {
+                        break;
+                    }
+                    for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                        if a[p][q] == $t::zero() {
+                            continue;
+                        }
+                        let theta = (a[q][q] - a[p][p]) / (a[p][q] * $t::splat(2.0));
+                        let t = $t::one() / (theta.abs() + Float::sqrt(theta * theta + $t::one()));
+                        let t = if theta < $t::zero() { -t } else { t };
+                        let c = $t::one() / Float::sqrt(t * t + $t::one());
+                        let s = t * c;
+                        for k in 0..3 {
+                            let (kp, kq) = (a[k][p], a[k][q]);
+                            a[k][p] = c * kp - s * kq;
+                            a[k][q] = s * kp + c * kq;
+                        }
+                        for k in 0..3 {
+                            let (pk, qk) = (a[p][k], a[q][k]);
+                            a[p][k] = c * pk - s * qk;
+                            a[q][k] = s * pk + c * qk;
+                        }
+                        for row in v.iter_mut() {
+                            let (kp, kq) = (row[p], row[q]);
+                            row[p] = c * kp - s * kq;
+                            row[q] = s * kp + c * kq;
+                        }
+                    }
+                }
+
+                let mut order = [0, 1, 2];
+                order.sort_unstable_by(|&i, &j| {
+                    a[j][j]
+                        .partial_cmp(&a[i][i])
+                        .unwrap_or(core::cmp::Ordering::Equal)
+                });
+                let values = Vec3::<$t>::new(
+                    a[order[0]][order[0]],
+                    a[order[1]][order[1]],
+                    a[order[2]][order[2]],
+                );
+                let mut vectors = Mat3::<$t>(order.map(|c| Vec3::<$t>::new(v[0][c], v[1][c], v[2][c])));
+                // keep the basis right handed so it is a rotation
+                if vectors.0[0].cross(vectors.0[1]).dot(vectors.0[2]) < $t::zero() {
+                    vectors.0[2] = -vectors.0[2];
+                }
+                (values, vectors)
+            }
+        }
+    };
+}
+
+impl_eigen!(f32);
+impl_eigen!(f64);
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
@@ -95,16 +95,17 @@ macro_rules! impl_mat2 {
                 *self *= Self::from_translation(translation);
             }
 
-            pub fn from_rotation(rad: $t) -> Self {
-                let (s, c) = (rad.sin(), rad.cos());
+            pub fn from_rotation(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                let (s, c) = (Float::sin(rad), Float::cos(rad));
                 Self::new(c, -s, s, c)
             }
 
             pub fn get_rotation(&self) -> $t {
-                self[0][0].atan2(self[1][0])
+                Float::atan2(self[0][0], self[1][0])
             }
 
-            pub fn rotate(&mut self, rad: $t) {
+            pub fn rotate(&mut self, rad: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation(rad);
             }
 
@@ -123,8 +124,8 @@ macro_rules! impl_mat2 {
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Mat2<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for Mat2<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 let [m00, m01] = self[0].into();
                 let [m10, m11] = self[1].into();
 
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
 
@@ -138,42 +138,45 @@ macro_rules! impl_mat3 {
                 *self *= Self::from_translation(translation);
             }
 
-            pub fn from_rotation_x(theta: $t) -> Self {
-                let (sin, cos) = theta.sin_cos();
+            pub fn from_rotation_x(theta: impl Into<Rad<$t>>) -> Self {
+                let theta = theta.into().0;
+                let (sin, cos) = Float::sin_cos(theta);
                 Self::new($t::one(), $t::zero(), $t::zero(), $t::zero(), cos, -sin, $t::zero(), sin, cos)
             }
 
-            pub fn from_rotation_y(theta: $t) -> Self {
-                let (sin, cos) = theta.sin_cos();
+            pub fn from_rotation_y(theta: impl Into<Rad<$t>>) -> Self {
+                let theta = theta.into().0;
+                let (sin, cos) = Float::sin_cos(theta);
                 Self::new(cos, $t::zero(), sin, $t::zero(), $t::one(), $t::zero(), -sin, $t::zero(), cos)
             }
 
-            pub fn from_rotation_z(theta: $t) -> Self {
-                let (sin, cos) = theta.sin_cos();
+            pub fn from_rotation_z(theta: impl Into<Rad<$t>>) -> Self {
+                let theta = theta.into().0;
+                let (sin, cos) = Float::sin_cos(theta);
                 Self::new(cos, -sin, $t::zero(), sin, cos, $t::zero(), $t::zero(), $t::zero(), $t::one())
             }
 
             pub fn get_rotation_x(&self) -> $t {
-                self[1][1].atan2(self[2][1])
+                Float::atan2(self[1][1], self[2][1])
             }
 
             pub fn get_rotation_y(&self) -> $t {
-                self[2][0].atan2(self[0][0])
+                Float::atan2(self[2][0], self[0][0])
             }
 
             pub fn get_rotation_z(&self) -> $t {
-                self[0][1].atan2(self[1][0])
+                Float::atan2(self[0][1], self[1][0])
             }
 
-            pub fn rotate_x(&mut self, theta: $t) {
+            pub fn rotate_x(&mut self, theta: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation_x(theta);
             }
 
-            pub fn rotate_y(&mut self, theta: $t) {
+            pub fn rotate_y(&mut self, theta: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation_y(theta);
             }
 
-            pub fn rotate_z(&mut self, theta: $t) {
+            pub fn rotate_z(&mut self, theta: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation_z(theta);
             }
 
@@ -192,8 +195,8 @@ macro_rules! impl_mat3 {
 
 		// display
 		// ---------------------------------------------------------------------------------------------------
-		impl std::fmt::Display for Mat3<$t> {
-			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		impl core::fmt::Display for Mat3<$t> {
+			fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
 				let [m00, m01, m02] = self[0].into();
 				let [m10, m11, m12] = self[1].into();
 				let [m20, m21, m22] = self[2].into();
@@ -386,3 +389,5 @@ macro_rules! impl_mat3 {
 
 impl_mat3!(f32);
 impl_mat3!(f64);
+impl_mat3!(I16F16);
+impl_mat3!(I32F32);
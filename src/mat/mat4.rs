@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 macro_rules! impl_mat4 {
     ($t:ident) => {
@@ -176,8 +176,9 @@ macro_rules! impl_mat4 {
                 *self *= Self::from_translation(translation.into());
             }
 
-            pub fn from_rotation_x(rad: $t) -> Self {
-                let (sin, cos) = rad.sin_cos();
+            pub fn from_rotation_x(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                let (sin, cos) = Float::sin_cos(rad);
                 Self::new(
                     $t::one(),
                     $t::zero(),
@@ -198,8 +199,9 @@ macro_rules! impl_mat4 {
                 )
             }
 
-            pub fn from_rotation_y(rad: $t) -> Self {
-                let (sin, cos) = rad.sin_cos();
+            pub fn from_rotation_y(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                let (sin, cos) = Float::sin_cos(rad);
                 Self::new(
                     cos,
                     $t::zero(),
@@ -220,8 +222,9 @@ macro_rules! impl_mat4 {
                 )
             }
 
-            pub fn from_rotation_z(rad: $t) -> Self {
-                let (sin, cos) = rad.sin_cos();
+            pub fn from_rotation_z(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                let (sin, cos) = Float::sin_cos(rad);
                 Self::new(
                     cos,
                     sin,
@@ -242,15 +245,15 @@ macro_rules! impl_mat4 {
                 )
             }
 
-            pub fn rotate_x(&mut self, rad: $t) {
+            pub fn rotate_x(&mut self, rad: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation_x(rad);
             }
 
-            pub fn rotate_y(&mut self, rad: $t) {
+            pub fn rotate_y(&mut self, rad: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation_y(rad);
             }
 
-            pub fn rotate_z(&mut self, rad: $t) {
+            pub fn rotate_z(&mut self, rad: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation_z(rad);
             }
 
@@ -263,9 +266,9 @@ macro_rules! impl_mat4 {
             }
 
             pub fn from_euler(roll: $t, pitch: $t, yaw: $t) -> Self {
-                let (sin_r, cos_r) = roll.sin_cos();
-                let (sin_p, cos_p) = pitch.sin_cos();
-                let (sin_y, cos_y) = yaw.sin_cos();
+                let (sin_r, cos_r) = Float::sin_cos(roll);
+                let (sin_p, cos_p) = Float::sin_cos(pitch);
+                let (sin_y, cos_y) = Float::sin_cos(yaw);
 
                 let m00 = cos_y * cos_r + sin_p * sin_y * sin_r;
                 let m01 = cos_r * sin_p * sin_y - cos_y * sin_r;
@@ -299,14 +302,15 @@ macro_rules! impl_mat4 {
 
             pub fn get_euler(&self) -> Vec3<$t> {
                 let m = self.transposed();
-                let y = (-m[2][0]).asin();
-                let x = m[2][1].atan2(m[2][2]);
-                let z = m[1][0].atan2(m[0][0]);
+                let y = Float::asin(-m[2][0]);
+                let x = Float::atan2(m[2][1], m[2][2]);
+                let z = Float::atan2(m[1][0], m[0][0]);
                 Vec3::<$t>::new(x, y, z)
             }
 
-            pub fn from_rotation_axis(axis: Vec3<$t>, rad: $t) -> Self {
-                let (sin, cos) = rad.sin_cos();
+            pub fn from_rotation_axis(axis: Vec3<$t>, rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                let (sin, cos) = Float::sin_cos(rad);
                 let axis = axis.normalized();
                 let one_minus_cos = $t::one() - cos;
 
@@ -335,13 +339,13 @@ macro_rules! impl_mat4 {
                 let y = self[1].truncated().normalized();
                 let z = self[2].truncated().normalized();
                 let cos = (x[0] + y[1] + z[2] - $t::one()) * $t::splat(0.5);
-                let sin = ($t::one() - cos * cos).sqrt();
-                let rad = sin.acos();
+                let sin = Float::sqrt($t::one() - cos * cos);
+                let rad = Float::acos(sin);
                 let axis = Vec3::<$t>::new(z[1] - y[2], x[2] - z[0], y[0] - x[1]);
                 (axis.normalized(), rad)
             }
 
-            pub fn rotate(&mut self, axis: Vec3<$t>, rad: $t) {
+            pub fn rotate(&mut self, axis: Vec3<$t>, rad: impl Into<Rad<$t>>) {
                 *self *= Self::from_rotation_axis(axis, rad);
             }
 
@@ -395,16 +399,16 @@ macro_rules! impl_mat4 {
                     f[2],
                     $t::zero(),
 
-                    r.dot(eye),
-                    (u.dot(eye)),
-                    f.dot(eye),
+                    -r.dot(eye),
+                    -(u.dot(eye)),
+                    -f.dot(eye),
                     $t::one(),
                 )
             }
 
             // -1.0 - 1.0
             // pub fn perspective(fov: $t, aspect: $t, near: $t, far: $t) -> Self {
-            //     let s = $t::one() / (fov * $t::splat(0.5)).tan();
+            //     let s = $t::one() / Float::tan(fov * $t::splat(0.5));
             //     let n_diff_f = $t::one() / (near - far);
 
             //     Self::new(
@@ -427,8 +431,9 @@ macro_rules! impl_mat4 {
             //     )
             // }
 
-            pub fn perspective(fov: $t, aspect: $t, near: $t, far: $t) -> Self {
-                let s = $t::one() / (fov * $t::splat(0.5)).tan();
+            pub fn perspective(fov: impl Into<Rad<$t>>, aspect: $t, near: $t, far: $t) -> Self {
+                let fov = fov.into().0;
+                let s = $t::one() / Float::tan(fov * $t::splat(0.5));
                 let n_diff_f = $t::one() / (near - far);
 
                 Self::new(
@@ -455,8 +460,9 @@ macro_rules! impl_mat4 {
             }
 
 			// 0.0 - 1.0
-            pub fn perspective_infinite(fov: $t, aspect: $t, near: $t, far: $t) -> Self {
-                let s = $t::one() / (fov * $t::splat(0.5)).tan();
+            pub fn perspective_infinite(fov: impl Into<Rad<$t>>, aspect: $t, near: $t, far: $t) -> Self {
+                let fov = fov.into().0;
+                let s = $t::one() / Float::tan(fov * $t::splat(0.5));
                 let n_diff_f = $t::one() / (near - far);
 
                 Self::new(
@@ -481,13 +487,27 @@ macro_rules! impl_mat4 {
                     $t::zero(),
                 )
             }
+
+            // offsets the projection by a subpixel amount in clip space, `jitter` is in pixels, typically in
+            // [-0.5, 0.5] from a low-discrepancy sequence, and `resolution` is the render target size
+            pub fn jittered(&self, jitter: Vec2<$t>, resolution: Vec2<$t>) -> Self {
+                let dx = $t::splat(2.0) * jitter[0] / resolution[0];
+                let dy = $t::splat(2.0) * jitter[1] / resolution[1];
+                let mut m = *self;
+                for c in 0..4 {
+                    let w = m[c][3];
+                    m[c][0] += dx * w;
+                    m[c][1] += dy * w;
+                }
+                m
+            }
         }
 
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Mat4<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for Mat4<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 let [m00, m01, m02, m03] = self[0].into();
                 let [m10, m11, m12, m13] = self[1].into();
                 let [m20, m21, m22, m23] = self[2].into();
@@ -693,3 +713,5 @@ macro_rules! impl_mat4 {
 
 impl_mat4!(f32);
 impl_mat4!(f64);
+impl_mat4!(I16F16);
+impl_mat4!(I32F32);
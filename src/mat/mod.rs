@@ -1,3 +1,4 @@
+mod eigen;
 mod mat2;
 mod mat3;
 mod mat4;
@@ -8,18 +9,27 @@ pub use mat4::*;
 use crate::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mat2<S>(pub [Vec2<S>; 2]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Mat2<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Mat2<S> where S: bytemuck::Pod {}
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mat3<S>(pub [Vec3<S>; 3]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Mat3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Mat3<S> where S: bytemuck::Pod {}
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mat4<S>(pub [Vec4<S>; 4]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Mat4<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Mat4<S> where S: bytemuck::Pod {}
 
 // test
@@ -178,4 +188,43 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_mat4_jittered() {
+        let proj = Mat4::<f32>::perspective(Rad(1.2), 16.0 / 9.0, 0.1, 100.0);
+        let resolution = Vec2::<f32>::new(1920.0, 1080.0);
+        let jitter = Vec2::<f32>::halton(5) - Vec2::<f32>::one() * 0.5;
+        let jittered = proj.jittered(jitter, resolution);
+
+        let p = Vec4::<f32>::new(0.3, -0.2, -4.0, 1.0);
+        let (a, b) = (proj * p, jittered * p);
+        let offset = Vec2::<f32>::new(b[0] / b[3] - a[0] / a[3], b[1] / b[3] - a[1] / a[3]);
+        let expected = Vec2::<f32>::new(2.0 * jitter[0] / resolution[0], 2.0 * jitter[1] / resolution[1]);
+        assert!((offset - expected).length() < 1e-6);
+        assert_eq!(a[2], b[2]);
+        assert_eq!(a[3], b[3]);
+    }
+
+    #[test]
+    fn test_mat3_symmetric_eigen() {
+        let m = Mat3::<f64>([
+            Vec3::<f64>::new(4.0, 1.0, 2.0),
+            Vec3::<f64>::new(1.0, 3.0, 0.0),
+            Vec3::<f64>::new(2.0, 0.0, 5.0),
+        ]);
+        let (values, vectors) = m.symmetric_eigen();
+        assert!(values[0] >= values[1] && values[1] >= values[2]);
+        for i in 0..3 {
+            let v = vectors.0[i];
+            assert_abs_diff_eq!(m * v, v * values[i], epsilon = 1e-12);
+            assert!((v.length() - 1.0).abs() < 1e-12);
+        }
+        assert!((vectors.determinant() - 1.0).abs() < 1e-12);
+        assert!((values[0] + values[1] + values[2] - 12.0).abs() < 1e-12);
+
+        // already diagonal, and a repeated eigenvalue
+        let (values, vectors) = Mat3::<f32>::from_diagonal(Vec3::<f32>::new(1.0, 3.0, 3.0)).symmetric_eigen();
+        assert_eq!(values, Vec3::<f32>::new(3.0, 3.0, 1.0));
+        assert_abs_diff_eq!(vectors.0[2].cross(Vec3::<f32>::unit_x()), Vec3::<f32>::zero());
+    }
 }
@@ -0,0 +1,95 @@
+use crate::*;
+
+macro_rules! impl_fractal {
+    ($t:ident, $v:ident, $n:literal) => {
+        impl<N> Noise<$v<$t>> for Fbm<N, $t>
+        where
+            N: Noise<$v<$t>, Output = $t>,
+        {
+            type Output = $t;
+            fn sample_grad(&self, p: $v<$t>) -> ($t, $v<$t>) {
+                let mut value = $t::zero();
+                let mut grad = $v::<$t>::zero();
+                let mut amplitude = $t::one();
+                let mut frequency = $t::one();
+                let mut total = $t::zero();
+                for _ in 0..self.octaves {
+                    let (v, g) = self.noise.sample_grad(p * frequency);
+                    value += v * amplitude;
+                    grad += g * (amplitude * frequency);
+                    total += amplitude;
+                    amplitude *= self.gain;
+                    frequency *= self.lacunarity;
+                }
+
+                if total == $t::zero() {
+                    (value, grad)
+                } else {
+                    (value / total, grad / total)
+                }
+            }
+        }
+
+        impl<N> Noise<$v<$t>> for Ridged<N, $t>
+        where
+            N: Noise<$v<$t>, Output = $t>,
+        {
+            type Output = $t;
+            fn sample_grad(&self, p: $v<$t>) -> ($t, $v<$t>) {
+                let mut value = $t::zero();
+                let mut grad = $v::<$t>::zero();
+                let mut amplitude = $t::one();
+                let mut frequency = $t::one();
+                let mut total = $t::zero();
+                for _ in 0..self.octaves {
+                    let (v, g) = self.noise.sample_grad(p * frequency);
+                    let ridge = $t::one() - v.abs();
+                    value += ridge * ridge * amplitude;
+                    grad += g * (-$t::splat(2.0) * ridge * v.signum() * amplitude * frequency);
+                    total += amplitude;
+                    amplitude *= self.gain;
+                    frequency *= self.lacunarity;
+                }
+
+                if total == $t::zero() {
+                    (value, grad)
+                } else {
+                    (value / total, grad / total)
+                }
+            }
+        }
+
+        impl<N, W> Noise<$v<$t>> for DomainWarp<N, W, $t>
+        where
+            N: Noise<$v<$t>, Output = $t>,
+            W: Noise<$v<$t>, Output = $t>,
+        {
+            type Output = $t;
+            fn sample_grad(&self, p: $v<$t>) -> ($t, $v<$t>) {
+                // each warped axis samples the warp noise at a decorrelating offset
+                let warps: [($t, $v<$t>); $n] =
+                    core::array::from_fn(|i| self.warp.sample_grad(p + $v::<$t>::one() * (i as $t * 5.2)));
+
+                let mut q = p;
+                for i in 0..$n {
+                    q[i] += warps[i].0 * self.strength;
+                }
+
+                // chain rule through q = p + strength * w(p)
+                let (value, g) = self.noise.sample_grad(q);
+                let mut grad = g;
+                for i in 0..$n {
+                    grad += warps[i].1 * (g[i] * self.strength);
+                }
+                (value, grad)
+            }
+        }
+    };
+}
+
+impl_fractal!(f32, Vec2, 2);
+impl_fractal!(f32, Vec3, 3);
+impl_fractal!(f32, Vec4, 4);
+impl_fractal!(f64, Vec2, 2);
+impl_fractal!(f64, Vec3, 3);
+impl_fractal!(f64, Vec4, 4);
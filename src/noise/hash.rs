@@ -0,0 +1,13 @@
+// integer only so results are identical on every platform
+pub(crate) fn hash<const N: usize>(seed: u32, cell: [i32; N]) -> u32 {
+    let mut h = seed.wrapping_mul(0x9e37_79b9) ^ 0x85eb_ca6b;
+    for c in cell {
+        h ^= (c as u32).wrapping_mul(0xcc9e_2d51);
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
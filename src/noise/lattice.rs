@@ -0,0 +1,364 @@
+use super::hash::hash;
+use crate::*;
+
+macro_rules! impl_lattice {
+    ($t:ident, $m:ident) => {
+        mod $m {
+            use super::*;
+
+            fn floor<const N: usize>(p: [$t; N]) -> [i32; N] {
+                p.map(|x| Float::floor(x) as i32)
+            }
+
+            // uniform in [0, 1)
+            fn unit(h: u32) -> $t {
+                (h >> 8) as $t / 16777216.0
+            }
+
+            fn fade(t: $t) -> ($t, $t) {
+                let value = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+                let derivative = 30.0 * t * t * (t * (t - 2.0) + 1.0);
+                (value, derivative)
+            }
+
+            // 8 unit directions in 2d, otherwise the edge midpoints of the n-cube
+            fn gradient<const N: usize>(h: u32) -> [$t; N] {
+                let mut g = [0.0; N];
+                if N == 2 {
+                    let s = core::$t::consts::FRAC_1_SQRT_2;
+                    let dirs = [
+                        [1.0, 0.0],
+                        [-1.0, 0.0],
+                        [0.0, 1.0],
+                        [0.0, -1.0],
+                        [s, s],
+                        [-s, s],
+                        [s, -s],
+                        [-s, -s],
+                    ];
+                    g.copy_from_slice(&dirs[(h & 7) as usize]);
+                } else {
+                    let zero = (h % N as u32) as usize;
+                    for (i, c) in g.iter_mut().enumerate() {
+                        if i != zero {
+                            *c = if (h >> (8 + i)) & 1 == 0 { 1.0 } else { -1.0 };
+                        }
+                    }
+                }
+                g
+            }
+
+            fn dot<const N: usize>(a: [$t; N], b: [$t; N]) -> $t {
+                (0..N).map(|i| a[i] * b[i]).sum()
+            }
+
+            // quintic interpolation of per-corner functions, `corner` returns the value and gradient at the
+            // given corner offset from p
+            fn interpolate<const N: usize, F>(p: [$t; N], corner: F) -> ($t, [$t; N])
+            where
+                F: Fn([i32; N], [$t; N]) -> ($t, [$t; N]),
+            {
+                let cell = floor(p);
+                let frac: [$t; N] = core::array::from_fn(|i| p[i] - cell[i] as $t);
+                let fades: [($t, $t); N] = frac.map(fade);
+
+                let mut value = 0.0;
+                let mut grad = [0.0; N];
+                for bits in 0..(1usize << N) {
+                    let c: [i32; N] = core::array::from_fn(|i| cell[i] + ((bits >> i) & 1) as i32);
+                    let d: [$t; N] = core::array::from_fn(|i| frac[i] - ((bits >> i) & 1) as $t);
+                    let (v, g) = corner(c, d);
+
+                    let weights: [($t, $t); N] = core::array::from_fn(|i| {
+                        let (u, du) = fades[i];
+                        if (bits >> i) & 1 == 1 {
+                            (u, du)
+                        } else {
+                            (1.0 - u, -du)
+                        }
+                    });
+                    let w: $t = weights.iter().map(|w| w.0).product();
+
+                    value += w * v;
+                    for i in 0..N {
+                        let dw: $t = (0..N)
+                            .map(|j| if i == j { weights[j].1 } else { weights[j].0 })
+                            .product();
+                        grad[i] += dw * v + w * g[i];
+                    }
+                }
+                (value, grad)
+            }
+
+            pub(crate) fn perlin<const N: usize>(seed: u32, p: [$t; N]) -> ($t, [$t; N]) {
+                let (value, grad) = interpolate(p, |c, d| {
+                    let g = gradient::<N>(hash(seed, c));
+                    (dot(g, d), g)
+                });
+
+                // one over the most the sum can reach, when every corner's gradient is the one closest to pointing at
+                // p. that's sqrt(2) / 2 at the middle of a square in 2d, elsewhere it was maximized numerically over
+                // the cell and rounded up, so the noise stays in [-1, 1]
+                let scale = match N {
+                    2 => core::$t::consts::SQRT_2,
+                    3 => 1.0 / 1.036_36,
+                    _ => 1.0 / 1.536_59,
+                };
+                (value * scale, grad.map(|g| g * scale))
+            }
+
+            pub(crate) fn value<const N: usize>(seed: u32, p: [$t; N]) -> ($t, [$t; N]) {
+                interpolate(p, |c, _| (unit(hash(seed, c)) * 2.0 - 1.0, [0.0; N]))
+            }
+
+            // adds the kernel (r² - |d|²)^4 (g . d) of a vertex at offset `d` from p, and its gradient
+            fn kernel<const N: usize>(r2: $t, g: [$t; N], d: [$t; N], value: &mut $t, grad: &mut [$t; N]) {
+                let falloff = r2 - dot(d, d);
+                if falloff <= 0.0 {
+                    return;
+                }
+                let gd = dot(g, d);
+                let f2 = falloff * falloff;
+                let f4 = f2 * f2;
+                *value += f4 * gd;
+                for i in 0..N {
+                    grad[i] += f4 * g[i] - 8.0 * f2 * falloff * gd * d[i];
+                }
+            }
+
+            // 24 directions 15 degrees apart
+            fn gradient_2d(h: u32) -> [$t; 2] {
+                const COS: [f64; 7] = [
+                    1.0,
+                    0.9659258262890683,
+                    0.8660254037844387,
+                    core::f64::consts::FRAC_1_SQRT_2,
+                    0.5,
+                    0.25881904510252074,
+                    0.0,
+                ];
+                let k = (h % 24) as usize;
+                let (c, s) = (COS[k % 6] as $t, COS[6 - k % 6] as $t);
+                match k / 6 {
+                    0 => [c, s],
+                    1 => [-s, c],
+                    2 => [-c, -s],
+                    _ => [s, -c],
+                }
+            }
+
+            // the 48 directions opensimplex2 uses on its bcc lattice, every arrangement of signs and order of
+            // (1 + sqrt(3/2), 1 + sqrt(3/2), 1) and of (3.086.., 1.172.., 0), which are the same length
+            fn gradient_3d(h: u32) -> [$t; 3] {
+                const LONG: [f64; 2] = [0.673887338679005, 0.9348469228350482];
+                const SHORT: [f64; 2] = [0.3029054465278827, 0.3550510257214325];
+                let k = (h % 48) as usize;
+                let mut g = [0.0; 3];
+                if k < 24 {
+                    g = [LONG[0] as $t; 3];
+                    g[k % 3] = SHORT[0] as $t;
+                } else {
+                    let (a, b) = if k & 1 == 0 {
+                        (LONG[1] as $t, SHORT[1] as $t)
+                    } else {
+                        (SHORT[1] as $t, LONG[1] as $t)
+                    };
+                    g[(k + 1) % 3] = a;
+                    g[(k + 2) % 3] = b;
+                }
+                for (i, c) in g.iter_mut().enumerate() {
+                    if (h >> (8 + i)) & 1 == 1 {
+                        *c = -*c;
+                    }
+                }
+                g
+            }
+
+            // opensimplex2 has the same three vertices per triangle as simplex noise in 2d, it's the gradients
+            // that tell them apart
+            pub(crate) fn open_simplex2_2d(seed: u32, p: [$t; 2]) -> ($t, [$t; 2]) {
+                let sqrt_3 = Float::sqrt(3.0 as $t);
+                let (skew, unskew) = ((sqrt_3 - 1.0) / 2.0, (3.0 - sqrt_3) / 6.0);
+                let s = (p[0] + p[1]) * skew;
+                let cell = floor([p[0] + s, p[1] + s]);
+                let t = (cell[0] + cell[1]) as $t * unskew;
+                let d0 = [p[0] - (cell[0] as $t - t), p[1] - (cell[1] as $t - t)];
+
+                let middle = if d0[0] > d0[1] { [1, 0] } else { [0, 1] };
+                let mut value = 0.0;
+                let mut grad = [0.0; 2];
+                for offset in [[0, 0], middle, [1, 1]] {
+                    let back = (offset[0] + offset[1]) as $t * unskew;
+                    let d = [d0[0] - offset[0] as $t + back, d0[1] - offset[1] as $t + back];
+                    let g = gradient_2d(hash(seed, [cell[0] + offset[0], cell[1] + offset[1]]));
+                    kernel(0.5, g, d, &mut value, &mut grad);
+                }
+
+                // one over the most the kernels can add up to, found like perlin's
+                let scale = 1.0 / 0.010_081;
+                (value * scale, grad.map(|g| g * scale))
+            }
+
+            // a body centered cubic lattice, as a cubic lattice and a copy shifted onto its cube centers, with the
+            // nearest two vertices of each contributing. opensimplex2's fast variant gives its kernels a radius
+            // squared of 0.6 here and in 4d, which leaves vertices still in reach out and the noise jumps a little
+            // where they change. 0.5 is as far as the vertices visited reach round every point
+            pub(crate) fn open_simplex2_3d(seed: u32, p: [$t; 3]) -> ($t, [$t; 3]) {
+                // turn the lattice half way round its main diagonal, which points the cube's edges off the axes.
+                // the turn is its own inverse, so it maps the gradient back the same way
+                let turn = |v: [$t; 3]| {
+                    let r = (v[0] + v[1] + v[2]) * (2.0 / 3.0);
+                    v.map(|x| r - x)
+                };
+                let q = turn(p);
+
+                let mut value = 0.0;
+                let mut grad = [0.0; 3];
+                for copy in 0..2 {
+                    let shifted = q.map(|x| x - 0.5 * copy as $t);
+                    let vertex = shifted.map(|x| Float::round(x) as i32);
+                    let d: [$t; 3] = core::array::from_fn(|i| shifted[i] - vertex[i] as $t);
+                    let g = gradient_3d(hash(seed, [vertex[0], vertex[1], vertex[2], copy]));
+                    kernel(0.5, g, d, &mut value, &mut grad);
+
+                    // the next nearest is the neighbour across the face the point is closest to
+                    let axis = (1..3).fold(0, |best, i| if d[i].abs() > d[best].abs() { i } else { best });
+                    let step = if d[axis] < 0.0 { -1 } else { 1 };
+                    let (mut next, mut d) = (vertex, d);
+                    next[axis] += step;
+                    d[axis] -= step as $t;
+                    let g = gradient_3d(hash(seed, [next[0], next[1], next[2], copy]));
+                    kernel(0.5, g, d, &mut value, &mut grad);
+                }
+
+                let scale = 1.0 / 0.009_235;
+                (value * scale, turn(grad).map(|g| g * scale))
+            }
+
+            // five copies of the simplex lattice, each shifted a fifth of the way along the main diagonal, with the
+            // one nearest vertex of each contributing. the copies are walked in turn, each starting from the vertex
+            // the last one picked
+            pub(crate) fn open_simplex2_4d(seed: u32, p: [$t; 4]) -> ($t, [$t; 4]) {
+                let sqrt_5 = Float::sqrt(5.0 as $t);
+                let (skew, unskew) = ((1.0 / sqrt_5 - 1.0) / 4.0, (sqrt_5 - 1.0) / 4.0);
+                let s = p.iter().sum::<$t>() * skew;
+                let mut vertex = floor(p.map(|x| x + s));
+                let frac: [$t; 4] = core::array::from_fn(|i| p[i] + s - vertex[i] as $t);
+
+                // start on the copy whose vertex near the cell's base is sure to be in reach
+                let mut copy = ((frac.iter().sum::<$t>() * 1.25) as i32).min(4);
+                let mut rel = frac.map(|f| f - 0.2 * copy as $t);
+                let mut value = 0.0;
+                let mut grad = [0.0; 4];
+                for _ in 0..5 {
+                    // the nearest of the vertex and its neighbours up one axis
+                    let axis = (1..4).fold(0, |best, i| if rel[i] > rel[best] { i } else { best });
+                    if rel[axis] >= 1.0 - rel.iter().sum::<$t>() {
+                        vertex[axis] += 1;
+                        rel[axis] -= 1.0;
+                    }
+                    let back = rel.iter().sum::<$t>() * unskew;
+                    let d = rel.map(|r| r + back);
+                    let g = gradient::<4>(hash(seed, [vertex[0], vertex[1], vertex[2], vertex[3], copy]));
+                    kernel(0.5, g, d, &mut value, &mut grad);
+
+                    // the copy below, where going below the first wraps round to the last one a cell down
+                    rel = rel.map(|r| r + 0.2);
+                    copy -= 1;
+                    if copy < 0 {
+                        copy = 4;
+                        vertex = vertex.map(|v| v - 1);
+                    }
+                }
+
+                let scale = 1.0 / 0.015_930;
+                (value * scale, grad.map(|g| g * scale))
+            }
+
+            // distances to the nearest and second nearest feature point and the gradients of both
+            pub(crate) fn worley<const N: usize>(
+                seed: u32,
+                jitter: $t,
+                p: [$t; N],
+            ) -> (($t, [$t; N]), ($t, [$t; N])) {
+                let cell = floor(p);
+                let mut nearest = ($t::MAX, [0.0; N]);
+                let mut second = ($t::MAX, [0.0; N]);
+
+                for n in 0..3usize.pow(N as u32) {
+                    let c: [i32; N] = core::array::from_fn(|i| cell[i] + (n / 3usize.pow(i as u32) % 3) as i32 - 1);
+                    let h = hash(seed, c);
+                    let d: [$t; N] = core::array::from_fn(|i| {
+                        let offset = unit(hash(h, [i as i32])) - 0.5;
+                        p[i] - (c[i] as $t + 0.5 + jitter * offset)
+                    });
+
+                    let dist_sq = dot(d, d);
+                    if dist_sq < nearest.0 {
+                        second = nearest;
+                        nearest = (dist_sq, d);
+                    } else if dist_sq < second.0 {
+                        second = (dist_sq, d);
+                    }
+                }
+
+                let finish = |(dist_sq, d): ($t, [$t; N])| {
+                    let dist = Float::sqrt(dist_sq);
+                    if dist == 0.0 {
+                        (dist, [0.0; N])
+                    } else {
+                        (dist, d.map(|x| x / dist))
+                    }
+                };
+                (finish(nearest), finish(second))
+            }
+
+            macro_rules! impl_lattice_noise {
+                ($v:ident, $n:literal, $open_simplex2:ident) => {
+                    impl Noise<$v<$t>> for Perlin {
+                        type Output = $t;
+                        fn sample_grad(&self, p: $v<$t>) -> ($t, $v<$t>) {
+                            let (value, grad) = perlin::<$n>(self.seed, p.0);
+                            (value, $v(grad))
+                        }
+                    }
+
+                    impl Noise<$v<$t>> for OpenSimplex2 {
+                        type Output = $t;
+                        fn sample_grad(&self, p: $v<$t>) -> ($t, $v<$t>) {
+                            let (value, grad) = $open_simplex2(self.seed, p.0);
+                            (value, $v(grad))
+                        }
+                    }
+
+                    impl Noise<$v<$t>> for Value {
+                        type Output = $t;
+                        fn sample_grad(&self, p: $v<$t>) -> ($t, $v<$t>) {
+                            let (value, grad) = value::<$n>(self.seed, p.0);
+                            (value, $v(grad))
+                        }
+                    }
+
+                    impl Noise<$v<$t>> for Worley<$t> {
+                        type Output = $t;
+                        fn sample_grad(&self, p: $v<$t>) -> ($t, $v<$t>) {
+                            let ((f1, g1), (f2, g2)) = worley::<$n>(self.seed, self.jitter, p.0);
+                            match self.distance {
+                                WorleyDistance::F1 => (f1, $v(g1)),
+                                WorleyDistance::F2 => (f2, $v(g2)),
+                                WorleyDistance::F2MinusF1 => (f2 - f1, $v(g2) - $v(g1)),
+                            }
+                        }
+                    }
+                };
+            }
+
+            impl_lattice_noise!(Vec2, 2, open_simplex2_2d);
+            impl_lattice_noise!(Vec3, 3, open_simplex2_3d);
+            impl_lattice_noise!(Vec4, 4, open_simplex2_4d);
+        }
+    };
+}
+
+impl_lattice!(f32, lattice_f32);
+impl_lattice!(f64, lattice_f64);
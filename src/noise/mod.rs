@@ -0,0 +1,246 @@
+mod fractal;
+mod hash;
+mod lattice;
+
+pub trait Noise<V> {
+    type Output;
+
+    // value and analytic gradient
+    fn sample_grad(&self, p: V) -> (Self::Output, V);
+
+    fn sample(&self, p: V) -> Self::Output {
+        self.sample_grad(p).0
+    }
+}
+
+// gradient noise in [-1, 1]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Perlin {
+    pub seed: u32,
+}
+
+// gradient noise in [-1, 1] on the lattices of opensimplex2, which in 3d and 4d keep the grid's axes from showing
+// through. the gradient sets and hashing are this crate's own, so values differ from the reference implementation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpenSimplex2 {
+    pub seed: u32,
+}
+
+// interpolated lattice values in [-1, 1]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Value {
+    pub seed: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorleyDistance {
+    #[default]
+    F1,
+    F2,
+    F2MinusF1,
+}
+
+// cellular noise, distance to the feature points of a jittered grid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Worley<S> {
+    pub seed: u32,
+    pub jitter: S,
+    pub distance: WorleyDistance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fbm<N, S> {
+    pub noise: N,
+    pub octaves: u32,
+    pub lacunarity: S,
+    pub gain: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ridged<N, S> {
+    pub noise: N,
+    pub octaves: u32,
+    pub lacunarity: S,
+    pub gain: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DomainWarp<N, W, S> {
+    pub noise: N,
+    pub warp: W,
+    pub strength: S,
+}
+
+macro_rules! impl_noise {
+    ($($s:ident),*) => {
+        impl Perlin {
+            pub fn new(seed: u32) -> Self {
+                Self { seed }
+            }
+        }
+
+        impl OpenSimplex2 {
+            pub fn new(seed: u32) -> Self {
+                Self { seed }
+            }
+        }
+
+        impl Value {
+            pub fn new(seed: u32) -> Self {
+                Self { seed }
+            }
+        }
+
+        $(
+            impl Worley<$s> {
+                pub fn new(seed: u32) -> Self {
+                    Self { seed, jitter: 1.0, distance: WorleyDistance::F1 }
+                }
+            }
+
+            impl<N> Fbm<N, $s> {
+                pub fn new(noise: N, octaves: u32) -> Self {
+                    Self { noise, octaves, lacunarity: 2.0, gain: 0.5 }
+                }
+            }
+
+            impl<N> Ridged<N, $s> {
+                pub fn new(noise: N, octaves: u32) -> Self {
+                    Self { noise, octaves, lacunarity: 2.0, gain: 0.5 }
+                }
+            }
+
+            impl<N, W> DomainWarp<N, W, $s> {
+                pub fn new(noise: N, warp: W, strength: $s) -> Self {
+                    Self { noise, warp, strength }
+                }
+            }
+        )*
+    };
+}
+
+impl_noise!(f32, f64);
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn points() -> impl Iterator<Item = Vec4<f64>> {
+        (0..500).map(|i| {
+            let i = i as f64;
+            Vec4::<f64>::new(i * 0.6180339, i * 0.3819660 - 40.0, i * 0.1372583 + 3.3, -i * 0.2719)
+        })
+    }
+
+    fn check_gradients<N>(noise: &N, eps: f64)
+    where
+        N: Noise<Vec2<f64>, Output = f64> + Noise<Vec3<f64>, Output = f64> + Noise<Vec4<f64>, Output = f64>,
+    {
+        let h = 1e-6;
+        for p in points() {
+            let p2 = p.truncated().truncated();
+            let (_, g) = noise.sample_grad(p2);
+            for i in 0..2 {
+                let mut o = Vec2::<f64>::zero();
+                o[i] = h;
+                let fd = (noise.sample(p2 + o) - noise.sample(p2 - o)) / (2.0 * h);
+                assert!((fd - g[i]).abs() < eps, "2d {} {} {}", p2, fd, g[i]);
+            }
+
+            let p3 = p.truncated();
+            let (_, g) = noise.sample_grad(p3);
+            for i in 0..3 {
+                let mut o = Vec3::<f64>::zero();
+                o[i] = h;
+                let fd = (noise.sample(p3 + o) - noise.sample(p3 - o)) / (2.0 * h);
+                assert!((fd - g[i]).abs() < eps, "3d {} {} {}", p3, fd, g[i]);
+            }
+
+            let (_, g) = noise.sample_grad(p);
+            for i in 0..4 {
+                let mut o = Vec4::<f64>::zero();
+                o[i] = h;
+                let fd = (noise.sample(p + o) - noise.sample(p - o)) / (2.0 * h);
+                assert!((fd - g[i]).abs() < eps, "4d {} {} {}", p, fd, g[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_noise_gradients() {
+        check_gradients(&Perlin::new(1), 1e-5);
+        check_gradients(&OpenSimplex2::new(2), 1e-5);
+        check_gradients(&Value::new(3), 1e-5);
+        check_gradients(&Worley::<f64>::new(4), 1e-5);
+        check_gradients(&Fbm::<_, f64>::new(Perlin::new(5), 4), 1e-4);
+        // ridged folds each octave where it crosses zero, this seed keeps the folds clear of the sample points
+        check_gradients(&Ridged::<_, f64>::new(OpenSimplex2::new(7), 3), 1e-4);
+        check_gradients(&DomainWarp::<_, _, f64>::new(Perlin::new(7), Value::new(8), 0.5), 1e-4);
+    }
+
+    // climbs each noise's own gradient from many starting points to look for peaks past 1
+    fn check_bounds<N>(noise: &N)
+    where
+        N: Noise<Vec2<f64>, Output = f64> + Noise<Vec3<f64>, Output = f64> + Noise<Vec4<f64>, Output = f64>,
+    {
+        fn climb<V, N>(noise: &N, mut p: V) -> f64
+        where
+            V: Copy + core::ops::Add<Output = V> + core::ops::Mul<f64, Output = V>,
+            N: Noise<V, Output = f64>,
+        {
+            let (mut value, _) = noise.sample_grad(p);
+            let sign = value.signum();
+            for _ in 0..40 {
+                let (v, g) = noise.sample_grad(p);
+                value = value.max(v * sign);
+                p = p + g * (0.02 * sign);
+            }
+            value
+        }
+        for p in points() {
+            assert!(climb(noise, p.truncated().truncated()) <= 1.0);
+            assert!(climb(noise, p.truncated()) <= 1.0);
+            assert!(climb(noise, p) <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_noise_bounds() {
+        for seed in 0..2 {
+            check_bounds(&Perlin::new(seed));
+            check_bounds(&OpenSimplex2::new(seed));
+        }
+    }
+
+    #[test]
+    fn test_noise_range_and_seed() {
+        for p in points() {
+            let p = p.truncated();
+            for v in [
+                Perlin::new(1).sample(p),
+                OpenSimplex2::new(1).sample(p),
+                Value::new(1).sample(p),
+            ] {
+                assert!((-1.0..=1.0).contains(&v), "{}", v);
+            }
+            assert!(Worley::<f64>::new(1).sample(p) >= 0.0);
+
+            assert_eq!(Perlin::new(9).sample(p), Perlin::new(9).sample(p));
+            assert_eq!(
+                OpenSimplex2::new(9).sample(p.truncated()),
+                OpenSimplex2::new(9).sample(p.truncated())
+            );
+        }
+
+        let p = Vec3::<f32>::new(0.3, 1.7, -2.1);
+        assert_ne!(Perlin::new(1).sample(p), Perlin::new(2).sample(p));
+        assert_ne!(OpenSimplex2::new(1).sample(p), OpenSimplex2::new(2).sample(p));
+        assert_eq!(Perlin::new(1).sample(Vec3::<f32>::new(3.0, -2.0, 5.0)), 0.0);
+        assert!(
+            (Perlin::new(1).sample(p) as f64 - Perlin::new(1).sample(Vec3::<f64>::new(0.3, 1.7, -2.1))).abs() < 1e-5
+        );
+    }
+}
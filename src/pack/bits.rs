@@ -0,0 +1,96 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+// bit streams, filled from the low bit of each byte upwards so a value written with n bits reads back with n bits
+// regardless of where it lands
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // writes the low `bits` bits of `value`, anything above them is ignored
+    pub fn write_bits(&mut self, mut value: u64, mut bits: u32) {
+        assert!(bits <= 64, "cannot write {} bits at once", bits);
+        while bits > 0 {
+            let used = (self.len % 8) as u32;
+            if used == 0 {
+                self.bytes.push(0);
+            }
+            let n = (8 - used).min(bits);
+            if let Some(last) = self.bytes.last_mut() {
+                *last |= ((value & ((1 << n) - 1)) as u8) << used;
+            }
+            value >>= n;
+            bits -= n;
+            self.len += n as usize;
+        }
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_bits(value as u64, 1);
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.len
+    }
+
+    // the bits written so far, with the last byte padded with zeros
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    // none once fewer than `bits` bits are left, in which case nothing is consumed
+    pub fn read_bits(&mut self, bits: u32) -> Option<u64> {
+        assert!(bits <= 64, "cannot read {} bits at once", bits);
+        if self.remaining_bits() < bits as usize {
+            return None;
+        }
+        let mut value = 0u64;
+        let mut done = 0;
+        while done < bits {
+            let used = (self.pos % 8) as u32;
+            let n = (8 - used).min(bits - done);
+            let chunk = (self.bytes[self.pos / 8] >> used) as u64 & ((1 << n) - 1);
+            value |= chunk << done;
+            done += n;
+            self.pos += n as usize;
+        }
+        Some(value)
+    }
+
+    pub fn read_bool(&mut self) -> Option<bool> {
+        self.read_bits(1).map(|b| b == 1)
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn remaining_bits(&self) -> usize {
+        self.bytes.len() * 8 - self.pos
+    }
+}
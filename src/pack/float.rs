@@ -0,0 +1,166 @@
+use crate::*;
+
+// small floats
+// -----------------------------------------------------------------------------------------------------------
+
+const fn exp2i(e: i32) -> f32 {
+    f32::from_bits(((e + 127) as u32) << 23)
+}
+
+// shifts right by `shift` rounding to nearest even
+fn shr_round(v: u32, shift: u32) -> u32 {
+    if shift == 0 {
+        return v;
+    }
+    if shift > 25 {
+        return 0;
+    }
+    let r = v >> shift;
+    let rem = v & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if rem > half || (rem == half && r & 1 == 1) {
+        r + 1
+    } else {
+        r
+    }
+}
+
+// encodes the magnitude of a finite, non negative f32 with `e` exponent and `m` mantissa bits, rounding to nearest
+// even. values past the largest finite one round up to the infinity pattern
+fn encode_unsigned(x: f32, e: u32, m: u32) -> u32 {
+    let bias = (1 << (e - 1)) - 1;
+    let bits = x.to_bits();
+    let exp = ((bits >> 23) & 0xff) as i32 - 127 + bias;
+    let mant = bits & 0x7f_ffff;
+
+    if exp <= 0 {
+        // denormal, the implicit one becomes explicit
+        shr_round(mant | 0x80_0000, (23 - m as i32 + 1 - exp) as u32)
+    } else if exp >= (1 << e) - 1 {
+        ((1 << e) - 1) << m
+    } else {
+        shr_round(((exp as u32) << 23) | mant, 23 - m)
+    }
+}
+
+fn decode_unsigned(v: u32, e: u32, m: u32) -> f32 {
+    let bias = (1 << (e - 1)) - 1;
+    let exp = (v >> m) as i32;
+    let mant = v & ((1 << m) - 1);
+
+    if exp == 0 {
+        mant as f32 * exp2i(1 - bias - m as i32)
+    } else if exp == (1 << e) - 1 {
+        if mant == 0 {
+            f32::INFINITY
+        } else {
+            f32::NAN
+        }
+    } else {
+        f32::from_bits((((exp - bias + 127) as u32) << 23) | (mant << (23 - m)))
+    }
+}
+
+// ieee binary16, rounds to nearest even and overflows to infinity
+pub fn f32_to_f16(x: f32) -> u16 {
+    let sign = ((x.to_bits() >> 16) & 0x8000) as u16;
+    if x.is_nan() {
+        return 0x7e00;
+    }
+    if x.is_infinite() {
+        return sign | 0x7c00;
+    }
+    sign | encode_unsigned(x.abs(), 5, 10) as u16
+}
+
+pub fn f16_to_f32(h: u16) -> f32 {
+    let magnitude = decode_unsigned((h & 0x7fff) as u32, 5, 10);
+    if h & 0x8000 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+// unsigned 11 and 10 bit floats for the packed hdr color format, negatives and nan go to zero and values past the
+// largest finite one saturate
+fn encode_color(x: f32, m: u32) -> u32 {
+    let max = ((0x1e << m) | ((1 << m) - 1)) as u32;
+    if x.is_nan() || x <= 0.0 {
+        0
+    } else {
+        encode_unsigned(x.min(f32::MAX), 5, m).min(max)
+    }
+}
+
+// vectors
+// -----------------------------------------------------------------------------------------------------------
+
+impl Vec2<f32> {
+    pub fn to_half(&self) -> [u16; 2] {
+        self.0.map(f32_to_f16)
+    }
+
+    pub fn from_half(h: [u16; 2]) -> Self {
+        Self(h.map(f16_to_f32))
+    }
+}
+
+impl Vec3<f32> {
+    pub fn to_half(&self) -> [u16; 3] {
+        self.0.map(f32_to_f16)
+    }
+
+    pub fn from_half(h: [u16; 3]) -> Self {
+        Self(h.map(f16_to_f32))
+    }
+
+    // r in the low 11 bits, g in the next 11 and b in the top 10
+    pub fn to_r11g11b10(&self) -> u32 {
+        encode_color(self[0], 6) | (encode_color(self[1], 6) << 11) | (encode_color(self[2], 5) << 22)
+    }
+
+    pub fn from_r11g11b10(bits: u32) -> Self {
+        Self::new(
+            decode_unsigned(bits & 0x7ff, 5, 6),
+            decode_unsigned((bits >> 11) & 0x7ff, 5, 6),
+            decode_unsigned(bits >> 22, 5, 5),
+        )
+    }
+
+    // three 9 bit mantissas sharing a 5 bit exponent in the top bits, as in EXT_texture_shared_exponent
+    pub fn to_rgb9e5(&self) -> u32 {
+        const MAX: f32 = 65408.0;
+        let c = self.0.map(|c| if c.is_nan() { 0.0 } else { c.clamp(0.0, MAX) });
+        let max_c = c[0].max(c[1]).max(c[2]);
+
+        let floor_log2 = ((max_c.to_bits() >> 23) & 0xff) as i32 - 127;
+        let mut exp = floor_log2.max(-16) + 16;
+        if (max_c / exp2i(exp - 24) + 0.5) as u32 == 512 {
+            exp += 1;
+        }
+
+        let scale = exp2i(exp - 24);
+        let [r, g, b] = c.map(|c| (c / scale + 0.5) as u32);
+        r | (g << 9) | (b << 18) | ((exp as u32) << 27)
+    }
+
+    pub fn from_rgb9e5(bits: u32) -> Self {
+        let scale = exp2i((bits >> 27) as i32 - 24);
+        Self::new(
+            (bits & 0x1ff) as f32 * scale,
+            ((bits >> 9) & 0x1ff) as f32 * scale,
+            ((bits >> 18) & 0x1ff) as f32 * scale,
+        )
+    }
+}
+
+impl Vec4<f32> {
+    pub fn to_half(&self) -> [u16; 4] {
+        self.0.map(f32_to_f16)
+    }
+
+    pub fn from_half(h: [u16; 4]) -> Self {
+        Self(h.map(f16_to_f32))
+    }
+}
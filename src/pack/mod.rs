@@ -0,0 +1,256 @@
+mod bits;
+mod float;
+mod norm;
+mod octahedral;
+mod quantize;
+pub use bits::*;
+pub use float::{f16_to_f32, f32_to_f16};
+pub use quantize::*;
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    // fibonacci sphere, evenly spread unit vectors
+    fn directions() -> impl Iterator<Item = Vec3<f32>> {
+        let n = 20000;
+        (0..n).map(move |i| {
+            let z = 1.0 - (2 * i + 1) as f64 / n as f64;
+            let r = (1.0 - z * z).sqrt();
+            let (sin, cos) = (i as f64 * 2.399963229728653).sin_cos();
+            Vec3::<f32>::new((r * cos) as f32, (r * sin) as f32, z as f32)
+        })
+    }
+
+    fn angle(a: Vec3<f32>, b: Vec3<f32>) -> f32 {
+        a.cross(b).length().atan2(a.dot(b)).to_degrees()
+    }
+
+    #[test]
+    fn test_pack_octahedral() {
+        let (mut max16, mut max8) = (0.0f32, 0.0f32);
+        for d in directions() {
+            max16 = max16.max(angle(d, Vec3::<f32>::from_oct16(d.to_oct16())));
+            max8 = max8.max(angle(d, Vec3::<f32>::from_oct8(d.to_oct8())));
+        }
+        assert!(max16 < 0.005, "{}", max16);
+        assert!(max8 < 1.0, "{}", max8);
+
+        for axis in [
+            Vec3::<f32>::unit_x(),
+            -Vec3::<f32>::unit_y(),
+            Vec3::<f32>::unit_z(),
+            -Vec3::<f32>::unit_z(),
+        ] {
+            assert_eq!(Vec3::<f32>::from_oct16(axis.to_oct16()), axis);
+            assert_eq!(Vec3::<f32>::from_oct8(axis.to_oct8()), axis);
+        }
+    }
+
+    #[test]
+    fn test_pack_norm() {
+        let v = Vec4::<f32>::new(1.0, 0.0, 0.5, -2.0);
+        assert_eq!(v.to_unorm8(), 0x0080_00ff);
+        assert_eq!(v.to_snorm8(), 0x8140_007f);
+        assert_eq!(
+            Vec4::<f32>::from_snorm8(0x8080_0000),
+            Vec4::<f32>::new(0.0, 0.0, -1.0, -1.0)
+        );
+
+        for i in 0..=1000 {
+            let c = i as f32 / 500.0 - 1.0;
+            let v = Vec4::<f32>::new(c, -c, c * 0.3, 0.7);
+            let u = Vec4::<f32>::new(c.abs(), 1.0 - c.abs(), 0.25, c * c);
+            let max_err = |a: Vec4<f32>, b: Vec4<f32>| (0..4).map(|i| (a[i] - b[i]).abs()).fold(0.0, f32::max);
+            assert!(max_err(v, Vec4::<f32>::from_snorm8(v.to_snorm8())) <= 0.5 / 127.0 + 1e-6);
+            assert!(max_err(v, Vec4::<f32>::from_snorm16(v.to_snorm16())) <= 0.5 / 32767.0 + 1e-7);
+            assert!(max_err(u, Vec4::<f32>::from_unorm8(u.to_unorm8())) <= 0.5 / 255.0 + 1e-6);
+            assert!(max_err(u, Vec4::<f32>::from_unorm16(u.to_unorm16())) <= 0.5 / 65535.0 + 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_pack_half() {
+        assert_eq!(f32_to_f16(1.0), 0x3c00);
+        assert_eq!(f32_to_f16(-2.0), 0xc000);
+        assert_eq!(f32_to_f16(0.1), 0x2e66);
+        assert_eq!(f32_to_f16(65504.0), 0x7bff);
+        assert_eq!(f32_to_f16(65520.0), 0x7c00);
+        assert_eq!(f32_to_f16(5.960464e-8), 0x0001);
+        assert_eq!(f32_to_f16(2.0e-8), 0x0000);
+        assert_eq!(f32_to_f16(1.0 + 1.0 / 2048.0), 0x3c00); // tie goes to even
+        assert_eq!(f32_to_f16(1.0 + 3.0 / 2048.0), 0x3c02);
+        assert_eq!(f32_to_f16(f32::NEG_INFINITY), 0xfc00);
+        assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+
+        // every finite half survives the round trip
+        for h in 0..=u16::MAX {
+            if h & 0x7c00 != 0x7c00 {
+                assert_eq!(f32_to_f16(f16_to_f32(h)), h);
+            }
+        }
+
+        let v = Vec3::<f32>::new(2.71, -0.001, 1000.0);
+        let back = Vec3::<f32>::from_half(v.to_half());
+        for i in 0..3 {
+            assert!((back[i] - v[i]).abs() <= v[i].abs() / 2048.0);
+        }
+        assert_eq!(
+            Vec2::<f32>::from_half(Vec2::<f32>::new(0.5, 8.0).to_half()),
+            Vec2::<f32>::new(0.5, 8.0)
+        );
+        assert_eq!(Vec4::<f32>::one().to_half(), [0x3c00; 4]);
+    }
+
+    #[test]
+    fn test_pack_hdr_color() {
+        assert_eq!(Vec3::<f32>::one().to_rgb9e5(), 0x8402_0100);
+        assert_eq!(Vec3::<f32>::from_rgb9e5(0x8402_0100), Vec3::<f32>::one());
+        assert_eq!(Vec3::<f32>::new(-1.0, f32::NAN, 1e9).to_r11g11b10(), 0xf7c0_0000);
+        assert_eq!(
+            Vec3::<f32>::from_r11g11b10(Vec3::<f32>::one().to_r11g11b10()),
+            Vec3::<f32>::one()
+        );
+
+        for i in 0..=1000 {
+            let t = i as f32 / 1000.0;
+            let c = Vec3::<f32>::new(t * 40.0, 0.5 + t * t, 0.02 * (1.0 - t));
+            let max_c = c[0].max(c[1]).max(c[2]);
+
+            // the shared exponent puts the error relative to the largest channel
+            let e = Vec3::<f32>::from_rgb9e5(c.to_rgb9e5());
+            for i in 0..3 {
+                assert!((e[i] - c[i]).abs() <= max_c / 512.0, "{} {}", e[i], c[i]);
+            }
+
+            // 6 and 5 bit mantissas, half an ulp relative to each channel or half the smallest denormal
+            let f = Vec3::<f32>::from_r11g11b10(c.to_r11g11b10());
+            assert!((f[0] - c[0]).abs() <= c[0] / 128.0);
+            assert!((f[1] - c[1]).abs() <= c[1] / 128.0);
+            assert!((f[2] - c[2]).abs() <= c[2] / 64.0 + 0.5f32.powi(20));
+        }
+    }
+
+    // angle of the rotation taking a to b, from the sine side since acos loses too much near 1
+    fn quat_angle(a: Quat<f32>, b: Quat<f32>) -> f32 {
+        let d = a.conjugate() * b;
+        (2.0 * Vec3::<f32>::new(d[0], d[1], d[2]).length().atan2(d[3].abs())).to_degrees()
+    }
+
+    #[test]
+    fn test_pack_smallest_three() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for (precision, bound) in [
+            (QuatPrecision::Bits29, 0.5),
+            (QuatPrecision::Bits32, 0.25),
+            (QuatPrecision::Bits48, 0.01),
+        ] {
+            let mut max = 0.0f32;
+            for _ in 0..20000 {
+                let q = Quat::<f32>::new(
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                )
+                .normalized();
+                let packed = q.to_smallest_three(precision);
+                assert!(packed < 1 << precision.bits());
+                max = max.max(quat_angle(q, Quat::<f32>::from_smallest_three(packed, precision)));
+            }
+            assert!(max < bound, "{:?} {}", precision, max);
+        }
+
+        // the sign flip and a dropped component that is not the last one
+        let q = Quat::<f32>::new(0.0, -1.0, 0.0, 0.0);
+        let back =
+            Quat::<f32>::from_smallest_three(q.to_smallest_three(QuatPrecision::Bits32), QuatPrecision::Bits32);
+        assert_eq!(back, -q);
+        let id = Quat::<f32>::identity();
+        assert_eq!(
+            Quat::<f32>::from_smallest_three(id.to_smallest_three(QuatPrecision::Bits29), QuatPrecision::Bits29),
+            id
+        );
+    }
+
+    #[test]
+    fn test_pack_position() {
+        let bounds = Aabb3::<f32>::new(
+            Vec3::<f32>::new(-512.0, 0.0, -512.0),
+            Vec3::<f32>::new(512.0, 64.0, 512.0),
+        );
+        let quantizer = PositionQuantizer::with_resolution(bounds, 0.01);
+        assert_eq!(quantizer.axis_bits(), [17, 13, 17]);
+        assert_eq!(quantizer.bits(), 47);
+        let step = quantizer.step();
+        assert!(step[0] <= 0.01 && step[1] <= 0.01 && step[2] <= 0.01);
+
+        let mut max = 0.0f32;
+        for i in 0..=10000 {
+            let t = i as f32 / 10000.0;
+            let p = Vec3::<f32>::new(t * 1024.0 - 512.0, 64.0 * t * t, 300.0 * (t * 20.0).sin());
+            let packed = quantizer.encode(p);
+            assert!(packed < 1 << quantizer.bits());
+            let back = quantizer.decode(packed);
+            for a in 0..3 {
+                max = max.max((back[a] - p[a]).abs() / step[a]);
+            }
+        }
+        // half a step, plus rounding of f32 positions a few hundred units out
+        assert!(max <= 0.51, "{}", max);
+
+        // outside points clamp and the corners are exact
+        assert_eq!(
+            quantizer.decode(quantizer.encode(Vec3::<f32>::new(-1000.0, 100.0, 0.0)))[0],
+            -512.0
+        );
+        assert_eq!(
+            quantizer.decode(quantizer.encode(Vec3::<f32>::new(-1000.0, 100.0, 0.0)))[1],
+            64.0
+        );
+        assert_eq!(quantizer.decode(quantizer.encode(bounds.max())), bounds.max());
+
+        // flat axes take no bits
+        let flat = PositionQuantizer::new(
+            Aabb3::<f32>::new(Vec3::<f32>::zero(), Vec3::<f32>::new(1.0, 0.0, 1.0)),
+            [8, 0, 8],
+        );
+        assert_eq!(
+            flat.decode(flat.encode(Vec3::<f32>::new(0.5, 3.0, 1.0))),
+            Vec3::<f32>::new(128.0 / 255.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_pack_bits() {
+        let q = Quat::<f32>::from_rotation_axis(Rad(0.7), Vec3::<f32>::new(0.0, 0.6, 0.8));
+        let mut writer = BitWriter::new();
+        writer.write_bool(true);
+        writer.write_bits(q.to_smallest_three(QuatPrecision::Bits29), 29);
+        writer.write_bits(0x1234_5678_9abc_def0, 64);
+        writer.write_bits(0b101, 3);
+        writer.write_bits(0xff, 0);
+        assert_eq!(writer.bit_len(), 97);
+        let bytes = writer.into_bytes();
+        assert_eq!(bytes.len(), 13);
+        assert_eq!(bytes[0] & 1, 1);
+
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read_bool(), Some(true));
+        let back = Quat::<f32>::from_smallest_three(reader.read_bits(29).unwrap(), QuatPrecision::Bits29);
+        assert!(quat_angle(q, back) < 0.5);
+        assert_eq!(reader.read_bits(64), Some(0x1234_5678_9abc_def0));
+        assert_eq!(reader.read_bits(3), Some(0b101));
+        assert_eq!(reader.position(), 97);
+        assert_eq!(reader.remaining_bits(), 7);
+        assert_eq!(reader.read_bits(8), None);
+        assert_eq!(reader.read_bits(7), Some(0));
+        assert_eq!(reader.read_bool(), None);
+    }
+}
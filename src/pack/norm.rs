@@ -0,0 +1,48 @@
+use crate::*;
+
+// normalized integers, following glsl packSnorm4x8 / packUnorm4x8 with x in the lowest bits
+impl Vec4<f32> {
+    fn quantize(&self, lo: f32, hi: f32, scale: f32) -> [i32; 4] {
+        self.0.map(|c| Float::round(c.clamp(lo, hi) * scale) as i32)
+    }
+
+    pub fn to_snorm8(&self) -> u32 {
+        let q = self.quantize(-1.0, 1.0, 127.0);
+        (0..4).fold(0, |packed, i| packed | ((q[i] as u8 as u32) << (i * 8)))
+    }
+
+    pub fn from_snorm8(bits: u32) -> Self {
+        Self(core::array::from_fn(|i| {
+            ((bits >> (i * 8)) as u8 as i8 as f32 / 127.0).max(-1.0)
+        }))
+    }
+
+    pub fn to_unorm8(&self) -> u32 {
+        let q = self.quantize(0.0, 1.0, 255.0);
+        (0..4).fold(0, |packed, i| packed | ((q[i] as u32) << (i * 8)))
+    }
+
+    pub fn from_unorm8(bits: u32) -> Self {
+        Self(core::array::from_fn(|i| ((bits >> (i * 8)) & 0xff) as f32 / 255.0))
+    }
+
+    pub fn to_snorm16(&self) -> u64 {
+        let q = self.quantize(-1.0, 1.0, 32767.0);
+        (0..4).fold(0, |packed, i| packed | ((q[i] as u16 as u64) << (i * 16)))
+    }
+
+    pub fn from_snorm16(bits: u64) -> Self {
+        Self(core::array::from_fn(|i| {
+            ((bits >> (i * 16)) as u16 as i16 as f32 / 32767.0).max(-1.0)
+        }))
+    }
+
+    pub fn to_unorm16(&self) -> u64 {
+        let q = self.quantize(0.0, 1.0, 65535.0);
+        (0..4).fold(0, |packed, i| packed | ((q[i] as u64) << (i * 16)))
+    }
+
+    pub fn from_unorm16(bits: u64) -> Self {
+        Self(core::array::from_fn(|i| ((bits >> (i * 16)) & 0xffff) as f32 / 65535.0))
+    }
+}
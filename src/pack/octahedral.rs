@@ -0,0 +1,46 @@
+use crate::*;
+
+// unit vectors folded onto an octahedron and unwrapped into the [-1, 1] square, then stored as two snorms with x
+// in the low bits. 2x16 is good to about 0.004 degrees and 2x8 to about 1 degree
+impl Vec3<f32> {
+    fn to_octahedral(self, scale: f32) -> [i32; 2] {
+        let n = self / (self[0].abs() + self[1].abs() + self[2].abs());
+        let sign = |c: f32| if c >= 0.0 { 1.0 } else { -1.0 };
+        let (x, y) = if n[2] >= 0.0 {
+            (n[0], n[1])
+        } else {
+            ((1.0 - n[1].abs()) * sign(n[0]), (1.0 - n[0].abs()) * sign(n[1]))
+        };
+        [x, y].map(|c| Float::round(c.clamp(-1.0, 1.0) * scale) as i32)
+    }
+
+    fn from_octahedral(x: f32, y: f32) -> Self {
+        let z = 1.0 - x.abs() - y.abs();
+        let t = (-z).max(0.0);
+        let x = if x >= 0.0 { x - t } else { x + t };
+        let y = if y >= 0.0 { y - t } else { y + t };
+        Self::new(x, y, z).normalized()
+    }
+
+    pub fn to_oct16(&self) -> u32 {
+        let [x, y] = self.to_octahedral(32767.0);
+        (x as u16 as u32) | ((y as u16 as u32) << 16)
+    }
+
+    pub fn from_oct16(bits: u32) -> Self {
+        let x = (bits as u16 as i16 as f32 / 32767.0).max(-1.0);
+        let y = ((bits >> 16) as u16 as i16 as f32 / 32767.0).max(-1.0);
+        Self::from_octahedral(x, y)
+    }
+
+    pub fn to_oct8(&self) -> u16 {
+        let [x, y] = self.to_octahedral(127.0);
+        (x as u8 as u16) | ((y as u8 as u16) << 8)
+    }
+
+    pub fn from_oct8(bits: u16) -> Self {
+        let x = (bits as u8 as i8 as f32 / 127.0).max(-1.0);
+        let y = ((bits >> 8) as u8 as i8 as f32 / 127.0).max(-1.0);
+        Self::from_octahedral(x, y)
+    }
+}
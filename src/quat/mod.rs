@@ -2,8 +2,11 @@ mod quat;
 pub use quat::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quat<S>(pub [S; 4]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Quat<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Quat<S> where S: bytemuck::Pod {}
 
 // test
@@ -23,4 +26,77 @@ mod tests {
         assert_eq!(q / 2.0, Quat::<f32>::new(0.5, 1.0, 1.5, 2.0));
         assert_eq!(-q, Quat::<f32>::new(-1.0, -2.0, -3.0, -4.0));
     }
+
+    #[test]
+    fn test_quat_swing_twist() {
+        let axis = |x: f64, y: f64, z: f64| Vec3::<f64>::new(x, y, z).normalized();
+        let swing = Quat::<f64>::from_rotation_axis(Deg(50.0), Vec3::<f64>::unit_x());
+        let twist = Quat::<f64>::from_rotation_axis(Deg(-120.0), Vec3::<f64>::unit_z());
+
+        // the decomposition is unique, so a swing around a perpendicular axis comes back out
+        let (s, t) = (swing * twist).swing_twist(Vec3::<f64>::unit_z());
+        assert_abs_diff_eq!(s, swing, epsilon = 1e-12);
+        assert_abs_diff_eq!(t, twist, epsilon = 1e-12);
+
+        let q = Quat::<f64>::from_rotation_axis(Rad(2.3), axis(-0.4, 0.9, 0.2));
+        let a = axis(0.3, 0.5, 0.8);
+        let (s, t) = q.swing_twist(a);
+        assert_abs_diff_eq!(s * t, q, epsilon = 1e-12);
+        assert_abs_diff_eq!(t * a, a, epsilon = 1e-12);
+        assert_abs_diff_eq!(Vec3::<f64>::new(s[0], s[1], s[2]).dot(a), 0.0, epsilon = 1e-12);
+
+        // a half turn around a perpendicular axis has no twist
+        let flip = Quat::<f64>::from_rotation_axis(Deg(180.0), Vec3::<f64>::unit_y());
+        assert_eq!(flip.swing_twist(Vec3::<f64>::unit_z()).1, Quat::<f64>::identity());
+
+        let (s, t) = Quat::<I32F32>::from_rotation_axis(Rad(I32F32::from_f64(0.8)), Vec3::<I32F32>::unit_z())
+            .swing_twist(Vec3::<I32F32>::unit_z());
+        assert_abs_diff_eq!(s, Quat::<I32F32>::identity(), epsilon = I32F32::from_f64(1e-6));
+        assert_abs_diff_eq!(t[2], I32F32::from_f64(0.4f64.sin()), epsilon = I32F32::from_f64(1e-6));
+    }
+
+    #[test]
+    fn test_quat_constraints() {
+        let z = Vec3::<f32>::unit_z();
+        let swing = |deg: f32| Quat::<f32>::from_rotation_axis(Deg(deg), Vec3::<f32>::unit_x());
+        let twist = |deg: f32| Quat::<f32>::from_rotation_axis(Deg(deg), z);
+        let q = swing(60.0) * twist(100.0);
+
+        assert_abs_diff_eq!(
+            q.clamp_twist(z, Deg(-45.0), Deg(45.0)),
+            swing(60.0) * twist(45.0),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(q.clamp_twist(z, Deg(-45.0), Deg(120.0)), q, epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            (swing(10.0) * twist(-170.0)).clamp_twist(z, Deg(-90.0), Deg(0.0)),
+            swing(10.0) * twist(-90.0),
+            epsilon = 1e-6
+        );
+
+        assert_abs_diff_eq!(q.clamp_swing(z, Deg(30.0)), swing(30.0) * twist(100.0), epsilon = 1e-6);
+        assert_eq!(q.clamp_swing(z, Deg(75.0)), q);
+
+        assert_abs_diff_eq!(q.clamp_hinge(z, Deg(0.0), Deg(180.0)), twist(100.0), epsilon = 1e-6);
+        assert_abs_diff_eq!(q.clamp_hinge(z, Deg(-20.0), Deg(20.0)), twist(20.0), epsilon = 1e-6);
+        // a hinge can't be bent sideways
+        let bent = q.clamp_hinge(z, Deg(-180.0), Deg(180.0)) * Vec3::<f32>::unit_x();
+        assert_abs_diff_eq!(bent[2], 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_quat_from_mat3() {
+        let axis = Vec3::<f64>::new(0.3, -0.5, 0.8).normalized();
+        // a half turn around each axis goes through every branch
+        for q in [
+            Quat::<f64>::from_rotation_axis(Deg(70.0), axis),
+            Quat::<f64>::from_rotation_axis(Deg(180.0), Vec3::<f64>::unit_x()),
+            Quat::<f64>::from_rotation_axis(Deg(180.0), Vec3::<f64>::unit_y()),
+            Quat::<f64>::from_rotation_axis(Deg(180.0), Vec3::<f64>::unit_z()),
+            Quat::<f64>::from_rotation_axis(Deg(-170.0), axis),
+        ] {
+            let m = Mat3::<f64>([Vec3::<f64>::unit_x(), Vec3::<f64>::unit_y(), Vec3::<f64>::unit_z()].map(|v| q * v));
+            assert_abs_diff_eq!(Quat::<f64>::from_mat3(m), q, epsilon = 1e-12);
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 macro_rules! impl_quat {
     ($t:ident) => {
@@ -41,11 +41,11 @@ macro_rules! impl_quat {
             }
 
             pub fn length(&self) -> $t {
-                self.dot(*self).sqrt()
+                Float::sqrt(self.dot(*self))
             }
 
             pub fn normalized(&self) -> Self {
-                if self.length() == 0.0 {
+                if self.length() == $t::zero() {
                     *self
                 } else {
                     *self / self.length()
@@ -60,16 +60,17 @@ macro_rules! impl_quat {
                 self.conjugate() / self.dot(*self)
             }
 
-            pub fn from_rotation_axis(rad: $t, axis: Vec3<$t>) -> Self {
+            pub fn from_rotation_axis(rad: impl Into<Rad<$t>>, axis: Vec3<$t>) -> Self {
+                let rad = rad.into().0;
                 let half_angle = rad * $t::splat(0.5);
-                let (sin, cos) = half_angle.sin_cos();
+                let (sin, cos) = Float::sin_cos(half_angle);
                 Self([axis[0] * sin, axis[1] * sin, axis[2] * sin, cos])
             }
 
             pub fn get_rotation_axis(&self) -> ($t, Vec3<$t>) {
                 let sin_sq = $t::one() - self[3] * self[3];
-                let angle = sin_sq.sqrt().atan2(self[3]) * 2.0;
-                let axis = Vec3::<$t>::new(self[0], self[1], self[2]) / sin_sq.sqrt();
+                let angle = Float::atan2(Float::sqrt(sin_sq), self[3]) * $t::splat(2.0);
+                let axis = Vec3::<$t>::new(self[0], self[1], self[2]) / Float::sqrt(sin_sq);
                 (angle, axis)
             }
 
@@ -79,13 +80,78 @@ macro_rules! impl_quat {
                 Self([x, y, z, w]).normalized()
             }
 
-            pub fn from_euler(roll: $t, pitch: $t, yaw: $t) -> Self {
+            // from a rotation matrix, whose columns are where the x, y and z axes end up
+            pub fn from_mat3(m: Mat3<$t>) -> Self {
+                let at = |row: usize, col: usize| m.0[col].0[row];
+                let (two, quarter) = ($t::splat(2.0), $t::splat(0.25));
+                let trace = at(0, 0) + at(1, 1) + at(2, 2);
+                let q = if trace > $t::zero() {
+                    let s = Float::sqrt(trace + $t::one()) * two;
+                    Self::new(
+                        (at(2, 1) - at(1, 2)) / s,
+                        (at(0, 2) - at(2, 0)) / s,
+                        (at(1, 0) - at(0, 1)) / s,
+                        s * quarter,
+                    )
+                } else if at(0, 0) > at(1, 1) && at(0, 0) > at(2, 2) {
+                    let s = Float::sqrt($t::one() + at(0, 0) - at(1, 1) - at(2, 2)) * two;
+                    Self::new(
+                        s * quarter,
+                        (at(0, 1) + at(1, 0)) / s,
+                        (at(0, 2) + at(2, 0)) / s,
+                        (at(2, 1) - at(1, 2)) / s,
+                    )
+                } else if at(1, 1) > at(2, 2) {
+                    let s = Float::sqrt($t::one() + at(1, 1) - at(0, 0) - at(2, 2)) * two;
+                    Self::new(
+                        (at(0, 1) + at(1, 0)) / s,
+                        s * quarter,
+                        (at(1, 2) + at(2, 1)) / s,
+                        (at(0, 2) - at(2, 0)) / s,
+                    )
+                } else {
+                    let s = Float::sqrt($t::one() + at(2, 2) - at(0, 0) - at(1, 1)) * two;
+                    Self::new(
+                        (at(0, 2) + at(2, 0)) / s,
+                        (at(1, 2) + at(2, 1)) / s,
+                        s * quarter,
+                        (at(1, 0) - at(0, 1)) / s,
+                    )
+                };
+                q.normalized()
+            }
+
+            // the rotation as a single vector, its axis scaled by its angle, taking the short way round
+            pub fn to_scaled_axis(&self) -> Vec3<$t> {
+                let q = if self[3] < $t::zero() { -*self } else { *self };
+                let v = Vec3::<$t>::new(q[0], q[1], q[2]);
+                let sin = v.length();
+                if sin == $t::zero() {
+                    Vec3::<$t>::zero()
+                } else {
+                    v * (Float::atan2(sin, q[3]) * $t::splat(2.0) / sin)
+                }
+            }
+
+            pub fn from_scaled_axis(v: Vec3<$t>) -> Self {
+                let angle = v.length();
+                if angle == $t::zero() {
+                    Self::identity()
+                } else {
+                    Self::from_rotation_axis(Rad(angle), v / angle)
+                }
+            }
+
+            pub fn from_euler(roll: impl Into<Rad<$t>>, pitch: impl Into<Rad<$t>>, yaw: impl Into<Rad<$t>>) -> Self {
+                let roll = roll.into().0;
+                let pitch = pitch.into().0;
+                let yaw = yaw.into().0;
                 let half_roll = roll * $t::splat(0.5);
                 let half_pitch = pitch * $t::splat(0.5);
                 let half_yaw = yaw * $t::splat(0.5);
-                let (sin_roll, cos_roll) = half_roll.sin_cos();
-                let (sin_pitch, cos_pitch) = half_pitch.sin_cos();
-                let (sin_yaw, cos_yaw) = half_yaw.sin_cos();
+                let (sin_roll, cos_roll) = Float::sin_cos(half_roll);
+                let (sin_pitch, cos_pitch) = Float::sin_cos(half_pitch);
+                let (sin_yaw, cos_yaw) = Float::sin_cos(half_yaw);
                 Self([
                     cos_roll * cos_pitch * cos_yaw + sin_roll * sin_pitch * sin_yaw,
                     sin_roll * cos_pitch * cos_yaw - cos_roll * sin_pitch * sin_yaw,
@@ -94,6 +160,55 @@ macro_rules! impl_quat {
                 ])
             }
 
+            // splits the rotation into a twist around `axis` and a swing around an axis perpendicular to it, so
+            // that self = swing * twist. `axis` has to be normalized
+            pub fn swing_twist(&self, axis: Vec3<$t>) -> (Self, Self) {
+                let v = Vec3::<$t>::new(self[0], self[1], self[2]);
+                let p = axis * v.dot(axis);
+                let twist = Self([p[0], p[1], p[2], self[3]]);
+                // a half turn around a perpendicular axis has no twist at all
+                let twist = if twist.length() == $t::zero() {
+                    Self::identity()
+                } else {
+                    twist.normalized()
+                };
+                (*self * twist.conjugate(), twist)
+            }
+
+            // angle of a twist around `axis`, taking the short way round so it lands in [-pi, pi]
+            fn twist_angle(&self, axis: Vec3<$t>) -> $t {
+                let t = if self[3] < $t::zero() { -*self } else { *self };
+                Float::atan2(Vec3::<$t>::new(t[0], t[1], t[2]).dot(axis), t[3]) * $t::splat(2.0)
+            }
+
+            // limits the twist around `axis` to the range, keeping the swing
+            pub fn clamp_twist(&self, axis: Vec3<$t>, min: impl Into<Rad<$t>>, max: impl Into<Rad<$t>>) -> Self {
+                let (swing, twist) = self.swing_twist(axis);
+                let angle = twist.twist_angle(axis).max(min.into().0).min(max.into().0);
+                swing * Self::from_rotation_axis(Rad(angle), axis)
+            }
+
+            // limits the swing away from `axis` to a cone of `max` around it, keeping the twist
+            pub fn clamp_swing(&self, axis: Vec3<$t>, max: impl Into<Rad<$t>>) -> Self {
+                let max = max.into().0;
+                let (swing, twist) = self.swing_twist(axis);
+                let swing = if swing[3] < $t::zero() { -swing } else { swing };
+                let v = Vec3::<$t>::new(swing[0], swing[1], swing[2]);
+                let angle = Float::atan2(v.length(), swing[3]) * $t::splat(2.0);
+                if angle <= max {
+                    *self
+                } else {
+                    Self::from_rotation_axis(Rad(max), v.normalized()) * twist
+                }
+            }
+
+            // drops the swing so only the rotation around `axis` is left, limited to the range
+            pub fn clamp_hinge(&self, axis: Vec3<$t>, min: impl Into<Rad<$t>>, max: impl Into<Rad<$t>>) -> Self {
+                let (_, twist) = self.swing_twist(axis);
+                let angle = twist.twist_angle(axis).max(min.into().0).min(max.into().0);
+                Self::from_rotation_axis(Rad(angle), axis)
+            }
+
             pub fn to_mat3(&self) -> Mat3<$t> {
                 let [x, y, z, w] = self.0;
                 let xx = x * x;
@@ -125,8 +240,8 @@ macro_rules! impl_quat {
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Quat<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for Quat<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 write!(f, "(x, y, z, w): [{:>8.4}, {:>8.4}, {:>8.4}, {:>8.4}]", self[0], self[1], self[2], self[3])
             }
         }
@@ -273,3 +388,5 @@ macro_rules! impl_quat {
 
 impl_quat!(f32);
 impl_quat!(f64);
+impl_quat!(I16F16);
+impl_quat!(I32F32);
@@ -6,13 +6,19 @@ pub use rot3::*;
 use crate::{Bivec2, Bivec3};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rot2<S>(pub S, pub Bivec2<S>);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Rot2<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Rot2<S> where S: bytemuck::Pod {}
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rot3<S>(pub S, pub Bivec3<S>);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Rot3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Rot3<S> where S: bytemuck::Pod {}
 
 // test
@@ -43,4 +49,35 @@ mod tests {
         assert_eq!(r / 2.0, Rot3::<f32>::new(0.5, Bivec3::<f32>::new(0.5, 1.0, 1.5)));
         assert_eq!(-r, Rot3::<f32>::new(-1.0, Bivec3::<f32>::new(-1.0, -2.0, -3.0)));
     }
+
+    #[test]
+    fn test_rot3_swing_twist() {
+        let z = Vec3::<f64>::unit_z();
+        let swing = |deg: f64| Rot3::<f64>::from_rotation_x(Deg(deg));
+        let twist = |deg: f64| Rot3::<f64>::from_rotation_z(Deg(deg));
+
+        let (s, t) = (swing(50.0) * twist(-120.0)).swing_twist(z);
+        assert_abs_diff_eq!(s, swing(50.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(t, twist(-120.0), epsilon = 1e-12);
+
+        let r = Rot3::<f64>::from_euler(Rad(0.3), Rad(-1.2), Rad(2.0));
+        let a = Vec3::<f64>::new(0.3, 0.5, 0.8).normalized();
+        let (s, t) = r.swing_twist(a);
+        assert_abs_diff_eq!(s * t, r, epsilon = 1e-12);
+        assert_abs_diff_eq!(t * a, a, epsilon = 1e-12);
+        assert_eq!(
+            Rot3::<f64>::from_rotation_y(Deg(180.0)).swing_twist(z).1,
+            Rot3::<f64>::identity()
+        );
+
+        let r = swing(60.0) * twist(100.0);
+        assert_abs_diff_eq!(
+            r.clamp_twist(z, Deg(-45.0), Deg(45.0)),
+            swing(60.0) * twist(45.0),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(r.clamp_swing(z, Deg(30.0)), swing(30.0) * twist(100.0), epsilon = 1e-12);
+        assert_eq!(r.clamp_swing(z, Deg(75.0)), r);
+        assert_abs_diff_eq!(r.clamp_hinge(z, Deg(-20.0), Deg(20.0)), twist(20.0), epsilon = 1e-12);
+    }
 }
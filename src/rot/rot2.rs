@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 macro_rules! impl_rot2 {
     ($t:ident) => {
@@ -33,7 +33,7 @@ macro_rules! impl_rot2 {
             }
 
             pub fn length(&self) -> $t {
-                self.dot(*self).sqrt()
+                Float::sqrt(self.dot(*self))
             }
 
             pub fn normalized(&self) -> Self {
@@ -49,17 +49,18 @@ macro_rules! impl_rot2 {
             }
 
             #[deprecated(note = "Use from_rotation instead")]
-            pub fn from_rotation_xy(rad: $t) -> Self {
+            pub fn from_rotation_xy(rad: impl Into<Rad<$t>>) -> Self {
                 Self::from_rotation_bi(rad, Bivec2::<$t>::unit_xy())
             }
 
-            pub fn from_rotation(rad: $t) -> Self {
+            pub fn from_rotation(rad: impl Into<Rad<$t>>) -> Self {
                 Self::from_rotation_bi(rad, Bivec2::<$t>::unit_xy())
             }
 
-            pub fn from_rotation_bi(rad: $t, bi: Bivec2<$t>) -> Self {
+            pub fn from_rotation_bi(rad: impl Into<Rad<$t>>, bi: Bivec2<$t>) -> Self {
+                let rad = rad.into().0;
                 let half_angle = rad * $t::splat(0.5);
-                let (sin, cos) = half_angle.sin_cos();
+                let (sin, cos) = Float::sin_cos(half_angle);
                 Self(cos, bi * -sin)
             }
 
@@ -83,17 +84,17 @@ macro_rules! impl_rot2 {
             }
 
             pub fn to_quat(&self) -> Quat<$t> {
-                let half_angle = self.0.acos();
-                let sin = half_angle.sin();
+                let half_angle = Float::acos(self.0);
+                let sin = Float::sin(half_angle);
                 let axis = self.1.normalized();
-                Quat::<$t>::new(axis[0] * sin, axis[1] * sin, $t::zero(), half_angle.cos())
+                Quat::<$t>::new(axis[0] * sin, axis[1] * sin, $t::zero(), Float::cos(half_angle))
             }
         }
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Rot2<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Display for Rot2<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 let xy = self.1[0];
                 write!(f, "(xy, s): [{:>8.4}, {:>8.4}]", xy, self.0)
             }
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 macro_rules! impl_rot3 {
     ($t:ident) => {
@@ -33,11 +33,11 @@ macro_rules! impl_rot3 {
             }
 
             pub fn length(&self) -> $t {
-                self.dot(*self).sqrt()
+                Float::sqrt(self.dot(*self))
             }
 
             pub fn normalized(&self) -> Self {
-                if self.length() == 0.0 {
+                if self.length() == $t::zero() {
                     *self
                 } else {
                     *self / self.length()
@@ -49,35 +49,40 @@ macro_rules! impl_rot3 {
             }
 
             #[deprecated(note = "Use from_rotation_z instead")]
-            pub fn from_rotation_xy(rad: $t) -> Self {
-                Self::from_rotation_bi(-rad, Bivec3::<$t>::unit_xy())
+            pub fn from_rotation_xy(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                Self::from_rotation_bi(Rad(-rad), Bivec3::<$t>::unit_xy())
             }
 
             #[deprecated(note = "Use from_rotation_y instead")]
-            pub fn from_rotation_xz(rad: $t) -> Self {
+            pub fn from_rotation_xz(rad: impl Into<Rad<$t>>) -> Self {
                 Self::from_rotation_bi(rad, Bivec3::<$t>::unit_xz())
             }
 
             #[deprecated(note = "Use from_rotation_x instead")]
-            pub fn from_rotation_yz(rad: $t) -> Self {
-                Self::from_rotation_bi(-rad, Bivec3::<$t>::unit_yz())
+            pub fn from_rotation_yz(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                Self::from_rotation_bi(Rad(-rad), Bivec3::<$t>::unit_yz())
             }
 
-            pub fn from_rotation_x(rad: $t) -> Self {
-                Self::from_rotation_bi(-rad, Bivec3::<$t>::unit_yz())
+            pub fn from_rotation_x(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                Self::from_rotation_bi(Rad(-rad), Bivec3::<$t>::unit_yz())
             }
 
-            pub fn from_rotation_y(rad: $t) -> Self {
+            pub fn from_rotation_y(rad: impl Into<Rad<$t>>) -> Self {
                 Self::from_rotation_bi(rad, Bivec3::<$t>::unit_xz())
             }
 
-            pub fn from_rotation_z(rad: $t) -> Self {
-                Self::from_rotation_bi(-rad, Bivec3::<$t>::unit_xy())
+            pub fn from_rotation_z(rad: impl Into<Rad<$t>>) -> Self {
+                let rad = rad.into().0;
+                Self::from_rotation_bi(Rad(-rad), Bivec3::<$t>::unit_xy())
             }
 
-            pub fn from_rotation_bi(rad: $t, bi: Bivec3<$t>) -> Self {
+            pub fn from_rotation_bi(rad: impl Into<Rad<$t>>, bi: Bivec3<$t>) -> Self {
+                let rad = rad.into().0;
                 let half_angle = rad * $t::splat(0.5);
-                let (sin, cos) = half_angle.sin_cos();
+                let (sin, cos) = Float::sin_cos(half_angle);
                 Self(cos, bi * -sin).normalized()
             }
 
@@ -85,10 +90,98 @@ macro_rules! impl_rot3 {
                 Self::new($t::one() + to.dot(from), to.wedge(from)).normalized()
             }
 
-            pub fn from_euler(roll: $t, pitch: $t, yaw: $t) -> Self {
+            // the rotation as a single vector, its axis scaled by its angle, taking the short way round
+            pub fn to_scaled_axis(&self) -> Vec3<$t> {
+                let r = if self.0 < $t::zero() { -*self } else { *self };
+                let v = r.axis_part();
+                let sin = v.length();
+                if sin == $t::zero() {
+                    Vec3::<$t>::zero()
+                } else {
+                    v * (Float::atan2(sin, r.0) * $t::splat(2.0) / sin)
+                }
+            }
+
+            pub fn from_scaled_axis(v: Vec3<$t>) -> Self {
+                let angle = v.length();
+                if angle == $t::zero() {
+                    Self::identity()
+                } else {
+                    Self::from_rotation_bi(Rad(angle), Self::axis_plane(v / angle))
+                }
+            }
+
+            pub fn from_euler(roll: impl Into<Rad<$t>>, pitch: impl Into<Rad<$t>>, yaw: impl Into<Rad<$t>>) -> Self {
                 Self::from_rotation_y(yaw) * Self::from_rotation_x(pitch) * Self::from_rotation_z(roll)
             }
 
+            // the plane `from_rotation_bi` needs to turn around `axis` the way `from_rotation_x`, `from_rotation_y`
+            // and `from_rotation_z` do, and the axis scaled by the sine of the half angle for a rotor
+            fn axis_plane(axis: Vec3<$t>) -> Bivec3<$t> {
+                Bivec3::<$t>::new(-axis[2], axis[1], -axis[0])
+            }
+
+            fn axis_part(&self) -> Vec3<$t> {
+                let [xy, xz, yz] = self.1 .0;
+                Vec3::<$t>::new(yz, -xz, xy)
+            }
+
+            // splits the rotation into a twist around `axis` and a swing around an axis perpendicular to it, so
+            // that self = swing * twist. `axis` has to be normalized
+            pub fn swing_twist(&self, axis: Vec3<$t>) -> (Self, Self) {
+                let p = axis * self.axis_part().dot(axis);
+                let twist = Self(self.0, Bivec3::<$t>::new(p[2], -p[1], p[0]));
+                // a half turn around a perpendicular axis has no twist at all
+                let twist = if twist.length() == $t::zero() {
+                    Self::identity()
+                } else {
+                    twist.normalized()
+                };
+                (*self * twist.reversed(), twist)
+            }
+
+            // angle of a twist around `axis`, taking the short way round so it lands in [-pi, pi]
+            fn twist_angle(&self, axis: Vec3<$t>) -> $t {
+                let t = if self.0 < $t::zero() {
+                    Self(-self.0, -self.1)
+                } else {
+                    *self
+                };
+                Float::atan2(t.axis_part().dot(axis), t.0) * $t::splat(2.0)
+            }
+
+            // limits the twist around `axis` to the range, keeping the swing
+            pub fn clamp_twist(&self, axis: Vec3<$t>, min: impl Into<Rad<$t>>, max: impl Into<Rad<$t>>) -> Self {
+                let (swing, twist) = self.swing_twist(axis);
+                let angle = twist.twist_angle(axis).max(min.into().0).min(max.into().0);
+                swing * Self::from_rotation_bi(Rad(angle), Self::axis_plane(axis))
+            }
+
+            // limits the swing away from `axis` to a cone of `max` around it, keeping the twist
+            pub fn clamp_swing(&self, axis: Vec3<$t>, max: impl Into<Rad<$t>>) -> Self {
+                let max = max.into().0;
+                let (swing, twist) = self.swing_twist(axis);
+                let swing = if swing.0 < $t::zero() {
+                    Self(-swing.0, -swing.1)
+                } else {
+                    swing
+                };
+                let v = swing.axis_part();
+                let angle = Float::atan2(v.length(), swing.0) * $t::splat(2.0);
+                if angle <= max {
+                    *self
+                } else {
+                    Self::from_rotation_bi(Rad(max), Self::axis_plane(v.normalized())) * twist
+                }
+            }
+
+            // drops the swing so only the rotation around `axis` is left, limited to the range
+            pub fn clamp_hinge(&self, axis: Vec3<$t>, min: impl Into<Rad<$t>>, max: impl Into<Rad<$t>>) -> Self {
+                let (_, twist) = self.swing_twist(axis);
+                let angle = twist.twist_angle(axis).max(min.into().0).min(max.into().0);
+                Self::from_rotation_bi(Rad(angle), Self::axis_plane(axis))
+            }
+
             pub fn to_mat3(&self) -> Mat3<$t> {
                 let s = self.0;
                 let bi = self.1;
@@ -129,8 +222,8 @@ macro_rules! impl_rot3 {
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Rot3<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for Rot3<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 let xy = self.1[0];
                 let xz = self.1[1];
                 let yz = self.1[2];
@@ -268,3 +361,5 @@ macro_rules! impl_rot3 {
 
 impl_rot3!(f32);
 impl_rot3!(f64);
+impl_rot3!(I16F16);
+impl_rot3!(I32F32);
@@ -0,0 +1,140 @@
+#[cfg(feature = "rand")]
+mod random;
+mod sequence;
+mod warp;
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn grid() -> impl Iterator<Item = Vec2<f64>> {
+        (0..32).flat_map(|i| (0..32).map(move |j| Vec2::<f64>::new(i as f64 / 32.0, j as f64 / 32.0)))
+    }
+
+    #[test]
+    fn test_sample_warps() {
+        let (a, b, c) = (
+            Vec2::<f64>::new(0.0, 0.0),
+            Vec2::<f64>::new(1.0, 0.0),
+            Vec2::<f64>::new(0.0, 1.0),
+        );
+
+        for u in grid() {
+            assert!((Vec3::<f64>::sample_sphere(u).length() - 1.0).abs() < 1e-12);
+            assert!(Vec2::<f64>::sample_disk(u).length() <= 1.0);
+            assert!(Vec2::<f64>::sample_disk_concentric(u).length() <= 1.0 + 1e-12);
+
+            let h = Vec3::<f64>::sample_hemisphere(u);
+            let ch = Vec3::<f64>::sample_cosine_hemisphere(u);
+            assert!(h[2] >= 0.0 && (h.length() - 1.0).abs() < 1e-12);
+            assert!(ch[2] >= 0.0 && (ch.length() - 1.0).abs() < 1e-12);
+
+            let t = Vec2::<f64>::sample_triangle(a, b, c, u);
+            assert!(t[0] >= 0.0 && t[1] >= 0.0 && t[0] + t[1] <= 1.0 + 1e-12);
+
+            let q = Quat::<f64>::sample_uniform(u.extended(u[0] * u[1]));
+            assert!((q.length() - 1.0).abs() < 1e-12);
+        }
+
+        assert_eq!(
+            Vec2::<f64>::sample_disk_concentric(Vec2::<f64>::new(0.5, 0.5)),
+            Vec2::<f64>::zero()
+        );
+        assert!(
+            (Vec2::<f64>::sample_disk_concentric(Vec2::<f64>::new(1.0, 0.5)) - Vec2::<f64>::unit_x()).length()
+                < 1e-12
+        );
+
+        let aabb = Aabb3::<f64>::new(Vec3::<f64>::new(-1.0, 0.0, 2.0), Vec3::<f64>::new(1.0, 4.0, 3.0));
+        assert_eq!(
+            aabb.sample(Vec3::<f64>::new(0.5, 0.25, 1.0)),
+            Vec3::<f64>::new(0.0, 1.0, 3.0)
+        );
+
+        let u = Vec3::<f64>::new(0.3, 0.6, 0.9);
+        let v = Vec3::<f64>::new(1.0, -2.0, 0.5);
+        assert!((Rot3::<f64>::sample_uniform(u).length() - 1.0).abs() < 1e-12);
+        assert!(((Rot3::<f64>::sample_uniform(u) * v).length() - v.length()).abs() < 1e-12);
+    }
+
+    // every 1d projection of the first 2^k points has exactly one point in each of the 2^k strata
+    fn stratified(values: impl Iterator<Item = f64>, k: u32) -> bool {
+        let mut strata = vec![false; 1 << k];
+        for v in values {
+            strata[(v * (1 << k) as f64) as usize] = true;
+        }
+        strata.iter().all(|&s| s)
+    }
+
+    #[test]
+    fn test_sample_sequences() {
+        assert_eq!(Vec2::<f32>::halton(1), Vec2::<f32>::new(0.5, 1.0 / 3.0));
+        assert_eq!(Vec2::<f64>::halton(3), Vec2::<f64>::new(0.75, 1.0 / 9.0));
+        assert_eq!(Vec3::<f64>::halton(2)[2], 0.4);
+        assert!(Vec2::<f32>::halton(43_046_720)[1] < 1.0);
+        assert_eq!(Vec2::<f32>::hammersley(4, 8), Vec2::<f32>::new(0.5, 0.125));
+
+        let sobol: Vec<Vec3<f64>> = (0..4).map(Vec3::<f64>::sobol).collect();
+        assert_eq!(
+            sobol,
+            [
+                Vec3::<f64>::new(0.0, 0.0, 0.0),
+                Vec3::<f64>::new(0.5, 0.5, 0.5),
+                Vec3::<f64>::new(0.25, 0.75, 0.75),
+                Vec3::<f64>::new(0.75, 0.25, 0.25),
+            ]
+        );
+
+        for k in 0..8 {
+            let n = 1u32 << k;
+            for d in 0..3 {
+                assert!(stratified((0..n).map(|i| Vec3::<f64>::sobol(i)[d]), k));
+                assert!(stratified((0..n).map(|i| Vec3::<f64>::sobol_owen(i, 42)[d]), k));
+                assert!(stratified((0..n).map(|i| Vec3::<f32>::sobol_owen(i, 7)[d] as f64), k));
+            }
+            assert!(stratified((0..n).map(|i| Vec2::<f64>::hammersley(i, n)[0]), k));
+            assert!(stratified((0..n).map(|i| Vec2::<f64>::hammersley(i, n)[1]), k));
+        }
+        assert_ne!(Vec2::<f32>::sobol_owen(5, 1), Vec2::<f32>::sobol_owen(5, 2));
+
+        let r2 = Vec2::<f64>::r_sequence(1);
+        assert!((r2 - Vec2::<f64>::new(0.2548776662, 0.0698402910)).length() < 1e-9);
+        for i in [0, 1, 1000, u32::MAX] {
+            let r = Vec3::<f32>::r_sequence(i);
+            assert!(r.0.iter().all(|v| (0.0..1.0).contains(v)));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_random() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let n = 20000;
+
+        let mut mean = Vec3::<f64>::zero();
+        let mut cos_mean = 0.0;
+        for _ in 0..n {
+            let v = Vec3::<f64>::random_unit(&mut rng);
+            assert!((v.length() - 1.0).abs() < 1e-12);
+            mean += v / n as f64;
+            cos_mean += Vec3::<f64>::random_cosine_hemisphere(&mut rng)[2] / n as f64;
+
+            assert!(Vec3::<f64>::random_in_sphere(&mut rng).length() <= 1.0);
+            assert!(Vec2::<f32>::random_in_disk(&mut rng).length() <= 1.0 + 1e-6);
+            assert!((Quat::<f32>::random(&mut rng).length() - 1.0).abs() < 1e-5);
+            assert!((Rot3::<f32>::random(&mut rng).length() - 1.0).abs() < 1e-5);
+
+            let aabb = Aabb2::<f32>::new(Vec2::<f32>::new(-1.0, 2.0), Vec2::<f32>::new(0.0, 5.0));
+            assert!(aabb.contains(aabb.random_point(&mut rng)));
+        }
+
+        // uniform directions average out, cosine weighted ones have E[cos] = 2/3
+        assert!(mean.length() < 0.02);
+        assert!((cos_mean - 2.0 / 3.0).abs() < 0.01);
+    }
+}
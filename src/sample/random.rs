@@ -0,0 +1,69 @@
+use crate::*;
+use rand::Rng;
+
+macro_rules! impl_random {
+    ($t:ident) => {
+        impl Vec2<$t> {
+            pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_circle(rng.gen())
+            }
+
+            pub fn random_in_disk<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_disk_concentric(Self::new(rng.gen(), rng.gen()))
+            }
+
+            pub fn random_in_triangle<R: Rng + ?Sized>(a: Self, b: Self, c: Self, rng: &mut R) -> Self {
+                Self::sample_triangle(a, b, c, Self::new(rng.gen(), rng.gen()))
+            }
+        }
+
+        impl Vec3<$t> {
+            pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_sphere(Vec2::<$t>::new(rng.gen(), rng.gen()))
+            }
+
+            pub fn random_in_sphere<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_ball(Self::new(rng.gen(), rng.gen(), rng.gen()))
+            }
+
+            pub fn random_hemisphere<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_hemisphere(Vec2::<$t>::new(rng.gen(), rng.gen()))
+            }
+
+            pub fn random_cosine_hemisphere<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_cosine_hemisphere(Vec2::<$t>::new(rng.gen(), rng.gen()))
+            }
+
+            pub fn random_in_triangle<R: Rng + ?Sized>(a: Self, b: Self, c: Self, rng: &mut R) -> Self {
+                Self::sample_triangle(a, b, c, Vec2::<$t>::new(rng.gen(), rng.gen()))
+            }
+        }
+
+        impl Quat<$t> {
+            pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_uniform(Vec3::<$t>::new(rng.gen(), rng.gen(), rng.gen()))
+            }
+        }
+
+        impl Rot3<$t> {
+            pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::sample_uniform(Vec3::<$t>::new(rng.gen(), rng.gen(), rng.gen()))
+            }
+        }
+
+        impl Aabb2<$t> {
+            pub fn random_point<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<$t> {
+                self.sample(Vec2::<$t>::new(rng.gen(), rng.gen()))
+            }
+        }
+
+        impl Aabb3<$t> {
+            pub fn random_point<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3<$t> {
+                self.sample(Vec3::<$t>::new(rng.gen(), rng.gen(), rng.gen()))
+            }
+        }
+    };
+}
+
+impl_random!(f32);
+impl_random!(f64);
@@ -0,0 +1,172 @@
+use crate::*;
+
+// integer parts shared by the f32 and f64 sequences
+// -----------------------------------------------------------------------------------------------------------
+
+// joe-kuo direction numbers for the second and third sobol dimensions, the first is the van der corput sequence
+const SOBOL_MATRICES: [[u32; 32]; 2] = [sobol_matrix(1, 0, &[1]), sobol_matrix(2, 1, &[1, 3])];
+
+const fn sobol_matrix(s: usize, a: u32, m: &[u32]) -> [u32; 32] {
+    let mut v = [0; 32];
+    let mut k = 0;
+    while k < 32 {
+        if k < s {
+            v[k] = m[k] << (31 - k);
+        } else {
+            v[k] = v[k - s] ^ (v[k - s] >> s);
+            let mut l = 1;
+            while l < s {
+                v[k] ^= ((a >> (s - 1 - l)) & 1) * v[k - l];
+                l += 1;
+            }
+        }
+        k += 1;
+    }
+    v
+}
+
+fn sobol(dimension: usize, index: u32) -> u32 {
+    if dimension == 0 {
+        return index.reverse_bits();
+    }
+
+    let matrix = &SOBOL_MATRICES[dimension - 1];
+    (0..32)
+        .filter(|bit| (index >> bit) & 1 == 1)
+        .fold(0, |x, bit| x ^ matrix[bit])
+}
+
+// burley's hash based nested uniform scramble
+fn owen_scramble(x: u32, seed: u32) -> u32 {
+    let mut x = x.reverse_bits();
+    x ^= x.wrapping_mul(0x3d20adea);
+    x = x.wrapping_add(seed);
+    x = x.wrapping_mul((seed >> 16) | 1);
+    x ^= x.wrapping_mul(0x05526c56);
+    x ^= x.wrapping_mul(0x53a22864);
+    x.reverse_bits()
+}
+
+fn hash_seed(seed: u32, dimension: u32) -> u32 {
+    let mut h = seed ^ dimension.wrapping_mul(0x9e3779b9);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846ca68b);
+    h ^ (h >> 16)
+}
+
+fn sobol_owen(dimension: usize, index: u32, seed: u32) -> u32 {
+    let index = owen_scramble(index, hash_seed(seed, u32::MAX));
+    owen_scramble(sobol(dimension, index), hash_seed(seed, dimension as u32))
+}
+
+// roberts' generalized golden ratio sequence in 0.32 fixed point, wraps exactly instead of losing precision
+fn r_sequence(alpha: u32, index: u32) -> u32 {
+    0x8000_0000u32.wrapping_add(alpha.wrapping_mul(index))
+}
+
+const R2: [u32; 2] = [3242174889, 2447445414];
+const R3: [u32; 3] = [3518319155, 2882110345, 2360945575];
+
+macro_rules! impl_sequence {
+    ($t:ident, $m:ident) => {
+        mod $m {
+            use super::*;
+
+            // keeps as many high bits as the mantissa holds so the result stays below 1.0
+            fn unit(x: u32) -> $t {
+                let bits = $t::MANTISSA_DIGITS.min(32);
+                (x >> (32 - bits)) as $t / (1u64 << bits) as $t
+            }
+
+            fn radical_inverse(base: u32, mut index: u32) -> $t {
+                if base == 2 {
+                    return unit(index.reverse_bits());
+                }
+
+                let inv = $t::one() / base as $t;
+                let mut factor = inv;
+                let mut result = $t::zero();
+                while index > 0 {
+                    result += (index % base) as $t * factor;
+                    index /= base;
+                    factor *= inv;
+                }
+                // with enough leading digits of base - 1 the sum rounds up to 1.0, so keep it just below like `unit` does
+                result.min($t::one() - $t::EPSILON * 0.5)
+            }
+
+            impl Vec2<$t> {
+                // bases 2 and 3
+                pub fn halton(index: u32) -> Self {
+                    Self::new(radical_inverse(2, index), radical_inverse(3, index))
+                }
+
+                pub fn hammersley(index: u32, count: u32) -> Self {
+                    Self::new(index as $t / count as $t, radical_inverse(2, index))
+                }
+
+                pub fn sobol(index: u32) -> Self {
+                    Self::new(unit(sobol(0, index)), unit(sobol(1, index)))
+                }
+
+                pub fn sobol_owen(index: u32, seed: u32) -> Self {
+                    Self::new(unit(sobol_owen(0, index, seed)), unit(sobol_owen(1, index, seed)))
+                }
+
+                // r2
+                pub fn r_sequence(index: u32) -> Self {
+                    Self::new(unit(r_sequence(R2[0], index)), unit(r_sequence(R2[1], index)))
+                }
+            }
+
+            impl Vec3<$t> {
+                // bases 2, 3 and 5
+                pub fn halton(index: u32) -> Self {
+                    Self::new(
+                        radical_inverse(2, index),
+                        radical_inverse(3, index),
+                        radical_inverse(5, index),
+                    )
+                }
+
+                pub fn hammersley(index: u32, count: u32) -> Self {
+                    Self::new(
+                        index as $t / count as $t,
+                        radical_inverse(2, index),
+                        radical_inverse(3, index),
+                    )
+                }
+
+                pub fn sobol(index: u32) -> Self {
+                    Self::new(
+                        unit(sobol(0, index)),
+                        unit(sobol(1, index)),
+                        unit(sobol(2, index)),
+                    )
+                }
+
+                pub fn sobol_owen(index: u32, seed: u32) -> Self {
+                    Self::new(
+                        unit(sobol_owen(0, index, seed)),
+                        unit(sobol_owen(1, index, seed)),
+                        unit(sobol_owen(2, index, seed)),
+                    )
+                }
+
+                // r3
+                pub fn r_sequence(index: u32) -> Self {
+                    Self::new(
+                        unit(r_sequence(R3[0], index)),
+                        unit(r_sequence(R3[1], index)),
+                        unit(r_sequence(R3[2], index)),
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_sequence!(f32, sequence_f32);
+impl_sequence!(f64, sequence_f64);
@@ -0,0 +1,114 @@
+use crate::*;
+
+// mappings from uniform samples in [0, 1) to uniformly distributed points on shapes, usable with both random
+// and low-discrepancy samples
+macro_rules! impl_warp {
+    ($t:ident, $m:ident) => {
+        mod $m {
+            use super::*;
+
+            impl Vec2<$t> {
+                pub fn sample_circle(u: $t) -> Self {
+                    let (sin, cos) = Float::sin_cos(u * core::$t::consts::TAU);
+                    Self::new(cos, sin)
+                }
+
+                pub fn sample_disk(u: Vec2<$t>) -> Self {
+                    Self::sample_circle(u[1]) * Float::sqrt(u[0])
+                }
+
+                // shirley-chiu mapping, preserves stratification and adjacency of the input samples
+                pub fn sample_disk_concentric(u: Vec2<$t>) -> Self {
+                    let o = u * $t::splat(2.0) - Self::one();
+                    if o[0] == $t::zero() && o[1] == $t::zero() {
+                        return Self::zero();
+                    }
+
+                    let quarter_pi = core::$t::consts::FRAC_PI_4;
+                    let (r, theta) = if o[0].abs() > o[1].abs() {
+                        (o[0], quarter_pi * (o[1] / o[0]))
+                    } else {
+                        (o[1], core::$t::consts::FRAC_PI_2 - quarter_pi * (o[0] / o[1]))
+                    };
+                    let (sin, cos) = Float::sin_cos(theta);
+                    Self::new(cos, sin) * r
+                }
+
+                pub fn sample_triangle(a: Self, b: Self, c: Self, u: Vec2<$t>) -> Self {
+                    let [b0, b1, b2] = sample_barycentric(u);
+                    a * b0 + b * b1 + c * b2
+                }
+            }
+
+            impl Vec3<$t> {
+                pub fn sample_sphere(u: Vec2<$t>) -> Self {
+                    let z = $t::one() - $t::splat(2.0) * u[0];
+                    let r = Float::sqrt(($t::one() - z * z).max($t::zero()));
+                    (Vec2::<$t>::sample_circle(u[1]) * r).extended(z)
+                }
+
+                pub fn sample_ball(u: Vec3<$t>) -> Self {
+                    Self::sample_sphere(u.truncated()) * Float::cbrt(u[2])
+                }
+
+                // around +z
+                pub fn sample_hemisphere(u: Vec2<$t>) -> Self {
+                    let z = u[0];
+                    let r = Float::sqrt(($t::one() - z * z).max($t::zero()));
+                    (Vec2::<$t>::sample_circle(u[1]) * r).extended(z)
+                }
+
+                // around +z, pdf is cos(theta) / pi
+                pub fn sample_cosine_hemisphere(u: Vec2<$t>) -> Self {
+                    let d = Vec2::<$t>::sample_disk_concentric(u);
+                    d.extended(Float::sqrt(($t::one() - d.dot(d)).max($t::zero())))
+                }
+
+                pub fn sample_triangle(a: Self, b: Self, c: Self, u: Vec2<$t>) -> Self {
+                    let [b0, b1, b2] = sample_barycentric(u);
+                    a * b0 + b * b1 + c * b2
+                }
+            }
+
+            impl Quat<$t> {
+                // shoemake's uniform rotation
+                pub fn sample_uniform(u: Vec3<$t>) -> Self {
+                    let r1 = Float::sqrt($t::one() - u[0]);
+                    let r2 = Float::sqrt(u[0]);
+                    let (sin1, cos1) = Float::sin_cos(u[1] * core::$t::consts::TAU);
+                    let (sin2, cos2) = Float::sin_cos(u[2] * core::$t::consts::TAU);
+                    Self::new(r1 * sin1, r1 * cos1, r2 * sin2, r2 * cos2)
+                }
+            }
+
+            impl Rot3<$t> {
+                pub fn sample_uniform(u: Vec3<$t>) -> Self {
+                    let [x, y, z, w] = Quat::<$t>::sample_uniform(u).0;
+                    Self::new(w, Bivec3::<$t>::new(-x, -y, -z))
+                }
+            }
+
+            impl Aabb2<$t> {
+                pub fn sample(&self, u: Vec2<$t>) -> Vec2<$t> {
+                    self.0 + self.size() * u
+                }
+            }
+
+            impl Aabb3<$t> {
+                pub fn sample(&self, u: Vec3<$t>) -> Vec3<$t> {
+                    self.0 + self.size() * u
+                }
+            }
+
+            fn sample_barycentric(u: Vec2<$t>) -> [$t; 3] {
+                let su = Float::sqrt(u[0]);
+                let b0 = $t::one() - su;
+                let b1 = u[1] * su;
+                [b0, b1, $t::one() - b0 - b1]
+            }
+        }
+    };
+}
+
+impl_warp!(f32, warp_f32);
+impl_warp!(f64, warp_f64);
@@ -0,0 +1,40 @@
+use crate::*;
+
+// the spring is solved exactly rather than integrated, so any step size gives the same curve
+macro_rules! impl_smooth {
+    ($($ty:ty),*; $t:ident) => {
+        $(
+            impl Smooth for $ty {
+                type Scalar = $t;
+                type Velocity = $ty;
+
+                fn spring(self, target: Self, velocity: &mut Self, half_life: $t, dt: $t) -> Self {
+                    if half_life <= $t::zero() {
+                        *velocity = Self::default();
+                        return target;
+                    }
+                    let omega = core::$t::consts::LN_2 * $t::splat(2.0) / half_life;
+                    let decay = Float::exp2(-dt * $t::splat(2.0) / half_life);
+                    let offset = self - target;
+                    let j = *velocity + offset * omega;
+                    *velocity = (*velocity - j * (omega * dt)) * decay;
+                    target + (offset + j * dt) * decay
+                }
+
+                fn smooth_damp(self, target: Self, velocity: &mut Self, smooth_time: $t, dt: $t) -> Self {
+                    self.spring(target, velocity, smooth_time * core::$t::consts::LN_2, dt)
+                }
+
+                fn decay(self, target: Self, half_life: $t, dt: $t) -> Self {
+                    if half_life <= $t::zero() {
+                        return target;
+                    }
+                    target + (self - target) * Float::exp2(-dt / half_life)
+                }
+            }
+        )*
+    };
+}
+
+impl_smooth!(f32, Vec2<f32>, Vec3<f32>, Vec4<f32>; f32);
+impl_smooth!(f64, Vec2<f64>, Vec3<f64>, Vec4<f64>; f64);
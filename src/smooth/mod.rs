@@ -0,0 +1,131 @@
+mod damp;
+mod rotation;
+mod spring;
+
+// frame rate independent smoothing towards a moving target, stepping by `dt` seconds at a time. velocities are per
+// second and for rotations an angular velocity, the axis scaled by radians per second
+pub trait Smooth: Copy {
+    type Scalar: Copy;
+    type Velocity: Copy + Default;
+
+    // critically damped spring, `half_life` is the time the damping alone takes to halve the distance
+    fn spring(self, target: Self, velocity: &mut Self::Velocity, half_life: Self::Scalar, dt: Self::Scalar) -> Self;
+
+    // the same spring parameterized like unity's SmoothDamp, arriving in roughly `smooth_time`
+    fn smooth_damp(
+        self,
+        target: Self,
+        velocity: &mut Self::Velocity,
+        smooth_time: Self::Scalar,
+        dt: Self::Scalar,
+    ) -> Self;
+
+    // exponential decay without a velocity, closing half the distance every `half_life`
+    fn decay(self, target: Self, half_life: Self::Scalar, dt: Self::Scalar) -> Self;
+}
+
+// a value following its target on a critically damped spring, keeping the velocity between updates
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spring<T: Smooth> {
+    pub value: T,
+    pub velocity: T::Velocity,
+    pub half_life: T::Scalar,
+}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_smooth_frame_rate() {
+        // sixty small steps land where one big step does
+        let (mut x, mut v) = (3.0f64, -1.0);
+        for _ in 0..60 {
+            x = x.spring(10.0, &mut v, 0.25, 1.0 / 60.0);
+        }
+        let mut v1 = -1.0;
+        let x1 = 3.0f64.spring(10.0, &mut v1, 0.25, 1.0);
+        assert!((x - x1).abs() < 1e-12 && (v - v1).abs() < 1e-12);
+
+        let mut d = 3.0f64;
+        for _ in 0..60 {
+            d = d.decay(10.0, 0.5, 1.0 / 60.0);
+        }
+        assert!((d - 8.25).abs() < 1e-12);
+        assert!((3.0f64.decay(10.0, 0.5, 0.5) - 6.5).abs() < 1e-12);
+
+        // vectors move component by component
+        let mut velocity = Vec3::<f32>::zero();
+        let p = Vec3::<f32>::new(1.0, -2.0, 0.5).spring(Vec3::<f32>::zero(), &mut velocity, 0.3, 0.1);
+        let mut vx = 0.0f32;
+        assert_eq!(p[1], (-2.0f32).spring(0.0, &mut vx, 0.3, 0.1));
+        assert_eq!(velocity[1], vx);
+
+        // no half life snaps straight to the target
+        let mut v = Vec2::<f32>::new(4.0, 4.0);
+        assert_eq!(
+            Vec2::<f32>::zero().smooth_damp(Vec2::<f32>::one(), &mut v, 0.0, 0.1),
+            Vec2::<f32>::one()
+        );
+        assert_eq!(v, Vec2::<f32>::zero());
+    }
+
+    #[test]
+    fn test_smooth_spring() {
+        // critically damped, so it closes in on the target without overshooting
+        let mut spring = Spring::new(0.0f64, 0.1);
+        let mut last = 0.0;
+        for _ in 0..120 {
+            let x = spring.update(1.0, 1.0 / 60.0);
+            assert!(x >= last && x <= 1.0);
+            last = x;
+        }
+        assert!((last - 1.0).abs() < 1e-6 && spring.velocity.abs() < 1e-4);
+
+        // smooth_damp arrives in about the smooth time
+        let (mut x, mut v) = (0.0f64, 0.0);
+        for _ in 0..30 {
+            x = x.smooth_damp(1.0, &mut v, 0.25, 1.0 / 60.0);
+        }
+        assert!(x > 0.9 && x < 1.0);
+    }
+
+    #[test]
+    fn test_smooth_rotation() {
+        let z = Vec3::<f64>::unit_z();
+
+        // halfway in angle after one half life, the short way round
+        let q = Quat::<f64>::from_rotation_axis(Deg(300.0), z);
+        let half = q.decay(Quat::<f64>::identity(), 0.5, 0.5);
+        assert_abs_diff_eq!(half, Quat::<f64>::from_rotation_axis(Deg(-30.0), z), epsilon = 1e-12);
+        let r = Rot3::<f64>::from_rotation_z(Deg(80.0));
+        let half = r.decay(Rot3::<f64>::identity(), 0.5, 0.5);
+        assert_abs_diff_eq!(half, Rot3::<f64>::from_rotation_z(Deg(40.0)), epsilon = 1e-12);
+
+        // the angular velocity points along the axis of the turn
+        let target = Quat::<f64>::from_rotation_axis(Deg(90.0), z);
+        let mut spring = Spring::new(Quat::<f64>::identity(), 0.2);
+        for _ in 0..10 {
+            spring.update(target, 1.0 / 30.0);
+        }
+        assert!(spring.velocity[2] > 0.0 && spring.velocity.truncated().length() < 1e-12);
+        let (angle, _) = spring.value.get_rotation_axis();
+        assert!(angle > 0.0 && angle < 90f64.to_radians());
+        for _ in 0..200 {
+            spring.update(target, 1.0 / 30.0);
+        }
+        assert_abs_diff_eq!(spring.value, target, epsilon = 1e-9);
+
+        let mut w = Vec3::<f64>::zero();
+        let mut r = Rot3::<f64>::identity();
+        for _ in 0..300 {
+            r = r.smooth_damp(Rot3::<f64>::from_rotation_x(Deg(-45.0)), &mut w, 0.3, 1.0 / 60.0);
+        }
+        assert_abs_diff_eq!(r, Rot3::<f64>::from_rotation_x(Deg(-45.0)), epsilon = 1e-9);
+        assert_abs_diff_eq!(Rot3::<f64>::from_scaled_axis(r.to_scaled_axis()), r, epsilon = 1e-12);
+    }
+}
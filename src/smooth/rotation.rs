@@ -0,0 +1,33 @@
+use crate::*;
+
+// the same spring as for vectors, run on the rotation vector of the offset from the target. that keeps to the short
+// way round and leaves the target exactly on arrival
+macro_rules! impl_smooth_rotation {
+    ($ty:ident, $inverse:ident, $t:ident) => {
+        impl Smooth for $ty<$t> {
+            type Scalar = $t;
+            type Velocity = Vec3<$t>;
+
+            fn spring(self, target: Self, velocity: &mut Vec3<$t>, half_life: $t, dt: $t) -> Self {
+                let offset = (self * target.$inverse()).to_scaled_axis();
+                let offset = offset.spring(Vec3::<$t>::zero(), velocity, half_life, dt);
+                ($ty::<$t>::from_scaled_axis(offset) * target).normalized()
+            }
+
+            fn smooth_damp(self, target: Self, velocity: &mut Vec3<$t>, smooth_time: $t, dt: $t) -> Self {
+                self.spring(target, velocity, smooth_time * core::$t::consts::LN_2, dt)
+            }
+
+            fn decay(self, target: Self, half_life: $t, dt: $t) -> Self {
+                let offset = (self * target.$inverse()).to_scaled_axis();
+                let offset = offset.decay(Vec3::<$t>::zero(), half_life, dt);
+                ($ty::<$t>::from_scaled_axis(offset) * target).normalized()
+            }
+        }
+    };
+}
+
+impl_smooth_rotation!(Quat, conjugate, f32);
+impl_smooth_rotation!(Quat, conjugate, f64);
+impl_smooth_rotation!(Rot3, reversed, f32);
+impl_smooth_rotation!(Rot3, reversed, f64);
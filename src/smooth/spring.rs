@@ -0,0 +1,17 @@
+use crate::*;
+
+impl<T: Smooth> Spring<T> {
+    pub fn new(value: T, half_life: T::Scalar) -> Self {
+        Self {
+            value,
+            velocity: T::Velocity::default(),
+            half_life,
+        }
+    }
+
+    // steps towards `target` and returns the new value
+    pub fn update(&mut self, target: T, dt: T::Scalar) -> T {
+        self.value = self.value.spring(target, &mut self.velocity, self.half_life, dt);
+        self.value
+    }
+}
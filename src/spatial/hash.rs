@@ -0,0 +1,155 @@
+use super::keep_nearest;
+use crate::*;
+use alloc::vec::Vec;
+
+macro_rules! impl_spatial_hash {
+    ($name:ident, $v:ident, $dim:literal, $t:ident) => {
+        impl $name<$t> {
+            pub fn new(points: &[$v<$t>], cell_size: $t) -> Self {
+                let mut hash = Self {
+                    cell_size,
+                    points: Vec::new(),
+                    cells: Vec::new(),
+                    starts: Vec::new(),
+                    entries: Vec::new(),
+                };
+                hash.rebuild(points);
+                hash
+            }
+
+            // buckets a new set of points in place of the old ones, keeping the allocations
+            pub fn rebuild(&mut self, points: &[$v<$t>]) {
+                let cell_size = self.cell_size;
+                self.points.clear();
+                self.points.extend_from_slice(points);
+                self.cells.clear();
+                self.cells
+                    .extend(points.iter().map(|&p| Self::cell_at(p, cell_size)));
+
+                // a counting sort, leaving each bucket's start just past its end and then filling it backwards
+                let buckets = (points.len() * 2).next_power_of_two();
+                self.starts.clear();
+                self.starts.resize(buckets + 1, 0);
+                for &cell in &self.cells {
+                    self.starts[Self::bucket(cell, buckets)] += 1;
+                }
+                for i in 1..=buckets {
+                    self.starts[i] += self.starts[i - 1];
+                }
+                self.entries.clear();
+                self.entries.resize(points.len(), 0);
+                for (i, &cell) in self.cells.iter().enumerate().rev() {
+                    let start = &mut self.starts[Self::bucket(cell, buckets)];
+                    *start -= 1;
+                    self.entries[*start] = i;
+                }
+            }
+
+            pub fn cell_size(&self) -> $t {
+                self.cell_size
+            }
+
+            pub fn len(&self) -> usize {
+                self.points.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.points.is_empty()
+            }
+
+            pub fn cell(&self, point: $v<$t>) -> [i32; $dim] {
+                Self::cell_at(point, self.cell_size)
+            }
+
+            // the points in the cell, by index
+            pub fn cell_points(&self, cell: [i32; $dim]) -> impl Iterator<Item = usize> + '_ {
+                let bucket = Self::bucket(cell, self.starts.len() - 1);
+                self.entries[self.starts[bucket]..self.starts[bucket + 1]]
+                    .iter()
+                    .copied()
+                    .filter(move |&i| self.cells[i] == cell)
+            }
+
+            fn cell_at(point: $v<$t>, cell_size: $t) -> [i32; $dim] {
+                core::array::from_fn(|i| Float::floor(point[i] / cell_size) as i32)
+            }
+
+            fn bucket(cell: [i32; $dim], buckets: usize) -> usize {
+                const PRIMES: [u32; 3] = [73856093, 19349663, 83492791];
+                let hash = (0..$dim).fold(0u32, |hash, i| hash ^ (cell[i] as u32).wrapping_mul(PRIMES[i]));
+                hash as usize & (buckets - 1)
+            }
+
+            // calls `f` with the index and squared distance of every point within `radius`. the cells the ball
+            // overlaps are walked one by one, unless there are more of them than buckets, when it's quicker to go
+            // through all the points
+            fn visit(&self, point: $v<$t>, radius: $t, mut f: impl FnMut(usize, $t)) {
+                if radius.is_nan() || radius < $t::zero() {
+                    return;
+                }
+                let limit = radius * radius;
+                let mut check = |i: usize| {
+                    let d = self.points[i] - point;
+                    if d.dot(d) <= limit {
+                        f(i, d.dot(d));
+                    }
+                };
+                let reach = $v::<$t>::one() * radius;
+                let (lo, hi) = (self.cell(point - reach), self.cell(point + reach));
+                let count = (0..$dim).fold(1u64, |count, i| {
+                    count.saturating_mul((hi[i] as i64 - lo[i] as i64 + 1) as u64)
+                });
+                if count >= self.starts.len() as u64 {
+                    (0..self.len()).for_each(check);
+                    return;
+                }
+                let mut cell = lo;
+                loop {
+                    self.cell_points(cell).for_each(&mut check);
+                    // on to the next cell, like an odometer
+                    let mut axis = 0;
+                    while axis < $dim && cell[axis] == hi[axis] {
+                        cell[axis] = lo[axis];
+                        axis += 1;
+                    }
+                    if axis == $dim {
+                        return;
+                    }
+                    cell[axis] += 1;
+                }
+            }
+        }
+
+        // queries only look through the cells within `max_distance`, so keep it down to a few cells across
+        impl PointQuery<$v<$t>, $t> for $name<$t> {
+            fn nearest(&self, point: $v<$t>, max_distance: $t) -> Option<(usize, $t)> {
+                let mut best: Option<(usize, $t)> = None;
+                self.visit(point, max_distance, |index, d2| {
+                    if best.map_or(true, |(_, d)| d2 < d) {
+                        best = Some((index, d2));
+                    }
+                });
+                best.map(|(index, d2)| (index, Float::sqrt(d2)))
+            }
+
+            fn k_nearest(&self, point: $v<$t>, k: usize, max_distance: $t, out: &mut Vec<(usize, $t)>) {
+                let start = out.len();
+                self.visit(point, max_distance, |index, d2| {
+                    keep_nearest(out, start, k, index, d2);
+                });
+                for found in &mut out[start..] {
+                    found.1 = Float::sqrt(found.1);
+                }
+            }
+
+            fn within_radius(&self, point: $v<$t>, radius: $t, out: &mut Vec<usize>) {
+                self.visit(point, radius, |index, _| out.push(index));
+            }
+        }
+    };
+}
+
+impl_spatial_hash!(SpatialHash2, Vec2, 2, f32);
+impl_spatial_hash!(SpatialHash2, Vec2, 2, f64);
+impl_spatial_hash!(SpatialHash3, Vec3, 3, f32);
+impl_spatial_hash!(SpatialHash3, Vec3, 3, f64);
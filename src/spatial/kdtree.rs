@@ -0,0 +1,121 @@
+use super::keep_nearest;
+use crate::*;
+use alloc::vec::Vec;
+
+macro_rules! impl_kdtree {
+    ($name:ident, $v:ident, $dim:literal, $t:ident) => {
+        impl $name<$t> {
+            pub fn new(points: &[$v<$t>]) -> Self {
+                let mut tree = Self {
+                    nodes: points.iter().copied().zip(0..).collect(),
+                    axes: alloc::vec![0; points.len()],
+                };
+                tree.split(0, points.len());
+                tree
+            }
+
+            pub fn len(&self) -> usize {
+                self.nodes.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.nodes.is_empty()
+            }
+
+            fn split(&mut self, lo: usize, hi: usize) {
+                if hi - lo <= 1 {
+                    return;
+                }
+                let range = &mut self.nodes[lo..hi];
+                let spread = |axis: usize| {
+                    let (min, max) = range.iter().fold(($t::INFINITY, $t::NEG_INFINITY), |(min, max), (p, _)| {
+                        (min.min(p[axis]), max.max(p[axis]))
+                    });
+                    max - min
+                };
+                let axis = (1..$dim).fold(0, |best, axis| if spread(axis) > spread(best) { axis } else { best });
+                let mid = (hi - lo) / 2;
+                range.select_nth_unstable_by(mid, |a, b| {
+                    a.0[axis].partial_cmp(&b.0[axis]).unwrap_or(core::cmp::Ordering::Equal)
+                });
+                self.axes[lo + mid] = axis as u8;
+                self.split(lo, lo + mid);
+                self.split(lo + mid + 1, hi);
+            }
+
+            // visits the points within the square root of `reach` of `point`, the nearer side of each split first.
+            // `visit` gets the index and squared distance and may shrink `reach` to prune what's left
+            fn search(
+                &self,
+                lo: usize,
+                hi: usize,
+                point: $v<$t>,
+                reach: &mut $t,
+                visit: &mut impl FnMut(usize, $t, &mut $t),
+            ) {
+                if lo >= hi {
+                    return;
+                }
+                let mid = lo + (hi - lo) / 2;
+                let (p, index) = self.nodes[mid];
+                let d = point - p;
+                if d.dot(d) <= *reach {
+                    visit(index, d.dot(d), reach);
+                }
+                let axis = self.axes[mid] as usize;
+                let offset = point[axis] - p[axis];
+                let (near, far) = if offset < $t::zero() { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+                self.search(near.0, near.1, point, reach, visit);
+                if offset * offset <= *reach {
+                    self.search(far.0, far.1, point, reach, visit);
+                }
+            }
+        }
+
+        impl PointQuery<$v<$t>, $t> for $name<$t> {
+            fn nearest(&self, point: $v<$t>, max_distance: $t) -> Option<(usize, $t)> {
+                if max_distance < $t::zero() {
+                    return None;
+                }
+                let mut best = None;
+                let mut reach = max_distance * max_distance;
+                self.search(0, self.len(), point, &mut reach, &mut |index, d2, reach| {
+                    best = Some((index, d2));
+                    *reach = d2;
+                });
+                best.map(|(index, d2)| (index, Float::sqrt(d2)))
+            }
+
+            fn k_nearest(&self, point: $v<$t>, k: usize, max_distance: $t, out: &mut Vec<(usize, $t)>) {
+                if k == 0 || max_distance < $t::zero() {
+                    return;
+                }
+                let start = out.len();
+                let mut reach = max_distance * max_distance;
+                self.search(0, self.len(), point, &mut reach, &mut |index, d2, reach| {
+                    keep_nearest(out, start, k, index, d2);
+                    // once there are k, only nearer ones than the last of them matter
+                    if out.len() - start == k {
+                        *reach = out[out.len() - 1].1;
+                    }
+                });
+                for found in &mut out[start..] {
+                    found.1 = Float::sqrt(found.1);
+                }
+            }
+
+            fn within_radius(&self, point: $v<$t>, radius: $t, out: &mut Vec<usize>) {
+                if radius < $t::zero() {
+                    return;
+                }
+                let mut reach = radius * radius;
+                self.search(0, self.len(), point, &mut reach, &mut |index, _, _| out.push(index));
+            }
+        }
+    };
+}
+
+impl_kdtree!(KdTree2, Vec2, 2, f32);
+impl_kdtree!(KdTree2, Vec2, 2, f64);
+impl_kdtree!(KdTree3, Vec3, 3, f32);
+impl_kdtree!(KdTree3, Vec3, 3, f64);
@@ -0,0 +1,216 @@
+mod hash;
+mod kdtree;
+
+use crate::{Vec2, Vec3};
+use alloc::vec::Vec;
+
+// nearest neighbour and radius queries over a fixed set of points, answered with indices into the slice the points
+// were given in and euclidean distances
+pub trait PointQuery<V, S> {
+    fn nearest(&self, point: V, max_distance: S) -> Option<(usize, S)>;
+
+    // appends up to `k` of them, nearest first
+    fn k_nearest(&self, point: V, k: usize, max_distance: S, out: &mut Vec<(usize, S)>);
+
+    // appends them in no particular order
+    fn within_radius(&self, point: V, radius: S, out: &mut Vec<usize>);
+
+    fn nearest_batch(&self, points: &[V], max_distance: S) -> Vec<Option<(usize, S)>>
+    where
+        V: Copy,
+        S: Copy,
+    {
+        points.iter().map(|&p| self.nearest(p, max_distance)).collect()
+    }
+
+    fn k_nearest_batch(&self, points: &[V], k: usize, max_distance: S) -> Vec<Vec<(usize, S)>>
+    where
+        V: Copy,
+        S: Copy,
+    {
+        points
+            .iter()
+            .map(|&p| {
+                let mut out = Vec::with_capacity(k);
+                self.k_nearest(p, k, max_distance, &mut out);
+                out
+            })
+            .collect()
+    }
+
+    fn within_radius_batch(&self, points: &[V], radius: S) -> Vec<Vec<usize>>
+    where
+        V: Copy,
+        S: Copy,
+    {
+        points
+            .iter()
+            .map(|&p| {
+                let mut out = Vec::new();
+                self.within_radius(p, radius, &mut out);
+                out
+            })
+            .collect()
+    }
+}
+
+// a static k-d tree, balanced by splitting at the median along the axis the points spread out most on
+#[derive(Debug, Clone, PartialEq)]
+pub struct KdTree2<S> {
+    // each point with its index, arranged so each range's splitting point sits in its middle
+    nodes: Vec<(Vec2<S>, usize)>,
+    axes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KdTree3<S> {
+    nodes: Vec<(Vec3<S>, usize)>,
+    axes: Vec<u8>,
+}
+
+// points bucketed by the integer coordinates of the grid cell they fall in, with the cells hashed into a table about
+// twice the size of the point count. cheap enough to rebuild every frame, and best for radius queries up to a
+// few cells across
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialHash2<S> {
+    cell_size: S,
+    points: Vec<Vec2<S>>,
+    cells: Vec<[i32; 2]>,
+    // where each bucket's run of `entries` begins, with one more at the end
+    starts: Vec<usize>,
+    entries: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialHash3<S> {
+    cell_size: S,
+    points: Vec<Vec3<S>>,
+    cells: Vec<[i32; 3]>,
+    starts: Vec<usize>,
+    entries: Vec<usize>,
+}
+
+// inserts a squared distance into the sorted run of `found` from `start`, keeping no more than `k` of them
+fn keep_nearest<S: PartialOrd + Copy>(found: &mut Vec<(usize, S)>, start: usize, k: usize, index: usize, d2: S) {
+    let at = start + found[start..].partition_point(|&(_, d)| d <= d2);
+    if at - start < k {
+        found.insert(at, (index, d2));
+        found.truncate(start + k);
+    }
+}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use alloc::vec::Vec;
+
+    // points scattered through the cube from -5 to 5 by a fixed sequence
+    fn scatter(n: usize) -> Vec<Vec3<f64>> {
+        let mut state = 1u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 10.0 - 5.0
+        };
+        (0..n).map(|_| Vec3::<f64>::new(next(), next(), next())).collect()
+    }
+
+    // every point by distance, nearest first
+    fn brute(points: &[Vec3<f64>], point: Vec3<f64>) -> Vec<(usize, f64)> {
+        let mut all: Vec<_> = points.iter().map(|&p| (p - point).length()).enumerate().collect();
+        all.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        all
+    }
+
+    fn check(query: &impl PointQuery<Vec3<f64>, f64>, points: &[Vec3<f64>], max: f64) {
+        let queries = scatter(40);
+        for (i, &q) in queries.iter().enumerate() {
+            let all = brute(points, q);
+            let near = query.nearest(q, max);
+            assert_eq!(near.map(|n| n.0), all.first().filter(|n| n.1 <= max).map(|n| n.0));
+            if let Some(near) = near {
+                assert!((near.1 - all[0].1).abs() < 1e-12);
+            }
+
+            let mut out = Vec::from([(usize::MAX, 0.0)]);
+            query.k_nearest(q, 5, max, &mut out);
+            let expected: Vec<_> = all.iter().copied().filter(|n| n.1 <= max).take(5).collect();
+            assert_eq!(
+                out[1..].iter().map(|n| n.0).collect::<Vec<_>>(),
+                expected.iter().map(|n| n.0).collect::<Vec<_>>()
+            );
+            assert_eq!(out[0].0, usize::MAX);
+
+            let mut inside = Vec::new();
+            query.within_radius(q, 1.5, &mut inside);
+            inside.sort();
+            let mut expected: Vec<_> = all.iter().filter(|n| n.1 <= 1.5).map(|n| n.0).collect();
+            expected.sort();
+            assert_eq!(inside, expected);
+
+            assert_eq!(query.nearest_batch(&queries, max)[i], near);
+            assert_eq!(query.k_nearest_batch(&queries, 5, max)[i], out[1..]);
+            assert_eq!(query.within_radius_batch(&queries, 1.5)[i].len(), inside.len());
+        }
+    }
+
+    #[test]
+    fn test_kdtree() {
+        let points = scatter(500);
+        let tree = KdTree3::<f64>::new(&points);
+        assert_eq!(tree.len(), 500);
+        check(&tree, &points, f64::INFINITY);
+        check(&tree, &points, 0.8);
+
+        let empty = KdTree3::<f64>::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(Vec3::<f64>::zero(), f64::INFINITY), None);
+
+        // repeated points all come back
+        let tree = KdTree2::<f32>::new(&[Vec2::<f32>::one(); 6]);
+        let mut out = Vec::new();
+        tree.within_radius(Vec2::<f32>::one(), 0.0, &mut out);
+        assert_eq!(out.len(), 6);
+        let mut nearest = Vec::new();
+        tree.k_nearest(Vec2::<f32>::zero(), 4, f32::INFINITY, &mut nearest);
+        assert_eq!(nearest.len(), 4);
+    }
+
+    #[test]
+    fn test_spatial_hash() {
+        let points = scatter(500);
+        let mut hash = SpatialHash3::<f64>::new(&points, 1.0);
+        check(&hash, &points, 0.8);
+        // far past the cells the table has room for, so it goes through every point
+        check(&hash, &points, f64::INFINITY);
+
+        assert_eq!(hash.cell(Vec3::<f64>::new(-0.5, 0.0, 2.5)), [-1, 0, 2]);
+        let cell = hash.cell(points[3]);
+        let in_cell: Vec<_> = hash.cell_points(cell).collect();
+        assert!(in_cell.contains(&3));
+        assert!(in_cell.iter().all(|&i| hash.cell(points[i]) == cell));
+        let count = (0..points.len()).filter(|&i| hash.cell(points[i]) == cell).count();
+        assert_eq!(in_cell.len(), count);
+
+        let moved: Vec<_> = points.iter().map(|&p| p * 0.5).collect();
+        hash.rebuild(&moved[..100]);
+        assert_eq!(hash.len(), 100);
+        check(&hash, &moved[..100], 2.0);
+
+        let hash = SpatialHash2::<f32>::new(&[Vec2::<f32>::new(0.1, 0.1), Vec2::<f32>::new(-0.1, -0.1)], 0.5);
+        assert_eq!(hash.cell(Vec2::<f32>::new(-0.1, -0.1)), [-1, -1]);
+        let mut out = Vec::new();
+        hash.within_radius(Vec2::<f32>::zero(), 0.2, &mut out);
+        out.sort();
+        assert_eq!(out, [0, 1]);
+        assert_eq!(hash.nearest(Vec2::<f32>::new(-1.0, -1.0), 0.5), None);
+        assert_eq!(hash.nearest(Vec2::<f32>::new(0.3, 0.1), 0.5).map(|n| n.0), Some(0));
+        assert!(SpatialHash2::<f32>::new(&[], 1.0)
+            .nearest(Vec2::<f32>::zero(), 1.0)
+            .is_none());
+    }
+}
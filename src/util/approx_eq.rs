@@ -0,0 +1,311 @@
+use crate::*;
+
+// approximate equality, following the approx crate: an absolute epsilon for values near zero, a tolerance relative
+// to the larger magnitude, or a distance in units in the last place. the math types compare component by component
+// and pass if every component does, except quaternions and rotors which also accept the negated rotation
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+// scalars
+// -----------------------------------------------------------------------------------------------------------
+
+macro_rules! impl_approx_eq_float {
+    ($($t:ident, $bits:ident);*) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    $t::EPSILON
+                }
+
+                fn default_max_relative() -> $t {
+                    $t::EPSILON
+                }
+
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    // the exact check lets equal infinities through
+                    self == other || (self - other).abs() <= epsilon
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    let diff = (self - other).abs();
+                    diff <= epsilon || diff <= self.abs().max(other.abs()) * max_relative
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true;
+                    }
+                    if self.is_sign_positive() != other.is_sign_positive() {
+                        return false;
+                    }
+                    (self.to_bits() as $bits).abs_diff(other.to_bits() as $bits) <= max_ulps as _
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_float!(f32, i32; f64, i64);
+
+// a unit in the last place of a fixed point number is its smallest step, so ulps are just the raw difference
+macro_rules! impl_approx_eq_fixed {
+    ($($t:ident),*) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    $t::EPSILON
+                }
+
+                fn default_max_relative() -> $t {
+                    $t::EPSILON
+                }
+
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    self.0.abs_diff(other.0) <= epsilon.0.unsigned_abs()
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    let diff = (*self - *other).abs();
+                    diff <= epsilon || diff <= self.abs().max(other.abs()) * max_relative
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    self.abs_diff_eq(other, epsilon) || self.0.abs_diff(other.0) <= max_ulps as _
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_fixed!(I16F16, I32F32);
+
+// math types
+// -----------------------------------------------------------------------------------------------------------
+
+// `$get` reads component `$i` of `$v`, the `rotation` form also accepts every component negated
+macro_rules! impl_approx_eq {
+    ($ty:ident, $n:literal, |$v:ident, $i:ident| $get:expr, [$($t:ident),*]) => {
+        impl_approx_eq!(@impl $ty, $n, |$v, $i| $get, false, [$($t),*]);
+    };
+    (rotation $ty:ident, $n:literal, |$v:ident, $i:ident| $get:expr, [$($t:ident),*]) => {
+        impl_approx_eq!(@impl $ty, $n, |$v, $i| $get, true, [$($t),*]);
+    };
+    (@impl $ty:ident, $n:literal, |$v:ident, $i:ident| $get:expr, $rotation:expr, [$($t:ident),*]) => {
+        $(
+            impl $ty<$t> {
+                fn approx_eq_by(&self, other: &Self, eq: impl Fn($t, $t) -> bool) -> bool {
+                    let get = |$v: &Self, $i: usize| -> $t { $get };
+                    (0..$n).all(|i| eq(get(self, i), get(other, i)))
+                        || ($rotation && (0..$n).all(|i| eq(get(self, i), -get(other, i))))
+                }
+
+                pub fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    self.approx_eq_by(other, |a, b| ApproxEq::abs_diff_eq(&a, &b, epsilon))
+                }
+
+                pub fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    self.approx_eq_by(other, |a, b| ApproxEq::relative_eq(&a, &b, epsilon, max_relative))
+                }
+
+                pub fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    self.approx_eq_by(other, |a, b| ApproxEq::ulps_eq(&a, &b, epsilon, max_ulps))
+                }
+            }
+
+            impl ApproxEq for $ty<$t> {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    <$t as ApproxEq>::default_epsilon()
+                }
+
+                fn default_max_relative() -> $t {
+                    <$t as ApproxEq>::default_max_relative()
+                }
+
+                fn default_max_ulps() -> u32 {
+                    <$t as ApproxEq>::default_max_ulps()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    $ty::<$t>::abs_diff_eq(self, other, epsilon)
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    $ty::<$t>::relative_eq(self, other, epsilon, max_relative)
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    $ty::<$t>::ulps_eq(self, other, epsilon, max_ulps)
+                }
+            }
+
+            #[cfg(feature = "approx")]
+            impl approx::AbsDiffEq for $ty<$t> {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    <$t as ApproxEq>::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    $ty::<$t>::abs_diff_eq(self, other, epsilon)
+                }
+            }
+
+            #[cfg(feature = "approx")]
+            impl approx::RelativeEq for $ty<$t> {
+                fn default_max_relative() -> $t {
+                    <$t as ApproxEq>::default_max_relative()
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    $ty::<$t>::relative_eq(self, other, epsilon, max_relative)
+                }
+            }
+
+            #[cfg(feature = "approx")]
+            impl approx::UlpsEq for $ty<$t> {
+                fn default_max_ulps() -> u32 {
+                    <$t as ApproxEq>::default_max_ulps()
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    $ty::<$t>::ulps_eq(self, other, epsilon, max_ulps)
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq!(Vec2, 2, |v, i| v.0[i], [f32, f64, I16F16, I32F32]);
+impl_approx_eq!(Vec3, 3, |v, i| v.0[i], [f32, f64, I16F16, I32F32]);
+impl_approx_eq!(Vec4, 4, |v, i| v.0[i], [f32, f64, I16F16, I32F32]);
+impl_approx_eq!(Mat2, 4, |m, i| m.0[i / 2].0[i % 2], [f32, f64]);
+impl_approx_eq!(Mat3, 9, |m, i| m.0[i / 3].0[i % 3], [f32, f64, I16F16, I32F32]);
+impl_approx_eq!(Mat4, 16, |m, i| m.0[i / 4].0[i % 4], [f32, f64, I16F16, I32F32]);
+impl_approx_eq!(Bivec2, 1, |b, _i| b.0, [f32, f64]);
+impl_approx_eq!(Bivec3, 3, |b, i| b.0[i], [f32, f64, I16F16, I32F32]);
+impl_approx_eq!(
+    Aabb2,
+    4,
+    |a, i| if i < 2 { a.0 .0[i] } else { a.1 .0[i - 2] },
+    [f32, f64]
+);
+impl_approx_eq!(
+    Aabb3,
+    6,
+    |a, i| if i < 3 { a.0 .0[i] } else { a.1 .0[i - 3] },
+    [f32, f64]
+);
+impl_approx_eq!(
+    Obb3,
+    10,
+    |o, i| match i {
+        0..=2 => o.0 .0[i],
+        3..=5 => o.1 .0[i - 3],
+        _ => o.2 .0[i - 6],
+    },
+    [f32, f64]
+);
+impl_approx_eq!(Sphere, 4, |s, i| if i < 3 { s.0 .0[i] } else { s.1 }, [f32, f64]);
+impl_approx_eq!(rotation Quat, 4, |q, i| q.0[i], [f32, f64, I16F16, I32F32]);
+impl_approx_eq!(rotation Rot2, 2, |r, i| if i == 0 { r.0 } else { r.1 .0 }, [f32]);
+impl_approx_eq!(rotation Rot3, 4, |r, i| if i == 0 { r.0 } else { r.1 .0[i - 1] }, [f32, f64, I16F16, I32F32]);
+
+// assertions
+// -----------------------------------------------------------------------------------------------------------
+
+// each takes two values of the same `ApproxEq` type and optional `epsilon = ..` plus `max_relative = ..` or
+// `max_ulps = ..` arguments, falling back to the type's defaults
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($a:expr, $b:expr $(, epsilon = $eps:expr)? $(,)?) => {{
+        let (a, b) = (&$a, &$b);
+        let epsilon = $crate::approx_arg!(a, default_epsilon $(, $eps)?);
+        if !$crate::ApproxEq::abs_diff_eq(a, b, epsilon) {
+            panic!(
+                "assertion failed: `abs_diff_eq!(left, right, epsilon = {:?})`\n  left: {:?}\n right: {:?}",
+                epsilon, a, b
+            );
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($a:expr, $b:expr $(, epsilon = $eps:expr)? $(, max_relative = $rel:expr)? $(,)?) => {{
+        let (a, b) = (&$a, &$b);
+        let epsilon = $crate::approx_arg!(a, default_epsilon $(, $eps)?);
+        let max_relative = $crate::approx_arg!(a, default_max_relative $(, $rel)?);
+        if !$crate::ApproxEq::relative_eq(a, b, epsilon, max_relative) {
+            panic!(
+                "assertion failed: `relative_eq!(left, right, epsilon = {:?}, max_relative = {:?})`\n  left: {:?}\n right: {:?}",
+                epsilon, max_relative, a, b
+            );
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($a:expr, $b:expr $(, epsilon = $eps:expr)? $(, max_ulps = $ulps:expr)? $(,)?) => {{
+        let (a, b) = (&$a, &$b);
+        let epsilon = $crate::approx_arg!(a, default_epsilon $(, $eps)?);
+        let max_ulps = $crate::approx_arg!(a, default_max_ulps $(, $ulps)?);
+        if !$crate::ApproxEq::ulps_eq(a, b, epsilon, max_ulps) {
+            panic!(
+                "assertion failed: `ulps_eq!(left, right, epsilon = {:?}, max_ulps = {:?})`\n  left: {:?}\n right: {:?}",
+                epsilon, max_ulps, a, b
+            );
+        }
+    }};
+}
+
+// the given argument, or the default for the type `$a` points to
+#[doc(hidden)]
+#[macro_export]
+macro_rules! approx_arg {
+    ($a:ident, $default:ident, $arg:expr) => {
+        $arg
+    };
+    ($a:ident, default_max_ulps) => {{
+        fn default_of<T: $crate::ApproxEq>(_: &T) -> u32 {
+            T::default_max_ulps()
+        }
+        default_of($a)
+    }};
+    ($a:ident, $default:ident) => {{
+        fn default_of<T: $crate::ApproxEq>(_: &T) -> T::Epsilon {
+            T::$default()
+        }
+        default_of($a)
+    }};
+}
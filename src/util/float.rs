@@ -0,0 +1,73 @@
+// float math goes through std when it is available and libm otherwise, call as `Float::sqrt(x)` so the
+// inherent std methods never shadow it, some methods are only used by optional modules
+//
+// with the `deterministic` feature everything goes through libm, which is plain ieee arithmetic and gives the same
+// bits on every target that has real single and double precision (so not x87). rustc never contracts `a * b + c`
+// into an fma on its own and the crate never calls `mul_add`, so rounding only depends on the order of operations
+#[allow(dead_code)]
+pub(crate) trait Float: Sized {
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp2(self) -> Self;
+    fn log2(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn round(self) -> Self;
+}
+
+#[cfg(all(feature = "std", not(feature = "deterministic")))]
+macro_rules! Float {
+	($($t:ty),*) => {
+		$(
+			impl Float for $t {
+				fn sqrt(self) -> Self { self.sqrt() }
+				fn sin(self) -> Self { self.sin() }
+				fn cos(self) -> Self { self.cos() }
+				fn tan(self) -> Self { self.tan() }
+				fn sin_cos(self) -> (Self, Self) { self.sin_cos() }
+				fn asin(self) -> Self { self.asin() }
+				fn acos(self) -> Self { self.acos() }
+				fn atan2(self, other: Self) -> Self { self.atan2(other) }
+				fn powf(self, n: Self) -> Self { self.powf(n) }
+				fn exp2(self) -> Self { self.exp2() }
+				fn log2(self) -> Self { self.log2() }
+				fn cbrt(self) -> Self { self.cbrt() }
+				fn floor(self) -> Self { self.floor() }
+				fn round(self) -> Self { self.round() }
+			}
+		)*
+	};
+}
+
+#[cfg(any(not(feature = "std"), feature = "deterministic"))]
+macro_rules! Float {
+	($($t:ty),*) => {
+		$(
+			impl Float for $t {
+				fn sqrt(self) -> Self { libm::Libm::<$t>::sqrt(self) }
+				fn sin(self) -> Self { libm::Libm::<$t>::sin(self) }
+				fn cos(self) -> Self { libm::Libm::<$t>::cos(self) }
+				fn tan(self) -> Self { libm::Libm::<$t>::tan(self) }
+				fn sin_cos(self) -> (Self, Self) { libm::Libm::<$t>::sincos(self) }
+				fn asin(self) -> Self { libm::Libm::<$t>::asin(self) }
+				fn acos(self) -> Self { libm::Libm::<$t>::acos(self) }
+				fn atan2(self, other: Self) -> Self { libm::Libm::<$t>::atan2(self, other) }
+				fn powf(self, n: Self) -> Self { libm::Libm::<$t>::pow(self, n) }
+				fn exp2(self) -> Self { libm::Libm::<$t>::exp2(self) }
+				fn log2(self) -> Self { libm::Libm::<$t>::log2(self) }
+				fn cbrt(self) -> Self { libm::Libm::<$t>::cbrt(self) }
+				fn floor(self) -> Self { libm::Libm::<$t>::floor(self) }
+				fn round(self) -> Self { libm::Libm::<$t>::round(self) }
+			}
+		)*
+	};
+}
+
+Float!(f32, f64);
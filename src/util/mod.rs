@@ -1,7 +1,197 @@
+mod approx_eq;
+pub use approx_eq::*;
+
 #[cfg(feature = "colorable")]
 mod colorable;
 #[cfg(feature = "colorable")]
 pub use colorable::*;
 
+mod float;
+pub(crate) use float::*;
 mod num;
 pub use num::*;
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "colorable", feature = "alloc"))]
+    #[test]
+    fn test_colorable_hex() {
+        use super::*;
+
+        assert_eq!(parse_color("#f80"), Some([0xff, 0x88, 0x00, 0xff]));
+        assert_eq!(parse_color("#ff8000"), Some([0xff, 0x80, 0x00, 0xff]));
+        assert_eq!(parse_color("#ff800080"), Some([0xff, 0x80, 0x00, 0x80]));
+        assert_eq!(parse_color("RebeccaPurple"), Some([0x66, 0x33, 0x99, 0xff]));
+        assert_eq!(parse_color("#ff80"), Some([0xff, 0xff, 0x88, 0x00]));
+        assert_eq!(parse_color("#ff80g0"), None);
+        assert_eq!(parse_color("notacolor"), None);
+
+        assert_eq!(u32::from_hex("#11223344", ByteOrder::Argb), Some(0x44112233));
+        assert_eq!(u32::from_hex("#11223344", ByteOrder::Rgba), Some(0x11223344));
+        assert_eq!(u32::from_hex("#11223344", ByteOrder::Bgra), Some(0x33221144));
+
+        assert_eq!(0xff112233u32.to_hex(ByteOrder::Argb), "#112233");
+        assert_eq!(0x11223344u32.to_hex(ByteOrder::Rgba), "#11223344");
+        assert_eq!(0x0000ffffu32.as_rgba_ordered(ByteOrder::Bgra), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(0xffff0000u32.as_rgba(), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(
+            u32::from_rgba_ordered([1.0, 0.0, 0.0, 1.0], ByteOrder::Rgba),
+            0xff0000ff
+        );
+    }
+
+    #[test]
+    fn test_float_math() {
+        use super::*;
+
+        // whichever backend is active has to agree with std to within a couple of ulps
+        for i in -20..=20 {
+            let x = i as f64 * 0.37;
+            let close = |a: f64, b: f64| (a - b).abs() <= 4.0 * f64::EPSILON * b.abs().max(1.0);
+            assert!(close(Float::sin(x), x.sin()));
+            assert!(close(Float::cos(x), x.cos()));
+            assert!(close(Float::sin_cos(x).0, x.sin()));
+            assert!(close(Float::atan2(x, 1.5), x.atan2(1.5)));
+            assert!(close(Float::sqrt(x.abs()), x.abs().sqrt()));
+            assert!(close(Float::cbrt(x), x.cbrt()));
+            assert!(close(Float::exp2(x), x.exp2()));
+            assert_eq!(Float::floor(x), x.floor());
+            assert_eq!(Float::round(x as f32), (x as f32).round());
+        }
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        use crate::*;
+
+        // scalars
+        assert_ne!(0.1 + 0.2, 0.3);
+        assert!(ApproxEq::relative_eq(&(0.1 + 0.2), &0.3, f64::EPSILON, f64::EPSILON));
+        assert!(ApproxEq::ulps_eq(&(0.1 + 0.2), &0.3, 0.0, 1));
+        assert!(!ApproxEq::ulps_eq(&1.0f32, &1.00001, 0.0, 4));
+        assert!(!ApproxEq::ulps_eq(&-1e-30f32, &1e-30, 0.0, 4));
+        assert!(ApproxEq::abs_diff_eq(&f32::INFINITY, &f32::INFINITY, 0.0));
+        assert!(!ApproxEq::relative_eq(&f32::NAN, &f32::NAN, 1.0, 1.0));
+        assert!(ApproxEq::ulps_eq(
+            &I16F16::ONE,
+            &I16F16::from_bits(65539),
+            I16F16::ZERO,
+            3
+        ));
+
+        // trigonometry lands next to the exact value rather than on it
+        let q = Quat::<f32>::from_rotation_axis(Rad(core::f32::consts::FRAC_PI_2), Vec3::<f32>::unit_z());
+        let v = q * Vec3::<f32>::unit_x();
+        assert_ne!(v, Vec3::<f32>::unit_y());
+        assert!(v.abs_diff_eq(&Vec3::<f32>::unit_y(), 1e-6));
+        assert!(!v.abs_diff_eq(&Vec3::<f32>::unit_x(), 1e-6));
+        assert_abs_diff_eq!(v, Vec3::<f32>::unit_y(), epsilon = 1e-6);
+        assert_relative_eq!(v, Vec3::<f32>::unit_y(), epsilon = 1e-7);
+        assert_ulps_eq!(Vec3::<f64>::new(0.1 + 0.2, 1.0, 0.0), Vec3::<f64>::new(0.3, 1.0, 0.0));
+
+        let m = Mat3::<f64>([
+            Vec3::<f64>::new(2.0, 0.5, 0.0),
+            Vec3::<f64>::new(0.3, 1.0, 0.7),
+            Vec3::<f64>::new(0.0, 0.1, 3.0),
+        ]);
+        assert_relative_eq!(m * m.inversed().unwrap(), Mat3::<f64>::identity(), epsilon = 1e-12);
+
+        // a rotation and its negation are the same rotation, but the conjugate is not
+        assert!(q.abs_diff_eq(&-q, 0.0));
+        assert!(!q.abs_diff_eq(&q.conjugate(), 1e-3));
+        assert_relative_eq!(-q, q);
+        let r = Rot3::<f64>(0.6, Bivec3::<f64>([0.0, 0.8, 0.0]));
+        assert_ulps_eq!(r, Rot3::<f64>(-0.6, Bivec3::<f64>([0.0, -0.8, 0.0])));
+        assert!(!r.ulps_eq(&Rot3::<f64>(-0.6, Bivec3::<f64>([0.0, 0.8, 0.0])), 0.0, 4));
+        // plain vectors do not get that treatment
+        assert!(!Vec4::<f32>::one().abs_diff_eq(&-Vec4::<f32>::one(), 0.0));
+
+        let failed =
+            std::panic::catch_unwind(|| assert_abs_diff_eq!(Vec2::<f32>::new(1.0, 2.0), Vec2::<f32>::new(1.0, 2.1)));
+        assert!(failed.is_err());
+
+        #[cfg(feature = "approx")]
+        {
+            approx::assert_relative_eq!(v, Vec3::<f32>::unit_y(), epsilon = 1e-7);
+            approx::assert_ulps_eq!(q, -q);
+            approx::assert_abs_diff_ne!(
+                Aabb2::<f32>::default(),
+                Aabb2::<f32>(Vec2::<f32>::one(), Vec2::<f32>::one())
+            );
+        }
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_deterministic_golden() {
+        use crate::*;
+
+        // bit patterns recorded from the software path, any change here breaks lockstep peers
+        let x = 0.7316f32;
+        let scalar_f32 = [
+            Float::sqrt(x),
+            Float::sin_cos(x).0,
+            Float::sin_cos(x).1,
+            Float::atan2(x, -0.4),
+            Float::acos(x),
+            Float::tan(x),
+        ];
+        assert_eq!(
+            scalar_f32.map(f32::to_bits),
+            [1062926160, 1059784205, 1061060035, 1074040200, 1061161100, 1063638635]
+        );
+
+        let y = -2.05f64;
+        let scalar_f64 = [
+            Float::sqrt(-y),
+            Float::sin_cos(y).0,
+            Float::sin_cos(y).1,
+            Float::atan2(y, 0.3),
+            Float::acos(y / 3.0),
+            Float::tan(y),
+        ];
+        assert_eq!(
+            scalar_f64.map(f64::to_bits),
+            [
+                4609126992533179794,
+                13829539906065491965,
+                13825349603976981229,
+                13832470675669699009,
+                4612413608291729295,
+                4611346269896712310
+            ]
+        );
+
+        let q = Quat::<f32>::from_rotation_axis(Rad(1.234), Vec3::<f32>::new(0.267, 0.535, 0.802).normalized());
+        assert_eq!(q.0.map(f32::to_bits), [1042162294, 1050570310, 1055752065, 1062259798]);
+        let (angle, axis) = q.get_rotation_axis();
+        assert_eq!(angle.to_bits(), 1067316150);
+        assert_eq!(axis.0.map(f32::to_bits), [1049143211, 1057548591, 1062026500]);
+
+        let r = Rot3::<f64>::from_rotation_x(Rad(0.75)) * Rot3::<f64>::from_rotation_y(Rad(-2.1));
+        let v = r * Vec3::<f64>::new(1.0, 2.0, 3.0);
+        assert_eq!(
+            v.0.map(f64::to_bits),
+            [4611876930508713611, 13818210530169584341, 13837541900884252906]
+        );
+
+        let m = Mat4::<f32>::perspective(Rad(1.1), 16.0 / 9.0, 0.1, 100.0);
+        assert_eq!(
+            m.0.map(|c| c.0.map(f32::to_bits)),
+            [
+                [3211452086, 0, 0, 0],
+                [0, 3218130423, 0, 0],
+                [0, 0, 3212845261, 3212836864],
+                [0, 0, 3184329032, 0]
+            ]
+        );
+
+        // x * x rounds to 1 + 2^-22, a fused multiply-add would keep the 2^-46 term
+        let x = 1.0 + f32::EPSILON;
+        let dot = Vec2::<f32>::new(x, 1.0).dot(Vec2::<f32>::new(x, -(1.0 + 2.0 * f32::EPSILON)));
+        assert_eq!(dot, 0.0);
+    }
+}
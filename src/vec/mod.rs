@@ -5,17 +5,26 @@ pub use vec2::*;
 pub use vec3::*;
 pub use vec4::*;
 
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2<S>(pub [S; 2]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Vec2<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Vec2<S> where S: bytemuck::Pod {}
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec3<S>(pub [S; 3]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Vec3<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Vec3<S> where S: bytemuck::Pod {}
-#[derive(Debug, Default, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec4<S>(pub [S; 4]);
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Zeroable for Vec4<S> where S: bytemuck::Zeroable {}
+#[cfg(feature = "bytemuck")]
 unsafe impl<S> bytemuck::Pod for Vec4<S> where S: bytemuck::Pod {}
 #[cfg(test)]
 mod tests {
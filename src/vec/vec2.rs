@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 macro_rules! impl_vec2 {
     ($t:ident) => {
@@ -55,12 +55,12 @@ macro_rules! impl_vec2 {
 
             #[inline]
             pub fn length(&self) -> $t {
-                self.dot(*self).sqrt()
+                Float::sqrt(self.dot(*self))
             }
 
             #[inline]
             pub fn normalized(&self) -> Self {
-                if self.length() == 0.0 {
+                if self.length() == $t::zero() {
                     *self
                 } else {
                     *self / self.length()
@@ -80,8 +80,8 @@ macro_rules! impl_vec2 {
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Vec2<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for Vec2<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 write!(f, "(x, y): [{:>8.4}, {:>8.4}]", self[0], self[1])
             }
         }
@@ -212,3 +212,5 @@ macro_rules! impl_vec2 {
 
 impl_vec2!(f32);
 impl_vec2!(f64);
+impl_vec2!(I16F16);
+impl_vec2!(I32F32);
@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 macro_rules! impl_vec3 {
     ($t:ident) => {
@@ -79,12 +79,12 @@ macro_rules! impl_vec3 {
 
             #[inline]
             pub fn length(&self) -> $t {
-                self.dot(*self).sqrt()
+                Float::sqrt(self.dot(*self))
             }
 
             #[inline]
             pub fn normalized(&self) -> Self {
-                if self.length() == 0.0 {
+                if self.length() == $t::zero() {
                     *self
                 } else {
                     *self / self.length()
@@ -93,7 +93,7 @@ macro_rules! impl_vec3 {
 
             #[inline]
             pub fn reflected(&self, normal: Self) -> Self {
-                *self - normal * (2.0 * self.dot(normal))
+                *self - normal * ($t::splat(2.0) * self.dot(normal))
             }
 
             #[inline]
@@ -109,8 +109,8 @@ macro_rules! impl_vec3 {
 
         // display
         // ---------------------------------------------------------------------------------------------------
-        impl std::fmt::Display for Vec3<$t> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Display for Vec3<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(
                     f,
                     "(x, y, z): [{:>8.4} {:>8.4} {:>8.4}]",
@@ -243,3 +243,5 @@ macro_rules! impl_vec3 {
 
 impl_vec3!(f32);
 impl_vec3!(f64);
+impl_vec3!(I16F16);
+impl_vec3!(I32F32);
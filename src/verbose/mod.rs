@@ -0,0 +1,188 @@
+// opt-in human readable serde representations, either per field with
+// `#[serde(with = "carbon_math::verbose")]` or by wrapping a value in `Verbose`
+
+mod repr;
+pub use repr::*;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub trait VerboseRepr: Sized {
+    type Repr: Serialize + for<'de> Deserialize<'de>;
+
+    fn to_verbose(&self) -> Self::Repr;
+    fn from_verbose(repr: Self::Repr) -> Self;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Verbose<T>(pub T);
+
+impl<T: VerboseRepr> Serialize for Verbose<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T: VerboseRepr> Deserialize<'de> for Verbose<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Verbose)
+    }
+}
+
+pub fn serialize<T: VerboseRepr, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.to_verbose().serialize(serializer)
+}
+
+pub fn deserialize<'de, T: VerboseRepr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    T::Repr::deserialize(deserializer).map(T::from_verbose)
+}
+
+// test
+// -----------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::Verbose;
+    use crate::*;
+
+    fn round_trip<T>(value: T, compact: &str, verbose: &str)
+    where
+        T: verbose::VerboseRepr
+            + serde::Serialize
+            + for<'de> serde::Deserialize<'de>
+            + PartialEq
+            + std::fmt::Debug
+            + Copy,
+    {
+        assert_eq!(serde_json::to_string(&value).unwrap(), compact);
+        assert_eq!(serde_json::from_str::<T>(compact).unwrap(), value);
+        assert_eq!(serde_json::to_string(&Verbose(value)).unwrap(), verbose);
+        assert_eq!(serde_json::from_str::<Verbose<T>>(verbose).unwrap().0, value);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        round_trip(Vec2::<f32>::new(1.0, 2.0), "[1.0,2.0]", r#"{"x":1.0,"y":2.0}"#);
+        round_trip(
+            Vec3::<f64>::new(1.0, 2.0, 3.0),
+            "[1.0,2.0,3.0]",
+            r#"{"x":1.0,"y":2.0,"z":3.0}"#,
+        );
+        round_trip(
+            Vec4::<f32>::new(1.0, 2.0, 3.0, 4.0),
+            "[1.0,2.0,3.0,4.0]",
+            r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#,
+        );
+        round_trip(Bivec2::<f32>::new(0.5), "0.5", r#"{"xy":0.5}"#);
+        round_trip(
+            Bivec3::<f64>::new(1.0, 2.0, 3.0),
+            "[1.0,2.0,3.0]",
+            r#"{"xy":1.0,"xz":2.0,"yz":3.0}"#,
+        );
+
+        // compact matrices are column-major, verbose ones row-major
+        round_trip(
+            Mat2::<f32>::new(1.0, 2.0, 3.0, 4.0),
+            "[[1.0,2.0],[3.0,4.0]]",
+            r#"{"rows":[[1.0,3.0],[2.0,4.0]]}"#,
+        );
+        round_trip(
+            Mat3::<f64>::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0),
+            "[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]",
+            r#"{"rows":[[1.0,4.0,7.0],[2.0,5.0,8.0],[3.0,6.0,9.0]]}"#,
+        );
+        let m = Mat4::<f32>::from_translation(Vec3::<f32>::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            serde_json::to_string(&Verbose(m)).unwrap(),
+            r#"{"rows":[[1.0,0.0,0.0,1.0],[0.0,1.0,0.0,2.0],[0.0,0.0,1.0,3.0],[0.0,0.0,0.0,1.0]]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Verbose<Mat4<f32>>>(&serde_json::to_string(&Verbose(m)).unwrap())
+                .unwrap()
+                .0,
+            m
+        );
+        assert_eq!(
+            serde_json::from_str::<Mat4<f32>>(&serde_json::to_string(&m).unwrap()).unwrap(),
+            m
+        );
+
+        let aabb = Aabb2::<f32>::new(Vec2::<f32>::new(0.0, 1.0), Vec2::<f32>::new(2.0, 3.0));
+        round_trip(
+            aabb,
+            "[[0.0,1.0],[2.0,3.0]]",
+            r#"{"min":{"x":0.0,"y":1.0},"max":{"x":2.0,"y":3.0}}"#,
+        );
+        let aabb = Aabb3::<f64>::new(Vec3::<f64>::zero(), Vec3::<f64>::one());
+        assert_eq!(
+            serde_json::from_str::<Verbose<Aabb3<f64>>>(&serde_json::to_string(&Verbose(aabb)).unwrap())
+                .unwrap()
+                .0,
+            aabb
+        );
+
+        // rotations are exact in compact form and close in verbose form
+        let q = Quat::<f64>::from_rotation_axis(Rad(std::f64::consts::FRAC_PI_2), Vec3::<f64>::unit_z());
+        assert_eq!(
+            serde_json::from_str::<Quat<f64>>(&serde_json::to_string(&q).unwrap()).unwrap(),
+            q
+        );
+        let json = serde_json::to_string(&Verbose(q)).unwrap();
+        assert_eq!(json, r#"{"axis":{"x":0.0,"y":0.0,"z":1.0},"angle":1.5707963267948966}"#);
+        let back = serde_json::from_str::<Verbose<Quat<f64>>>(&json).unwrap().0;
+        assert!((back - q).length() < 1e-12);
+        let identity = serde_json::to_string(&Verbose(Quat::<f32>::identity())).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Verbose<Quat<f32>>>(&identity).unwrap().0,
+            Quat::<f32>::identity()
+        );
+
+        let r = Rot3::<f64>::from_rotation_x(Rad(0.75));
+        assert_eq!(
+            serde_json::from_str::<Rot3<f64>>(&serde_json::to_string(&r).unwrap()).unwrap(),
+            r
+        );
+        let json = serde_json::to_string(&Verbose(r)).unwrap();
+        assert!(
+            json.starts_with(r#"{"axis":{"x":1.0,"y":0.0,"z":0.0},"angle":0.75"#),
+            "{}",
+            json
+        );
+        let back = serde_json::from_str::<Verbose<Rot3<f64>>>(&json).unwrap().0;
+        assert!((back - r).length() < 1e-12);
+        let r = Rot3::<f64>::from_rotation_y(Rad(-0.3)) * Rot3::<f64>::from_rotation_z(Rad(1.1));
+        let back = serde_json::from_str::<Verbose<Rot3<f64>>>(&serde_json::to_string(&Verbose(r)).unwrap())
+            .unwrap()
+            .0;
+        assert!((back - r).length() < 1e-12);
+
+        let r = Rot2::<f32>::from_rotation(Rad(0.5));
+        assert_eq!(
+            serde_json::from_str::<Rot2<f32>>(&serde_json::to_string(&r).unwrap()).unwrap(),
+            r
+        );
+        let json = serde_json::to_string(&Verbose(r)).unwrap();
+        assert_eq!(json, r#"{"angle":0.5}"#);
+        assert!((serde_json::from_str::<Verbose<Rot2<f32>>>(&json).unwrap().0 - r).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_serde_with_field() {
+        #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+        struct Transform {
+            #[serde(with = "crate::verbose")]
+            position: Vec3<f32>,
+            rotation: Quat<f32>,
+        }
+
+        let t = Transform {
+            position: Vec3::<f32>::new(1.0, 2.0, 3.0),
+            rotation: Quat::<f32>::identity(),
+        };
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(
+            json,
+            r#"{"position":{"x":1.0,"y":2.0,"z":3.0},"rotation":[0.0,0.0,0.0,1.0]}"#
+        );
+        assert_eq!(serde_json::from_str::<Transform>(&json).unwrap(), t);
+    }
+}
@@ -0,0 +1,263 @@
+use super::VerboseRepr;
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Xy<S> {
+    pub x: S,
+    pub y: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Xyz<S> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Xyzw<S> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+    pub w: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Plane2<S> {
+    pub xy: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Plane3<S> {
+    pub xy: S,
+    pub xz: S,
+    pub yz: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Rows<R> {
+    pub rows: R,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Angle<S> {
+    pub angle: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AxisAngle<S> {
+    pub axis: Xyz<S>,
+    pub angle: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MinMax<V> {
+    pub min: V,
+    pub max: V,
+}
+
+macro_rules! impl_verbose {
+    ($t:ident) => {
+        // vectors
+        // ---------------------------------------------------------------------------------------------------
+        impl VerboseRepr for Vec2<$t> {
+            type Repr = Xy<$t>;
+            fn to_verbose(&self) -> Self::Repr {
+                Xy {
+                    x: self[0],
+                    y: self[1],
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self::new(r.x, r.y)
+            }
+        }
+
+        impl VerboseRepr for Vec3<$t> {
+            type Repr = Xyz<$t>;
+            fn to_verbose(&self) -> Self::Repr {
+                Xyz {
+                    x: self[0],
+                    y: self[1],
+                    z: self[2],
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self::new(r.x, r.y, r.z)
+            }
+        }
+
+        impl VerboseRepr for Vec4<$t> {
+            type Repr = Xyzw<$t>;
+            fn to_verbose(&self) -> Self::Repr {
+                Xyzw {
+                    x: self[0],
+                    y: self[1],
+                    z: self[2],
+                    w: self[3],
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self::new(r.x, r.y, r.z, r.w)
+            }
+        }
+
+        // bivectors
+        // ---------------------------------------------------------------------------------------------------
+        impl VerboseRepr for Bivec2<$t> {
+            type Repr = Plane2<$t>;
+            fn to_verbose(&self) -> Self::Repr {
+                Plane2 { xy: self.0 }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self(r.xy)
+            }
+        }
+
+        impl VerboseRepr for Bivec3<$t> {
+            type Repr = Plane3<$t>;
+            fn to_verbose(&self) -> Self::Repr {
+                let [xy, xz, yz] = self.0;
+                Plane3 { xy, xz, yz }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self([r.xy, r.xz, r.yz])
+            }
+        }
+
+        // matrices, row-major
+        // ---------------------------------------------------------------------------------------------------
+        impl VerboseRepr for Mat2<$t> {
+            type Repr = Rows<[[$t; 2]; 2]>;
+            fn to_verbose(&self) -> Self::Repr {
+                Rows {
+                    rows: core::array::from_fn(|r| core::array::from_fn(|c| self[c][r])),
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self(core::array::from_fn(|c| {
+                    Vec2(core::array::from_fn(|i| r.rows[i][c]))
+                }))
+            }
+        }
+
+        impl VerboseRepr for Mat3<$t> {
+            type Repr = Rows<[[$t; 3]; 3]>;
+            fn to_verbose(&self) -> Self::Repr {
+                Rows {
+                    rows: core::array::from_fn(|r| core::array::from_fn(|c| self[c][r])),
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self(core::array::from_fn(|c| {
+                    Vec3(core::array::from_fn(|i| r.rows[i][c]))
+                }))
+            }
+        }
+
+        impl VerboseRepr for Mat4<$t> {
+            type Repr = Rows<[[$t; 4]; 4]>;
+            fn to_verbose(&self) -> Self::Repr {
+                Rows {
+                    rows: core::array::from_fn(|r| core::array::from_fn(|c| self[c][r])),
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self(core::array::from_fn(|c| {
+                    Vec4(core::array::from_fn(|i| r.rows[i][c]))
+                }))
+            }
+        }
+
+        // rotations, axis-angle in radians
+        // ---------------------------------------------------------------------------------------------------
+        impl VerboseRepr for Quat<$t> {
+            type Repr = AxisAngle<$t>;
+            fn to_verbose(&self) -> Self::Repr {
+                let v = Vec3::<$t>::new(self[0], self[1], self[2]);
+                let sin = v.length();
+                let axis = if sin == $t::zero() {
+                    Vec3::<$t>::unit_x()
+                } else {
+                    v / sin
+                };
+                AxisAngle {
+                    axis: axis.to_verbose(),
+                    angle: $t::splat(2.0) * Float::atan2(sin, self[3]),
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self::from_rotation_axis(Rad(r.angle), Vec3::<$t>::from_verbose(r.axis).normalized())
+            }
+        }
+
+        // the axis is the one `from_rotation_x`, `from_rotation_y` and `from_rotation_z` rotate around
+        impl VerboseRepr for Rot3<$t> {
+            type Repr = AxisAngle<$t>;
+            fn to_verbose(&self) -> Self::Repr {
+                let [xy, xz, yz] = self.1 .0;
+                // subtracting from zero keeps -0.0 out of the output
+                let v = Vec3::<$t>::new(yz, $t::zero() - xz, xy);
+                let sin = v.length();
+                let axis = if sin == $t::zero() {
+                    Vec3::<$t>::unit_x()
+                } else {
+                    v / sin
+                };
+                AxisAngle {
+                    axis: axis.to_verbose(),
+                    angle: $t::splat(2.0) * Float::atan2(sin, self.0),
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                let axis = Vec3::<$t>::from_verbose(r.axis).normalized();
+                let plane = Bivec3::<$t>::new(-axis[2], axis[1], -axis[0]);
+                Self::from_rotation_bi(Rad(r.angle), plane)
+            }
+        }
+
+        // bounds
+        // ---------------------------------------------------------------------------------------------------
+        impl VerboseRepr for Aabb2<$t> {
+            type Repr = MinMax<Xy<$t>>;
+            fn to_verbose(&self) -> Self::Repr {
+                MinMax {
+                    min: self.0.to_verbose(),
+                    max: self.1.to_verbose(),
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self(Vec2::<$t>::from_verbose(r.min), Vec2::<$t>::from_verbose(r.max))
+            }
+        }
+
+        impl VerboseRepr for Aabb3<$t> {
+            type Repr = MinMax<Xyz<$t>>;
+            fn to_verbose(&self) -> Self::Repr {
+                MinMax {
+                    min: self.0.to_verbose(),
+                    max: self.1.to_verbose(),
+                }
+            }
+            fn from_verbose(r: Self::Repr) -> Self {
+                Self(Vec3::<$t>::from_verbose(r.min), Vec3::<$t>::from_verbose(r.max))
+            }
+        }
+    };
+}
+
+impl_verbose!(f32);
+impl_verbose!(f64);
+
+// rot2 is only implemented for f32
+impl VerboseRepr for Rot2<f32> {
+    type Repr = Angle<f32>;
+    fn to_verbose(&self) -> Self::Repr {
+        Angle {
+            angle: 2.0 * Float::atan2(0.0 - self.1 .0, self.0),
+        }
+    }
+    fn from_verbose(r: Self::Repr) -> Self {
+        Self::from_rotation(Rad(r.angle))
+    }
+}